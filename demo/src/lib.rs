@@ -18,7 +18,7 @@
 ///
 /// Dark mode is automatically enabled if `dark` or `ayu` rustdoc theme is selected.
 ///
-/// You might need to reload the page to redraw the diagrams after changing the theme.
+/// The diagrams are redrawn as soon as the rustdoc theme is changed.
 pub fn example() {}
 
 #[cfg_attr(doc, aquamarine::aquamarine)]
@@ -51,9 +51,62 @@ pub fn example_with_styling() {}
 ///
 /// include_mmd!(diagram_1.mmd)
 ///
-/// Diagrams up to Mermaid version 11.1 are currently supported
+/// Diagrams up to Mermaid version 10.1 are currently supported
 ///
 /// include_mmd!(diagram_2.mmd)
 ///
 /// **Note:** `indlude_mmd!` syntax is only supported inside doc comments
 pub fn example_load_from_file() {}
+
+#[cfg_attr(doc, aquamarine::aquamarine(theme = "forest", collapsible))]
+/// Rendering options can be passed to the macro as arguments
+///
+/// ```mermaid
+/// graph LR
+///     args["#[aquamarine(theme = &quot;forest&quot;, collapsible)]"] --> d([Collapsible forest diagram])
+/// ```
+///
+/// Click on the "Diagram" summary above to expand it
+pub fn example_with_arguments() {}
+
+#[cfg_attr(doc, aquamarine::aquamarine)]
+/// Diagrams can be captioned, sized and linked to with the code block attributes
+///
+/// ```mermaid caption="Figure 1: request lifecycle" id=lifecycle width=60% align=center
+/// sequenceDiagram
+///     Client->>Server: request
+///     Server-->>Client: response
+/// ```
+///
+/// The diagram above can be linked to as [`#lifecycle`](#lifecycle)
+///
+/// The `rustdoc` theme matches the colors of the rustdoc theme, try switching it in the settings
+///
+/// ```mermaid theme=rustdoc
+/// graph LR
+///     t([rustdoc theme]) --> v[CSS variables] --> m([mermaid themeVariables])
+/// ```
+pub fn example_with_attributes() {}
+
+#[cfg_attr(doc, aquamarine::aquamarine)]
+/// A single `#[aquamarine]` covers the docs of fields, variants, methods and nested modules
+pub struct ExampleNested {
+    /// Fields can have diagrams too
+    ///
+    /// ```mermaid
+    /// graph LR
+    ///     s[ExampleNested] --> f[field]
+    /// ```
+    pub field: u32,
+}
+
+#[cfg_attr(doc, aquamarine::aquamarine)]
+impl ExampleNested {
+    /// And so can methods
+    ///
+    /// ```mermaid
+    /// graph LR
+    ///     s[ExampleNested] --> m["method()"]
+    /// ```
+    pub fn method(&self) {}
+}
@@ -1,17 +0,0 @@
-export declare const clear: () => void;
-export declare const setAccTitle: (txt: string) => void;
-export declare const getAccTitle: () => string;
-export declare const setAccDescription: (txt: string) => void;
-export declare const getAccDescription: () => string;
-export declare const setDiagramTitle: (txt: string) => void;
-export declare const getDiagramTitle: () => string;
-declare const _default: {
-    getAccTitle: () => string;
-    setAccTitle: (txt: string) => void;
-    getDiagramTitle: () => string;
-    setDiagramTitle: (txt: string) => void;
-    getAccDescription: () => string;
-    setAccDescription: (txt: string) => void;
-    clear: () => void;
-};
-export default _default;
@@ -1,1166 +0,0 @@
-import { g as getConfig, o as setAccTitle, p as getAccTitle, v as setAccDescription, q as getAccDescription, w as setDiagramTitle, x as getDiagramTitle, l as log, y as clear$1, z as parseGenericTypes } from "./commonDb-573409be.js";
-import { m as mermaidAPI } from "./mermaidAPI-3ae0f2f0.js";
-import * as graphlib from "dagre-d3-es/src/graphlib/index.js";
-import { select, line, curveBasis } from "d3";
-import { layout } from "dagre-d3-es/src/dagre/index.js";
-import { u as utils, k as configureSvgSize } from "./utils-d622194a.js";
-import { v5 } from "uuid";
-import "dompurify";
-import "dayjs";
-import "khroma";
-import "stylis";
-import "lodash-es/isEmpty.js";
-import "@braintree/sanitize-url";
-import "lodash-es/memoize.js";
-var parser = function() {
-  var o = function(k, v, o2, l) {
-    for (o2 = o2 || {}, l = k.length; l--; o2[k[l]] = v)
-      ;
-    return o2;
-  }, $V0 = [1, 2], $V1 = [1, 5], $V2 = [6, 9, 11, 23, 25, 27, 29, 30, 31, 51], $V3 = [1, 17], $V4 = [1, 18], $V5 = [1, 19], $V6 = [1, 20], $V7 = [1, 21], $V8 = [1, 22], $V9 = [1, 25], $Va = [1, 30], $Vb = [1, 31], $Vc = [1, 32], $Vd = [1, 33], $Ve = [6, 9, 11, 15, 20, 23, 25, 27, 29, 30, 31, 44, 45, 46, 47, 51], $Vf = [1, 45], $Vg = [30, 31, 48, 49], $Vh = [4, 6, 9, 11, 23, 25, 27, 29, 30, 31, 51], $Vi = [44, 45, 46, 47], $Vj = [22, 37], $Vk = [1, 65], $Vl = [1, 64], $Vm = [22, 37, 39, 41];
-  var parser2 = {
-    trace: function trace() {
-    },
-    yy: {},
-    symbols_: { "error": 2, "start": 3, "ER_DIAGRAM": 4, "document": 5, "EOF": 6, "directive": 7, "line": 8, "SPACE": 9, "statement": 10, "NEWLINE": 11, "openDirective": 12, "typeDirective": 13, "closeDirective": 14, ":": 15, "argDirective": 16, "entityName": 17, "relSpec": 18, "role": 19, "BLOCK_START": 20, "attributes": 21, "BLOCK_STOP": 22, "title": 23, "title_value": 24, "acc_title": 25, "acc_title_value": 26, "acc_descr": 27, "acc_descr_value": 28, "acc_descr_multiline_value": 29, "ALPHANUM": 30, "ENTITY_NAME": 31, "attribute": 32, "attributeType": 33, "attributeName": 34, "attributeKeyTypeList": 35, "attributeComment": 36, "ATTRIBUTE_WORD": 37, "attributeKeyType": 38, "COMMA": 39, "ATTRIBUTE_KEY": 40, "COMMENT": 41, "cardinality": 42, "relType": 43, "ZERO_OR_ONE": 44, "ZERO_OR_MORE": 45, "ONE_OR_MORE": 46, "ONLY_ONE": 47, "NON_IDENTIFYING": 48, "IDENTIFYING": 49, "WORD": 50, "open_directive": 51, "type_directive": 52, "arg_directive": 53, "close_directive": 54, "$accept": 0, "$end": 1 },
-    terminals_: { 2: "error", 4: "ER_DIAGRAM", 6: "EOF", 9: "SPACE", 11: "NEWLINE", 15: ":", 20: "BLOCK_START", 22: "BLOCK_STOP", 23: "title", 24: "title_value", 25: "acc_title", 26: "acc_title_value", 27: "acc_descr", 28: "acc_descr_value", 29: "acc_descr_multiline_value", 30: "ALPHANUM", 31: "ENTITY_NAME", 37: "ATTRIBUTE_WORD", 39: "COMMA", 40: "ATTRIBUTE_KEY", 41: "COMMENT", 44: "ZERO_OR_ONE", 45: "ZERO_OR_MORE", 46: "ONE_OR_MORE", 47: "ONLY_ONE", 48: "NON_IDENTIFYING", 49: "IDENTIFYING", 50: "WORD", 51: "open_directive", 52: "type_directive", 53: "arg_directive", 54: "close_directive" },
-    productions_: [0, [3, 3], [3, 2], [5, 0], [5, 2], [8, 2], [8, 1], [8, 1], [8, 1], [7, 4], [7, 6], [10, 1], [10, 5], [10, 4], [10, 3], [10, 1], [10, 2], [10, 2], [10, 2], [10, 1], [17, 1], [17, 1], [21, 1], [21, 2], [32, 2], [32, 3], [32, 3], [32, 4], [33, 1], [34, 1], [35, 1], [35, 3], [38, 1], [36, 1], [18, 3], [42, 1], [42, 1], [42, 1], [42, 1], [43, 1], [43, 1], [19, 1], [19, 1], [19, 1], [12, 1], [13, 1], [16, 1], [14, 1]],
-    performAction: function anonymous(yytext, yyleng, yylineno, yy, yystate, $$, _$) {
-      var $0 = $$.length - 1;
-      switch (yystate) {
-        case 1:
-          break;
-        case 3:
-          this.$ = [];
-          break;
-        case 4:
-          $$[$0 - 1].push($$[$0]);
-          this.$ = $$[$0 - 1];
-          break;
-        case 5:
-        case 6:
-          this.$ = $$[$0];
-          break;
-        case 7:
-        case 8:
-          this.$ = [];
-          break;
-        case 12:
-          yy.addEntity($$[$0 - 4]);
-          yy.addEntity($$[$0 - 2]);
-          yy.addRelationship($$[$0 - 4], $$[$0], $$[$0 - 2], $$[$0 - 3]);
-          break;
-        case 13:
-          yy.addEntity($$[$0 - 3]);
-          yy.addAttributes($$[$0 - 3], $$[$0 - 1]);
-          break;
-        case 14:
-          yy.addEntity($$[$0 - 2]);
-          break;
-        case 15:
-          yy.addEntity($$[$0]);
-          break;
-        case 16:
-        case 17:
-          this.$ = $$[$0].trim();
-          yy.setAccTitle(this.$);
-          break;
-        case 18:
-        case 19:
-          this.$ = $$[$0].trim();
-          yy.setAccDescription(this.$);
-          break;
-        case 20:
-        case 43:
-          this.$ = $$[$0];
-          break;
-        case 21:
-        case 41:
-        case 42:
-          this.$ = $$[$0].replace(/"/g, "");
-          break;
-        case 22:
-        case 30:
-          this.$ = [$$[$0]];
-          break;
-        case 23:
-          $$[$0].push($$[$0 - 1]);
-          this.$ = $$[$0];
-          break;
-        case 24:
-          this.$ = { attributeType: $$[$0 - 1], attributeName: $$[$0] };
-          break;
-        case 25:
-          this.$ = { attributeType: $$[$0 - 2], attributeName: $$[$0 - 1], attributeKeyTypeList: $$[$0] };
-          break;
-        case 26:
-          this.$ = { attributeType: $$[$0 - 2], attributeName: $$[$0 - 1], attributeComment: $$[$0] };
-          break;
-        case 27:
-          this.$ = { attributeType: $$[$0 - 3], attributeName: $$[$0 - 2], attributeKeyTypeList: $$[$0 - 1], attributeComment: $$[$0] };
-          break;
-        case 28:
-        case 29:
-        case 32:
-          this.$ = $$[$0];
-          break;
-        case 31:
-          $$[$0 - 2].push($$[$0]);
-          this.$ = $$[$0 - 2];
-          break;
-        case 33:
-          this.$ = $$[$0].replace(/"/g, "");
-          break;
-        case 34:
-          this.$ = { cardA: $$[$0], relType: $$[$0 - 1], cardB: $$[$0 - 2] };
-          break;
-        case 35:
-          this.$ = yy.Cardinality.ZERO_OR_ONE;
-          break;
-        case 36:
-          this.$ = yy.Cardinality.ZERO_OR_MORE;
-          break;
-        case 37:
-          this.$ = yy.Cardinality.ONE_OR_MORE;
-          break;
-        case 38:
-          this.$ = yy.Cardinality.ONLY_ONE;
-          break;
-        case 39:
-          this.$ = yy.Identification.NON_IDENTIFYING;
-          break;
-        case 40:
-          this.$ = yy.Identification.IDENTIFYING;
-          break;
-        case 44:
-          yy.parseDirective("%%{", "open_directive");
-          break;
-        case 45:
-          yy.parseDirective($$[$0], "type_directive");
-          break;
-        case 46:
-          $$[$0] = $$[$0].trim().replace(/'/g, '"');
-          yy.parseDirective($$[$0], "arg_directive");
-          break;
-        case 47:
-          yy.parseDirective("}%%", "close_directive", "er");
-          break;
-      }
-    },
-    table: [{ 3: 1, 4: $V0, 7: 3, 12: 4, 51: $V1 }, { 1: [3] }, o($V2, [2, 3], { 5: 6 }), { 3: 7, 4: $V0, 7: 3, 12: 4, 51: $V1 }, { 13: 8, 52: [1, 9] }, { 52: [2, 44] }, { 6: [1, 10], 7: 15, 8: 11, 9: [1, 12], 10: 13, 11: [1, 14], 12: 4, 17: 16, 23: $V3, 25: $V4, 27: $V5, 29: $V6, 30: $V7, 31: $V8, 51: $V1 }, { 1: [2, 2] }, { 14: 23, 15: [1, 24], 54: $V9 }, o([15, 54], [2, 45]), o($V2, [2, 8], { 1: [2, 1] }), o($V2, [2, 4]), { 7: 15, 10: 26, 12: 4, 17: 16, 23: $V3, 25: $V4, 27: $V5, 29: $V6, 30: $V7, 31: $V8, 51: $V1 }, o($V2, [2, 6]), o($V2, [2, 7]), o($V2, [2, 11]), o($V2, [2, 15], { 18: 27, 42: 29, 20: [1, 28], 44: $Va, 45: $Vb, 46: $Vc, 47: $Vd }), { 24: [1, 34] }, { 26: [1, 35] }, { 28: [1, 36] }, o($V2, [2, 19]), o($Ve, [2, 20]), o($Ve, [2, 21]), { 11: [1, 37] }, { 16: 38, 53: [1, 39] }, { 11: [2, 47] }, o($V2, [2, 5]), { 17: 40, 30: $V7, 31: $V8 }, { 21: 41, 22: [1, 42], 32: 43, 33: 44, 37: $Vf }, { 43: 46, 48: [1, 47], 49: [1, 48] }, o($Vg, [2, 35]), o($Vg, [2, 36]), o($Vg, [2, 37]), o($Vg, [2, 38]), o($V2, [2, 16]), o($V2, [2, 17]), o($V2, [2, 18]), o($Vh, [2, 9]), { 14: 49, 54: $V9 }, { 54: [2, 46] }, { 15: [1, 50] }, { 22: [1, 51] }, o($V2, [2, 14]), { 21: 52, 22: [2, 22], 32: 43, 33: 44, 37: $Vf }, { 34: 53, 37: [1, 54] }, { 37: [2, 28] }, { 42: 55, 44: $Va, 45: $Vb, 46: $Vc, 47: $Vd }, o($Vi, [2, 39]), o($Vi, [2, 40]), { 11: [1, 56] }, { 19: 57, 30: [1, 60], 31: [1, 59], 50: [1, 58] }, o($V2, [2, 13]), { 22: [2, 23] }, o($Vj, [2, 24], { 35: 61, 36: 62, 38: 63, 40: $Vk, 41: $Vl }), o([22, 37, 40, 41], [2, 29]), o([30, 31], [2, 34]), o($Vh, [2, 10]), o($V2, [2, 12]), o($V2, [2, 41]), o($V2, [2, 42]), o($V2, [2, 43]), o($Vj, [2, 25], { 36: 66, 39: [1, 67], 41: $Vl }), o($Vj, [2, 26]), o($Vm, [2, 30]), o($Vj, [2, 33]), o($Vm, [2, 32]), o($Vj, [2, 27]), { 38: 68, 40: $Vk }, o($Vm, [2, 31])],
-    defaultActions: { 5: [2, 44], 7: [2, 2], 25: [2, 47], 39: [2, 46], 45: [2, 28], 52: [2, 23] },
-    parseError: function parseError(str, hash) {
-      if (hash.recoverable) {
-        this.trace(str);
-      } else {
-        var error = new Error(str);
-        error.hash = hash;
-        throw error;
-      }
-    },
-    parse: function parse(input) {
-      var self = this, stack = [0], tstack = [], vstack = [null], lstack = [], table = this.table, yytext = "", yylineno = 0, yyleng = 0, TERROR = 2, EOF = 1;
-      var args = lstack.slice.call(arguments, 1);
-      var lexer2 = Object.create(this.lexer);
-      var sharedState = { yy: {} };
-      for (var k in this.yy) {
-        if (Object.prototype.hasOwnProperty.call(this.yy, k)) {
-          sharedState.yy[k] = this.yy[k];
-        }
-      }
-      lexer2.setInput(input, sharedState.yy);
-      sharedState.yy.lexer = lexer2;
-      sharedState.yy.parser = this;
-      if (typeof lexer2.yylloc == "undefined") {
-        lexer2.yylloc = {};
-      }
-      var yyloc = lexer2.yylloc;
-      lstack.push(yyloc);
-      var ranges = lexer2.options && lexer2.options.ranges;
-      if (typeof sharedState.yy.parseError === "function") {
-        this.parseError = sharedState.yy.parseError;
-      } else {
-        this.parseError = Object.getPrototypeOf(this).parseError;
-      }
-      function lex() {
-        var token;
-        token = tstack.pop() || lexer2.lex() || EOF;
-        if (typeof token !== "number") {
-          if (token instanceof Array) {
-            tstack = token;
-            token = tstack.pop();
-          }
-          token = self.symbols_[token] || token;
-        }
-        return token;
-      }
-      var symbol, state, action, r, yyval = {}, p, len, newState, expected;
-      while (true) {
-        state = stack[stack.length - 1];
-        if (this.defaultActions[state]) {
-          action = this.defaultActions[state];
-        } else {
-          if (symbol === null || typeof symbol == "undefined") {
-            symbol = lex();
-          }
-          action = table[state] && table[state][symbol];
-        }
-        if (typeof action === "undefined" || !action.length || !action[0]) {
-          var errStr = "";
-          expected = [];
-          for (p in table[state]) {
-            if (this.terminals_[p] && p > TERROR) {
-              expected.push("'" + this.terminals_[p] + "'");
-            }
-          }
-          if (lexer2.showPosition) {
-            errStr = "Parse error on line " + (yylineno + 1) + ":\n" + lexer2.showPosition() + "\nExpecting " + expected.join(", ") + ", got '" + (this.terminals_[symbol] || symbol) + "'";
-          } else {
-            errStr = "Parse error on line " + (yylineno + 1) + ": Unexpected " + (symbol == EOF ? "end of input" : "'" + (this.terminals_[symbol] || symbol) + "'");
-          }
-          this.parseError(errStr, {
-            text: lexer2.match,
-            token: this.terminals_[symbol] || symbol,
-            line: lexer2.yylineno,
-            loc: yyloc,
-            expected
-          });
-        }
-        if (action[0] instanceof Array && action.length > 1) {
-          throw new Error("Parse Error: multiple actions possible at state: " + state + ", token: " + symbol);
-        }
-        switch (action[0]) {
-          case 1:
-            stack.push(symbol);
-            vstack.push(lexer2.yytext);
-            lstack.push(lexer2.yylloc);
-            stack.push(action[1]);
-            symbol = null;
-            {
-              yyleng = lexer2.yyleng;
-              yytext = lexer2.yytext;
-              yylineno = lexer2.yylineno;
-              yyloc = lexer2.yylloc;
-            }
-            break;
-          case 2:
-            len = this.productions_[action[1]][1];
-            yyval.$ = vstack[vstack.length - len];
-            yyval._$ = {
-              first_line: lstack[lstack.length - (len || 1)].first_line,
-              last_line: lstack[lstack.length - 1].last_line,
-              first_column: lstack[lstack.length - (len || 1)].first_column,
-              last_column: lstack[lstack.length - 1].last_column
-            };
-            if (ranges) {
-              yyval._$.range = [
-                lstack[lstack.length - (len || 1)].range[0],
-                lstack[lstack.length - 1].range[1]
-              ];
-            }
-            r = this.performAction.apply(yyval, [
-              yytext,
-              yyleng,
-              yylineno,
-              sharedState.yy,
-              action[1],
-              vstack,
-              lstack
-            ].concat(args));
-            if (typeof r !== "undefined") {
-              return r;
-            }
-            if (len) {
-              stack = stack.slice(0, -1 * len * 2);
-              vstack = vstack.slice(0, -1 * len);
-              lstack = lstack.slice(0, -1 * len);
-            }
-            stack.push(this.productions_[action[1]][0]);
-            vstack.push(yyval.$);
-            lstack.push(yyval._$);
-            newState = table[stack[stack.length - 2]][stack[stack.length - 1]];
-            stack.push(newState);
-            break;
-          case 3:
-            return true;
-        }
-      }
-      return true;
-    }
-  };
-  var lexer = function() {
-    var lexer2 = {
-      EOF: 1,
-      parseError: function parseError(str, hash) {
-        if (this.yy.parser) {
-          this.yy.parser.parseError(str, hash);
-        } else {
-          throw new Error(str);
-        }
-      },
-      // resets the lexer, sets new input
-      setInput: function(input, yy) {
-        this.yy = yy || this.yy || {};
-        this._input = input;
-        this._more = this._backtrack = this.done = false;
-        this.yylineno = this.yyleng = 0;
-        this.yytext = this.matched = this.match = "";
-        this.conditionStack = ["INITIAL"];
-        this.yylloc = {
-          first_line: 1,
-          first_column: 0,
-          last_line: 1,
-          last_column: 0
-        };
-        if (this.options.ranges) {
-          this.yylloc.range = [0, 0];
-        }
-        this.offset = 0;
-        return this;
-      },
-      // consumes and returns one char from the input
-      input: function() {
-        var ch = this._input[0];
-        this.yytext += ch;
-        this.yyleng++;
-        this.offset++;
-        this.match += ch;
-        this.matched += ch;
-        var lines = ch.match(/(?:\r\n?|\n).*/g);
-        if (lines) {
-          this.yylineno++;
-          this.yylloc.last_line++;
-        } else {
-          this.yylloc.last_column++;
-        }
-        if (this.options.ranges) {
-          this.yylloc.range[1]++;
-        }
-        this._input = this._input.slice(1);
-        return ch;
-      },
-      // unshifts one char (or a string) into the input
-      unput: function(ch) {
-        var len = ch.length;
-        var lines = ch.split(/(?:\r\n?|\n)/g);
-        this._input = ch + this._input;
-        this.yytext = this.yytext.substr(0, this.yytext.length - len);
-        this.offset -= len;
-        var oldLines = this.match.split(/(?:\r\n?|\n)/g);
-        this.match = this.match.substr(0, this.match.length - 1);
-        this.matched = this.matched.substr(0, this.matched.length - 1);
-        if (lines.length - 1) {
-          this.yylineno -= lines.length - 1;
-        }
-        var r = this.yylloc.range;
-        this.yylloc = {
-          first_line: this.yylloc.first_line,
-          last_line: this.yylineno + 1,
-          first_column: this.yylloc.first_column,
-          last_column: lines ? (lines.length === oldLines.length ? this.yylloc.first_column : 0) + oldLines[oldLines.length - lines.length].length - lines[0].length : this.yylloc.first_column - len
-        };
-        if (this.options.ranges) {
-          this.yylloc.range = [r[0], r[0] + this.yyleng - len];
-        }
-        this.yyleng = this.yytext.length;
-        return this;
-      },
-      // When called from action, caches matched text and appends it on next action
-      more: function() {
-        this._more = true;
-        return this;
-      },
-      // When called from action, signals the lexer that this rule fails to match the input, so the next matching rule (regex) should be tested instead.
-      reject: function() {
-        if (this.options.backtrack_lexer) {
-          this._backtrack = true;
-        } else {
-          return this.parseError("Lexical error on line " + (this.yylineno + 1) + ". You can only invoke reject() in the lexer when the lexer is of the backtracking persuasion (options.backtrack_lexer = true).\n" + this.showPosition(), {
-            text: "",
-            token: null,
-            line: this.yylineno
-          });
-        }
-        return this;
-      },
-      // retain first n characters of the match
-      less: function(n) {
-        this.unput(this.match.slice(n));
-      },
-      // displays already matched input, i.e. for error messages
-      pastInput: function() {
-        var past = this.matched.substr(0, this.matched.length - this.match.length);
-        return (past.length > 20 ? "..." : "") + past.substr(-20).replace(/\n/g, "");
-      },
-      // displays upcoming input, i.e. for error messages
-      upcomingInput: function() {
-        var next = this.match;
-        if (next.length < 20) {
-          next += this._input.substr(0, 20 - next.length);
-        }
-        return (next.substr(0, 20) + (next.length > 20 ? "..." : "")).replace(/\n/g, "");
-      },
-      // displays the character position where the lexing error occurred, i.e. for error messages
-      showPosition: function() {
-        var pre = this.pastInput();
-        var c = new Array(pre.length + 1).join("-");
-        return pre + this.upcomingInput() + "\n" + c + "^";
-      },
-      // test the lexed token: return FALSE when not a match, otherwise return token
-      test_match: function(match, indexed_rule) {
-        var token, lines, backup;
-        if (this.options.backtrack_lexer) {
-          backup = {
-            yylineno: this.yylineno,
-            yylloc: {
-              first_line: this.yylloc.first_line,
-              last_line: this.last_line,
-              first_column: this.yylloc.first_column,
-              last_column: this.yylloc.last_column
-            },
-            yytext: this.yytext,
-            match: this.match,
-            matches: this.matches,
-            matched: this.matched,
-            yyleng: this.yyleng,
-            offset: this.offset,
-            _more: this._more,
-            _input: this._input,
-            yy: this.yy,
-            conditionStack: this.conditionStack.slice(0),
-            done: this.done
-          };
-          if (this.options.ranges) {
-            backup.yylloc.range = this.yylloc.range.slice(0);
-          }
-        }
-        lines = match[0].match(/(?:\r\n?|\n).*/g);
-        if (lines) {
-          this.yylineno += lines.length;
-        }
-        this.yylloc = {
-          first_line: this.yylloc.last_line,
-          last_line: this.yylineno + 1,
-          first_column: this.yylloc.last_column,
-          last_column: lines ? lines[lines.length - 1].length - lines[lines.length - 1].match(/\r?\n?/)[0].length : this.yylloc.last_column + match[0].length
-        };
-        this.yytext += match[0];
-        this.match += match[0];
-        this.matches = match;
-        this.yyleng = this.yytext.length;
-        if (this.options.ranges) {
-          this.yylloc.range = [this.offset, this.offset += this.yyleng];
-        }
-        this._more = false;
-        this._backtrack = false;
-        this._input = this._input.slice(match[0].length);
-        this.matched += match[0];
-        token = this.performAction.call(this, this.yy, this, indexed_rule, this.conditionStack[this.conditionStack.length - 1]);
-        if (this.done && this._input) {
-          this.done = false;
-        }
-        if (token) {
-          return token;
-        } else if (this._backtrack) {
-          for (var k in backup) {
-            this[k] = backup[k];
-          }
-          return false;
-        }
-        return false;
-      },
-      // return next match in input
-      next: function() {
-        if (this.done) {
-          return this.EOF;
-        }
-        if (!this._input) {
-          this.done = true;
-        }
-        var token, match, tempMatch, index;
-        if (!this._more) {
-          this.yytext = "";
-          this.match = "";
-        }
-        var rules = this._currentRules();
-        for (var i = 0; i < rules.length; i++) {
-          tempMatch = this._input.match(this.rules[rules[i]]);
-          if (tempMatch && (!match || tempMatch[0].length > match[0].length)) {
-            match = tempMatch;
-            index = i;
-            if (this.options.backtrack_lexer) {
-              token = this.test_match(tempMatch, rules[i]);
-              if (token !== false) {
-                return token;
-              } else if (this._backtrack) {
-                match = false;
-                continue;
-              } else {
-                return false;
-              }
-            } else if (!this.options.flex) {
-              break;
-            }
-          }
-        }
-        if (match) {
-          token = this.test_match(match, rules[index]);
-          if (token !== false) {
-            return token;
-          }
-          return false;
-        }
-        if (this._input === "") {
-          return this.EOF;
-        } else {
-          return this.parseError("Lexical error on line " + (this.yylineno + 1) + ". Unrecognized text.\n" + this.showPosition(), {
-            text: "",
-            token: null,
-            line: this.yylineno
-          });
-        }
-      },
-      // return next match that has a token
-      lex: function lex() {
-        var r = this.next();
-        if (r) {
-          return r;
-        } else {
-          return this.lex();
-        }
-      },
-      // activates a new lexer condition state (pushes the new lexer condition state onto the condition stack)
-      begin: function begin(condition) {
-        this.conditionStack.push(condition);
-      },
-      // pop the previously active lexer condition state off the condition stack
-      popState: function popState() {
-        var n = this.conditionStack.length - 1;
-        if (n > 0) {
-          return this.conditionStack.pop();
-        } else {
-          return this.conditionStack[0];
-        }
-      },
-      // produce the lexer rule set which is active for the currently active lexer condition state
-      _currentRules: function _currentRules() {
-        if (this.conditionStack.length && this.conditionStack[this.conditionStack.length - 1]) {
-          return this.conditions[this.conditionStack[this.conditionStack.length - 1]].rules;
-        } else {
-          return this.conditions["INITIAL"].rules;
-        }
-      },
-      // return the currently active lexer condition state; when an index argument is provided it produces the N-th previous condition state, if available
-      topState: function topState(n) {
-        n = this.conditionStack.length - 1 - Math.abs(n || 0);
-        if (n >= 0) {
-          return this.conditionStack[n];
-        } else {
-          return "INITIAL";
-        }
-      },
-      // alias for begin(condition)
-      pushState: function pushState(condition) {
-        this.begin(condition);
-      },
-      // return the number of states currently on the stack
-      stateStackSize: function stateStackSize() {
-        return this.conditionStack.length;
-      },
-      options: { "case-insensitive": true },
-      performAction: function anonymous(yy, yy_, $avoiding_name_collisions, YY_START) {
-        switch ($avoiding_name_collisions) {
-          case 0:
-            this.begin("acc_title");
-            return 25;
-          case 1:
-            this.popState();
-            return "acc_title_value";
-          case 2:
-            this.begin("acc_descr");
-            return 27;
-          case 3:
-            this.popState();
-            return "acc_descr_value";
-          case 4:
-            this.begin("acc_descr_multiline");
-            break;
-          case 5:
-            this.popState();
-            break;
-          case 6:
-            return "acc_descr_multiline_value";
-          case 7:
-            this.begin("open_directive");
-            return 51;
-          case 8:
-            this.begin("type_directive");
-            return 52;
-          case 9:
-            this.popState();
-            this.begin("arg_directive");
-            return 15;
-          case 10:
-            this.popState();
-            this.popState();
-            return 54;
-          case 11:
-            return 53;
-          case 12:
-            return 11;
-          case 13:
-            break;
-          case 14:
-            return 9;
-          case 15:
-            return 31;
-          case 16:
-            return 50;
-          case 17:
-            return 4;
-          case 18:
-            this.begin("block");
-            return 20;
-          case 19:
-            return 39;
-          case 20:
-            break;
-          case 21:
-            return 40;
-          case 22:
-            return 37;
-          case 23:
-            return 37;
-          case 24:
-            return 41;
-          case 25:
-            break;
-          case 26:
-            this.popState();
-            return 22;
-          case 27:
-            return yy_.yytext[0];
-          case 28:
-            return 44;
-          case 29:
-            return 46;
-          case 30:
-            return 46;
-          case 31:
-            return 46;
-          case 32:
-            return 44;
-          case 33:
-            return 44;
-          case 34:
-            return 45;
-          case 35:
-            return 45;
-          case 36:
-            return 45;
-          case 37:
-            return 45;
-          case 38:
-            return 45;
-          case 39:
-            return 46;
-          case 40:
-            return 45;
-          case 41:
-            return 46;
-          case 42:
-            return 47;
-          case 43:
-            return 47;
-          case 44:
-            return 47;
-          case 45:
-            return 47;
-          case 46:
-            return 44;
-          case 47:
-            return 45;
-          case 48:
-            return 46;
-          case 49:
-            return 48;
-          case 50:
-            return 49;
-          case 51:
-            return 49;
-          case 52:
-            return 48;
-          case 53:
-            return 48;
-          case 54:
-            return 48;
-          case 55:
-            return 30;
-          case 56:
-            return yy_.yytext[0];
-          case 57:
-            return 6;
-        }
-      },
-      rules: [/^(?:accTitle\s*:\s*)/i, /^(?:(?!\n||)*[^\n]*)/i, /^(?:accDescr\s*:\s*)/i, /^(?:(?!\n||)*[^\n]*)/i, /^(?:accDescr\s*\{\s*)/i, /^(?:[\}])/i, /^(?:[^\}]*)/i, /^(?:%%\{)/i, /^(?:((?:(?!\}%%)[^:.])*))/i, /^(?::)/i, /^(?:\}%%)/i, /^(?:((?:(?!\}%%).|\n)*))/i, /^(?:[\n]+)/i, /^(?:\s+)/i, /^(?:[\s]+)/i, /^(?:"[^"%\r\n\v\b\\]+")/i, /^(?:"[^"]*")/i, /^(?:erDiagram\b)/i, /^(?:\{)/i, /^(?:,)/i, /^(?:\s+)/i, /^(?:\b((?:PK)|(?:FK)|(?:UK))\b)/i, /^(?:(.*?)[~](.*?)*[~])/i, /^(?:[A-Za-z_][A-Za-z0-9\-_\[\]\(\)]*)/i, /^(?:"[^"]*")/i, /^(?:[\n]+)/i, /^(?:\})/i, /^(?:.)/i, /^(?:one or zero\b)/i, /^(?:one or more\b)/i, /^(?:one or many\b)/i, /^(?:1\+)/i, /^(?:\|o\b)/i, /^(?:zero or one\b)/i, /^(?:zero or more\b)/i, /^(?:zero or many\b)/i, /^(?:0\+)/i, /^(?:\}o\b)/i, /^(?:many\(0\))/i, /^(?:many\(1\))/i, /^(?:many\b)/i, /^(?:\}\|)/i, /^(?:one\b)/i, /^(?:only one\b)/i, /^(?:1\b)/i, /^(?:\|\|)/i, /^(?:o\|)/i, /^(?:o\{)/i, /^(?:\|\{)/i, /^(?:\.\.)/i, /^(?:--)/i, /^(?:to\b)/i, /^(?:optionally to\b)/i, /^(?:\.-)/i, /^(?:-\.)/i, /^(?:[A-Za-z][A-Za-z0-9\-_]*)/i, /^(?:.)/i, /^(?:$)/i],
-      conditions: { "acc_descr_multiline": { "rules": [5, 6], "inclusive": false }, "acc_descr": { "rules": [3], "inclusive": false }, "acc_title": { "rules": [1], "inclusive": false }, "open_directive": { "rules": [8], "inclusive": false }, "type_directive": { "rules": [9, 10], "inclusive": false }, "arg_directive": { "rules": [10, 11], "inclusive": false }, "block": { "rules": [19, 20, 21, 22, 23, 24, 25, 26, 27], "inclusive": false }, "INITIAL": { "rules": [0, 2, 4, 7, 12, 13, 14, 15, 16, 17, 18, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57], "inclusive": true } }
-    };
-    return lexer2;
-  }();
-  parser2.lexer = lexer;
-  function Parser() {
-    this.yy = {};
-  }
-  Parser.prototype = parser2;
-  parser2.Parser = Parser;
-  return new Parser();
-}();
-parser.parser = parser;
-const erParser = parser;
-let entities = {};
-let relationships = [];
-const Cardinality = {
-  ZERO_OR_ONE: "ZERO_OR_ONE",
-  ZERO_OR_MORE: "ZERO_OR_MORE",
-  ONE_OR_MORE: "ONE_OR_MORE",
-  ONLY_ONE: "ONLY_ONE"
-};
-const Identification = {
-  NON_IDENTIFYING: "NON_IDENTIFYING",
-  IDENTIFYING: "IDENTIFYING"
-};
-const parseDirective = function(statement, context, type) {
-  mermaidAPI.parseDirective(this, statement, context, type);
-};
-const addEntity = function(name) {
-  if (entities[name] === void 0) {
-    entities[name] = { attributes: [] };
-    log.info("Added new entity :", name);
-  }
-  return entities[name];
-};
-const getEntities = () => entities;
-const addAttributes = function(entityName, attribs) {
-  let entity = addEntity(entityName);
-  let i;
-  for (i = attribs.length - 1; i >= 0; i--) {
-    entity.attributes.push(attribs[i]);
-    log.debug("Added attribute ", attribs[i].attributeName);
-  }
-};
-const addRelationship = function(entA, rolA, entB, rSpec) {
-  let rel = {
-    entityA: entA,
-    roleA: rolA,
-    entityB: entB,
-    relSpec: rSpec
-  };
-  relationships.push(rel);
-  log.debug("Added new relationship :", rel);
-};
-const getRelationships = () => relationships;
-const clear = function() {
-  entities = {};
-  relationships = [];
-  clear$1();
-};
-const erDb = {
-  Cardinality,
-  Identification,
-  parseDirective,
-  getConfig: () => getConfig().er,
-  addEntity,
-  addAttributes,
-  getEntities,
-  addRelationship,
-  getRelationships,
-  clear,
-  setAccTitle,
-  getAccTitle,
-  setAccDescription,
-  getAccDescription,
-  setDiagramTitle,
-  getDiagramTitle
-};
-const ERMarkers = {
-  ONLY_ONE_START: "ONLY_ONE_START",
-  ONLY_ONE_END: "ONLY_ONE_END",
-  ZERO_OR_ONE_START: "ZERO_OR_ONE_START",
-  ZERO_OR_ONE_END: "ZERO_OR_ONE_END",
-  ONE_OR_MORE_START: "ONE_OR_MORE_START",
-  ONE_OR_MORE_END: "ONE_OR_MORE_END",
-  ZERO_OR_MORE_START: "ZERO_OR_MORE_START",
-  ZERO_OR_MORE_END: "ZERO_OR_MORE_END"
-};
-const insertMarkers = function(elem, conf2) {
-  let marker;
-  elem.append("defs").append("marker").attr("id", ERMarkers.ONLY_ONE_START).attr("refX", 0).attr("refY", 9).attr("markerWidth", 18).attr("markerHeight", 18).attr("orient", "auto").append("path").attr("stroke", conf2.stroke).attr("fill", "none").attr("d", "M9,0 L9,18 M15,0 L15,18");
-  elem.append("defs").append("marker").attr("id", ERMarkers.ONLY_ONE_END).attr("refX", 18).attr("refY", 9).attr("markerWidth", 18).attr("markerHeight", 18).attr("orient", "auto").append("path").attr("stroke", conf2.stroke).attr("fill", "none").attr("d", "M3,0 L3,18 M9,0 L9,18");
-  marker = elem.append("defs").append("marker").attr("id", ERMarkers.ZERO_OR_ONE_START).attr("refX", 0).attr("refY", 9).attr("markerWidth", 30).attr("markerHeight", 18).attr("orient", "auto");
-  marker.append("circle").attr("stroke", conf2.stroke).attr("fill", "white").attr("cx", 21).attr("cy", 9).attr("r", 6);
-  marker.append("path").attr("stroke", conf2.stroke).attr("fill", "none").attr("d", "M9,0 L9,18");
-  marker = elem.append("defs").append("marker").attr("id", ERMarkers.ZERO_OR_ONE_END).attr("refX", 30).attr("refY", 9).attr("markerWidth", 30).attr("markerHeight", 18).attr("orient", "auto");
-  marker.append("circle").attr("stroke", conf2.stroke).attr("fill", "white").attr("cx", 9).attr("cy", 9).attr("r", 6);
-  marker.append("path").attr("stroke", conf2.stroke).attr("fill", "none").attr("d", "M21,0 L21,18");
-  elem.append("defs").append("marker").attr("id", ERMarkers.ONE_OR_MORE_START).attr("refX", 18).attr("refY", 18).attr("markerWidth", 45).attr("markerHeight", 36).attr("orient", "auto").append("path").attr("stroke", conf2.stroke).attr("fill", "none").attr("d", "M0,18 Q 18,0 36,18 Q 18,36 0,18 M42,9 L42,27");
-  elem.append("defs").append("marker").attr("id", ERMarkers.ONE_OR_MORE_END).attr("refX", 27).attr("refY", 18).attr("markerWidth", 45).attr("markerHeight", 36).attr("orient", "auto").append("path").attr("stroke", conf2.stroke).attr("fill", "none").attr("d", "M3,9 L3,27 M9,18 Q27,0 45,18 Q27,36 9,18");
-  marker = elem.append("defs").append("marker").attr("id", ERMarkers.ZERO_OR_MORE_START).attr("refX", 18).attr("refY", 18).attr("markerWidth", 57).attr("markerHeight", 36).attr("orient", "auto");
-  marker.append("circle").attr("stroke", conf2.stroke).attr("fill", "white").attr("cx", 48).attr("cy", 18).attr("r", 6);
-  marker.append("path").attr("stroke", conf2.stroke).attr("fill", "none").attr("d", "M0,18 Q18,0 36,18 Q18,36 0,18");
-  marker = elem.append("defs").append("marker").attr("id", ERMarkers.ZERO_OR_MORE_END).attr("refX", 39).attr("refY", 18).attr("markerWidth", 57).attr("markerHeight", 36).attr("orient", "auto");
-  marker.append("circle").attr("stroke", conf2.stroke).attr("fill", "white").attr("cx", 9).attr("cy", 18).attr("r", 6);
-  marker.append("path").attr("stroke", conf2.stroke).attr("fill", "none").attr("d", "M21,18 Q39,0 57,18 Q39,36 21,18");
-  return;
-};
-const erMarkers = {
-  ERMarkers,
-  insertMarkers
-};
-const BAD_ID_CHARS_REGEXP = /[^\dA-Za-z](\W)*/g;
-let conf = {};
-let entityNameIds = /* @__PURE__ */ new Map();
-const setConf = function(cnf) {
-  const keys = Object.keys(cnf);
-  for (const key of keys) {
-    conf[key] = cnf[key];
-  }
-};
-const drawAttributes = (groupNode, entityTextNode, attributes) => {
-  const heightPadding = conf.entityPadding / 3;
-  const widthPadding = conf.entityPadding / 3;
-  const attrFontSize = conf.fontSize * 0.85;
-  const labelBBox = entityTextNode.node().getBBox();
-  const attributeNodes = [];
-  let hasKeyType = false;
-  let hasComment = false;
-  let maxTypeWidth = 0;
-  let maxNameWidth = 0;
-  let maxKeyWidth = 0;
-  let maxCommentWidth = 0;
-  let cumulativeHeight = labelBBox.height + heightPadding * 2;
-  let attrNum = 1;
-  attributes.forEach((item) => {
-    if (item.attributeKeyTypeList !== void 0 && item.attributeKeyTypeList.length > 0) {
-      hasKeyType = true;
-    }
-    if (item.attributeComment !== void 0) {
-      hasComment = true;
-    }
-  });
-  attributes.forEach((item) => {
-    const attrPrefix = `${entityTextNode.node().id}-attr-${attrNum}`;
-    let nodeHeight = 0;
-    const attributeType = parseGenericTypes(item.attributeType);
-    const typeNode = groupNode.append("text").classed("er entityLabel", true).attr("id", `${attrPrefix}-type`).attr("x", 0).attr("y", 0).style("dominant-baseline", "middle").style("text-anchor", "left").style("font-family", getConfig().fontFamily).style("font-size", attrFontSize + "px").text(attributeType);
-    const nameNode = groupNode.append("text").classed("er entityLabel", true).attr("id", `${attrPrefix}-name`).attr("x", 0).attr("y", 0).style("dominant-baseline", "middle").style("text-anchor", "left").style("font-family", getConfig().fontFamily).style("font-size", attrFontSize + "px").text(item.attributeName);
-    const attributeNode = {};
-    attributeNode.tn = typeNode;
-    attributeNode.nn = nameNode;
-    const typeBBox = typeNode.node().getBBox();
-    const nameBBox = nameNode.node().getBBox();
-    maxTypeWidth = Math.max(maxTypeWidth, typeBBox.width);
-    maxNameWidth = Math.max(maxNameWidth, nameBBox.width);
-    nodeHeight = Math.max(typeBBox.height, nameBBox.height);
-    if (hasKeyType) {
-      const keyTypeNodeText = item.attributeKeyTypeList !== void 0 ? item.attributeKeyTypeList.join(",") : "";
-      const keyTypeNode = groupNode.append("text").classed("er entityLabel", true).attr("id", `${attrPrefix}-key`).attr("x", 0).attr("y", 0).style("dominant-baseline", "middle").style("text-anchor", "left").style("font-family", getConfig().fontFamily).style("font-size", attrFontSize + "px").text(keyTypeNodeText);
-      attributeNode.kn = keyTypeNode;
-      const keyTypeBBox = keyTypeNode.node().getBBox();
-      maxKeyWidth = Math.max(maxKeyWidth, keyTypeBBox.width);
-      nodeHeight = Math.max(nodeHeight, keyTypeBBox.height);
-    }
-    if (hasComment) {
-      const commentNode = groupNode.append("text").classed("er entityLabel", true).attr("id", `${attrPrefix}-comment`).attr("x", 0).attr("y", 0).style("dominant-baseline", "middle").style("text-anchor", "left").style("font-family", getConfig().fontFamily).style("font-size", attrFontSize + "px").text(item.attributeComment || "");
-      attributeNode.cn = commentNode;
-      const commentNodeBBox = commentNode.node().getBBox();
-      maxCommentWidth = Math.max(maxCommentWidth, commentNodeBBox.width);
-      nodeHeight = Math.max(nodeHeight, commentNodeBBox.height);
-    }
-    attributeNode.height = nodeHeight;
-    attributeNodes.push(attributeNode);
-    cumulativeHeight += nodeHeight + heightPadding * 2;
-    attrNum += 1;
-  });
-  let widthPaddingFactor = 4;
-  if (hasKeyType) {
-    widthPaddingFactor += 2;
-  }
-  if (hasComment) {
-    widthPaddingFactor += 2;
-  }
-  const maxWidth = maxTypeWidth + maxNameWidth + maxKeyWidth + maxCommentWidth;
-  const bBox = {
-    width: Math.max(
-      conf.minEntityWidth,
-      Math.max(
-        labelBBox.width + conf.entityPadding * 2,
-        maxWidth + widthPadding * widthPaddingFactor
-      )
-    ),
-    height: attributes.length > 0 ? cumulativeHeight : Math.max(conf.minEntityHeight, labelBBox.height + conf.entityPadding * 2)
-  };
-  if (attributes.length > 0) {
-    const spareColumnWidth = Math.max(
-      0,
-      (bBox.width - maxWidth - widthPadding * widthPaddingFactor) / (widthPaddingFactor / 2)
-    );
-    entityTextNode.attr(
-      "transform",
-      "translate(" + bBox.width / 2 + "," + (heightPadding + labelBBox.height / 2) + ")"
-    );
-    let heightOffset = labelBBox.height + heightPadding * 2;
-    let attribStyle = "attributeBoxOdd";
-    attributeNodes.forEach((attributeNode) => {
-      const alignY = heightOffset + heightPadding + attributeNode.height / 2;
-      attributeNode.tn.attr("transform", "translate(" + widthPadding + "," + alignY + ")");
-      const typeRect = groupNode.insert("rect", "#" + attributeNode.tn.node().id).classed(`er ${attribStyle}`, true).attr("x", 0).attr("y", heightOffset).attr("width", maxTypeWidth + widthPadding * 2 + spareColumnWidth).attr("height", attributeNode.height + heightPadding * 2);
-      const nameXOffset = parseFloat(typeRect.attr("x")) + parseFloat(typeRect.attr("width"));
-      attributeNode.nn.attr(
-        "transform",
-        "translate(" + (nameXOffset + widthPadding) + "," + alignY + ")"
-      );
-      const nameRect = groupNode.insert("rect", "#" + attributeNode.nn.node().id).classed(`er ${attribStyle}`, true).attr("x", nameXOffset).attr("y", heightOffset).attr("width", maxNameWidth + widthPadding * 2 + spareColumnWidth).attr("height", attributeNode.height + heightPadding * 2);
-      let keyTypeAndCommentXOffset = parseFloat(nameRect.attr("x")) + parseFloat(nameRect.attr("width"));
-      if (hasKeyType) {
-        attributeNode.kn.attr(
-          "transform",
-          "translate(" + (keyTypeAndCommentXOffset + widthPadding) + "," + alignY + ")"
-        );
-        const keyTypeRect = groupNode.insert("rect", "#" + attributeNode.kn.node().id).classed(`er ${attribStyle}`, true).attr("x", keyTypeAndCommentXOffset).attr("y", heightOffset).attr("width", maxKeyWidth + widthPadding * 2 + spareColumnWidth).attr("height", attributeNode.height + heightPadding * 2);
-        keyTypeAndCommentXOffset = parseFloat(keyTypeRect.attr("x")) + parseFloat(keyTypeRect.attr("width"));
-      }
-      if (hasComment) {
-        attributeNode.cn.attr(
-          "transform",
-          "translate(" + (keyTypeAndCommentXOffset + widthPadding) + "," + alignY + ")"
-        );
-        groupNode.insert("rect", "#" + attributeNode.cn.node().id).classed(`er ${attribStyle}`, "true").attr("x", keyTypeAndCommentXOffset).attr("y", heightOffset).attr("width", maxCommentWidth + widthPadding * 2 + spareColumnWidth).attr("height", attributeNode.height + heightPadding * 2);
-      }
-      heightOffset += attributeNode.height + heightPadding * 2;
-      attribStyle = attribStyle === "attributeBoxOdd" ? "attributeBoxEven" : "attributeBoxOdd";
-    });
-  } else {
-    bBox.height = Math.max(conf.minEntityHeight, cumulativeHeight);
-    entityTextNode.attr("transform", "translate(" + bBox.width / 2 + "," + bBox.height / 2 + ")");
-  }
-  return bBox;
-};
-const drawEntities = function(svgNode, entities2, graph) {
-  const keys = Object.keys(entities2);
-  let firstOne;
-  keys.forEach(function(entityName) {
-    const entityId = generateId(entityName, "entity");
-    entityNameIds.set(entityName, entityId);
-    const groupNode = svgNode.append("g").attr("id", entityId);
-    firstOne = firstOne === void 0 ? entityId : firstOne;
-    const textId = "text-" + entityId;
-    const textNode = groupNode.append("text").classed("er entityLabel", true).attr("id", textId).attr("x", 0).attr("y", 0).style("dominant-baseline", "middle").style("text-anchor", "middle").style("font-family", getConfig().fontFamily).style("font-size", conf.fontSize + "px").text(entityName);
-    const { width: entityWidth, height: entityHeight } = drawAttributes(
-      groupNode,
-      textNode,
-      entities2[entityName].attributes
-    );
-    const rectNode = groupNode.insert("rect", "#" + textId).classed("er entityBox", true).attr("x", 0).attr("y", 0).attr("width", entityWidth).attr("height", entityHeight);
-    const rectBBox = rectNode.node().getBBox();
-    graph.setNode(entityId, {
-      width: rectBBox.width,
-      height: rectBBox.height,
-      shape: "rect",
-      id: entityId
-    });
-  });
-  return firstOne;
-};
-const adjustEntities = function(svgNode, graph) {
-  graph.nodes().forEach(function(v) {
-    if (v !== void 0 && graph.node(v) !== void 0) {
-      svgNode.select("#" + v).attr(
-        "transform",
-        "translate(" + (graph.node(v).x - graph.node(v).width / 2) + "," + (graph.node(v).y - graph.node(v).height / 2) + " )"
-      );
-    }
-  });
-};
-const getEdgeName = function(rel) {
-  return (rel.entityA + rel.roleA + rel.entityB).replace(/\s/g, "");
-};
-const addRelationships = function(relationships2, g) {
-  relationships2.forEach(function(r) {
-    g.setEdge(
-      entityNameIds.get(r.entityA),
-      entityNameIds.get(r.entityB),
-      { relationship: r },
-      getEdgeName(r)
-    );
-  });
-  return relationships2;
-};
-let relCnt = 0;
-const drawRelationshipFromLayout = function(svg, rel, g, insert, diagObj) {
-  relCnt++;
-  const edge = g.edge(
-    entityNameIds.get(rel.entityA),
-    entityNameIds.get(rel.entityB),
-    getEdgeName(rel)
-  );
-  const lineFunction = line().x(function(d) {
-    return d.x;
-  }).y(function(d) {
-    return d.y;
-  }).curve(curveBasis);
-  const svgPath = svg.insert("path", "#" + insert).classed("er relationshipLine", true).attr("d", lineFunction(edge.points)).style("stroke", conf.stroke).style("fill", "none");
-  if (rel.relSpec.relType === diagObj.db.Identification.NON_IDENTIFYING) {
-    svgPath.attr("stroke-dasharray", "8,8");
-  }
-  let url = "";
-  if (conf.arrowMarkerAbsolute) {
-    url = window.location.protocol + "//" + window.location.host + window.location.pathname + window.location.search;
-    url = url.replace(/\(/g, "\\(");
-    url = url.replace(/\)/g, "\\)");
-  }
-  switch (rel.relSpec.cardA) {
-    case diagObj.db.Cardinality.ZERO_OR_ONE:
-      svgPath.attr("marker-end", "url(" + url + "#" + erMarkers.ERMarkers.ZERO_OR_ONE_END + ")");
-      break;
-    case diagObj.db.Cardinality.ZERO_OR_MORE:
-      svgPath.attr("marker-end", "url(" + url + "#" + erMarkers.ERMarkers.ZERO_OR_MORE_END + ")");
-      break;
-    case diagObj.db.Cardinality.ONE_OR_MORE:
-      svgPath.attr("marker-end", "url(" + url + "#" + erMarkers.ERMarkers.ONE_OR_MORE_END + ")");
-      break;
-    case diagObj.db.Cardinality.ONLY_ONE:
-      svgPath.attr("marker-end", "url(" + url + "#" + erMarkers.ERMarkers.ONLY_ONE_END + ")");
-      break;
-  }
-  switch (rel.relSpec.cardB) {
-    case diagObj.db.Cardinality.ZERO_OR_ONE:
-      svgPath.attr(
-        "marker-start",
-        "url(" + url + "#" + erMarkers.ERMarkers.ZERO_OR_ONE_START + ")"
-      );
-      break;
-    case diagObj.db.Cardinality.ZERO_OR_MORE:
-      svgPath.attr(
-        "marker-start",
-        "url(" + url + "#" + erMarkers.ERMarkers.ZERO_OR_MORE_START + ")"
-      );
-      break;
-    case diagObj.db.Cardinality.ONE_OR_MORE:
-      svgPath.attr(
-        "marker-start",
-        "url(" + url + "#" + erMarkers.ERMarkers.ONE_OR_MORE_START + ")"
-      );
-      break;
-    case diagObj.db.Cardinality.ONLY_ONE:
-      svgPath.attr("marker-start", "url(" + url + "#" + erMarkers.ERMarkers.ONLY_ONE_START + ")");
-      break;
-  }
-  const len = svgPath.node().getTotalLength();
-  const labelPoint = svgPath.node().getPointAtLength(len * 0.5);
-  const labelId = "rel" + relCnt;
-  const labelNode = svg.append("text").classed("er relationshipLabel", true).attr("id", labelId).attr("x", labelPoint.x).attr("y", labelPoint.y).style("text-anchor", "middle").style("dominant-baseline", "middle").style("font-family", getConfig().fontFamily).style("font-size", conf.fontSize + "px").text(rel.roleA);
-  const labelBBox = labelNode.node().getBBox();
-  svg.insert("rect", "#" + labelId).classed("er relationshipLabelBox", true).attr("x", labelPoint.x - labelBBox.width / 2).attr("y", labelPoint.y - labelBBox.height / 2).attr("width", labelBBox.width).attr("height", labelBBox.height);
-};
-const draw = function(text, id, _version, diagObj) {
-  conf = getConfig().er;
-  log.info("Drawing ER diagram");
-  const securityLevel = getConfig().securityLevel;
-  let sandboxElement;
-  if (securityLevel === "sandbox") {
-    sandboxElement = select("#i" + id);
-  }
-  const root = securityLevel === "sandbox" ? select(sandboxElement.nodes()[0].contentDocument.body) : select("body");
-  const svg = root.select(`[id='${id}']`);
-  erMarkers.insertMarkers(svg, conf);
-  let g;
-  g = new graphlib.Graph({
-    multigraph: true,
-    directed: true,
-    compound: false
-  }).setGraph({
-    rankdir: conf.layoutDirection,
-    marginx: 20,
-    marginy: 20,
-    nodesep: 100,
-    edgesep: 100,
-    ranksep: 100
-  }).setDefaultEdgeLabel(function() {
-    return {};
-  });
-  const firstEntity = drawEntities(svg, diagObj.db.getEntities(), g);
-  const relationships2 = addRelationships(diagObj.db.getRelationships(), g);
-  layout(g);
-  adjustEntities(svg, g);
-  relationships2.forEach(function(rel) {
-    drawRelationshipFromLayout(svg, rel, g, firstEntity, diagObj);
-  });
-  const padding = conf.diagramPadding;
-  utils.insertTitle(svg, "entityTitleText", conf.titleTopMargin, diagObj.db.getDiagramTitle());
-  const svgBounds = svg.node().getBBox();
-  const width = svgBounds.width + padding * 2;
-  const height = svgBounds.height + padding * 2;
-  configureSvgSize(svg, height, width, conf.useMaxWidth);
-  svg.attr("viewBox", `${svgBounds.x - padding} ${svgBounds.y - padding} ${width} ${height}`);
-};
-const MERMAID_ERDIAGRAM_UUID = "28e9f9db-3c8d-5aa5-9faf-44286ae5937c";
-function generateId(str = "", prefix = "") {
-  const simplifiedStr = str.replace(BAD_ID_CHARS_REGEXP, "");
-  return `${strWithHyphen(prefix)}${strWithHyphen(simplifiedStr)}${v5(
-    str,
-    MERMAID_ERDIAGRAM_UUID
-  )}`;
-}
-function strWithHyphen(str = "") {
-  return str.length > 0 ? `${str}-` : "";
-}
-const erRenderer = {
-  setConf,
-  draw
-};
-const getStyles = (options) => `
-  .entityBox {
-    fill: ${options.mainBkg};
-    stroke: ${options.nodeBorder};
-  }
-
-  .attributeBoxOdd {
-    fill: ${options.attributeBackgroundColorOdd};
-    stroke: ${options.nodeBorder};
-  }
-
-  .attributeBoxEven {
-    fill:  ${options.attributeBackgroundColorEven};
-    stroke: ${options.nodeBorder};
-  }
-
-  .relationshipLabelBox {
-    fill: ${options.tertiaryColor};
-    opacity: 0.7;
-    background-color: ${options.tertiaryColor};
-      rect {
-        opacity: 0.5;
-      }
-  }
-
-    .relationshipLine {
-      stroke: ${options.lineColor};
-    }
-
-  .entityTitleText {
-    text-anchor: middle;
-    font-size: 18px;
-    fill: ${options.textColor};
-  }    
-`;
-const erStyles = getStyles;
-const diagram = {
-  parser: erParser,
-  db: erDb,
-  renderer: erRenderer,
-  styles: erStyles
-};
-export {
-  diagram
-};
-//# sourceMappingURL=erDiagram-20cc9db4.js.map
@@ -1,3 +0,0 @@
-export declare class UnknownDiagramError extends Error {
-    constructor(message: string);
-}
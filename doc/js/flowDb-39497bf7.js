@@ -1,1116 +0,0 @@
-import { k as Ne, q as Oe, v as Pe, w as we, x as Ue, y as Me, z as Ge, g as w2, l as U2, A as Ke, f as je, h as i2 } from "./commonDb-41f8b4c5.js";
-import { u as M2 } from "./utils-8ea37061.js";
-import { m as We } from "./mermaidAPI-67f627de.js";
-var O2 = function() {
-  var e = function(H1, a, o, T) {
-    for (o = o || {}, T = H1.length; T--; o[H1[T]] = a)
-      ;
-    return o;
-  }, u = [1, 9], n = [1, 7], i = [1, 6], c = [1, 8], _ = [1, 20, 21, 22, 23, 38, 44, 46, 48, 52, 66, 67, 87, 88, 89, 90, 91, 92, 96, 106, 107, 110, 112, 113, 119, 120, 121, 122, 123, 124, 125, 126, 127, 128], B = [2, 10], u1 = [1, 20], H = [1, 21], s1 = [1, 22], A1 = [1, 23], Y1 = [1, 30], Q1 = [1, 32], X1 = [1, 33], s2 = [1, 34], q = [1, 62], c1 = [1, 48], m1 = [1, 52], o2 = [1, 36], l2 = [1, 37], h2 = [1, 38], f2 = [1, 39], A2 = [1, 40], V1 = [1, 56], x1 = [1, 63], v1 = [1, 51], R1 = [1, 53], L1 = [1, 55], I1 = [1, 59], N1 = [1, 60], d2 = [1, 41], p2 = [1, 42], E2 = [1, 43], b2 = [1, 44], O1 = [1, 61], P1 = [1, 50], w1 = [1, 54], U1 = [1, 57], M1 = [1, 58], G1 = [1, 49], j2 = [1, 66], u2 = [1, 71], Q = [1, 20, 21, 22, 23, 38, 42, 44, 46, 48, 52, 66, 67, 87, 88, 89, 90, 91, 92, 96, 106, 107, 110, 112, 113, 119, 120, 121, 122, 123, 124, 125, 126, 127, 128], d1 = [1, 75], p1 = [1, 74], E1 = [1, 76], k2 = [20, 21, 23, 81, 82], n1 = [1, 99], l = [1, 104], h = [1, 107], f = [1, 108], r1 = [1, 101], A = [1, 106], d = [1, 109], i1 = [1, 102], p = [1, 114], E = [1, 113], b = [1, 103], k = [1, 105], D = [1, 110], g = [1, 111], F = [1, 112], C = [1, 115], S2 = [20, 21, 22, 23, 81, 82], t1 = [20, 21, 22, 23, 53, 81, 82], Z = [20, 21, 22, 23, 40, 52, 53, 55, 57, 59, 61, 63, 65, 66, 67, 69, 71, 73, 74, 76, 81, 82, 92, 96, 106, 107, 110, 112, 113, 123, 124, 125, 126, 127, 128], Y = [20, 21, 23], W2 = [20, 21, 23, 52, 66, 67, 81, 82, 92, 96, 106, 107, 110, 112, 113, 123, 124, 125, 126, 127, 128], m2 = [1, 12, 20, 21, 22, 23, 24, 38, 42, 44, 46, 48, 52, 66, 67, 87, 88, 89, 90, 91, 92, 96, 106, 107, 110, 112, 113, 119, 120, 121, 122, 123, 124, 125, 126, 127, 128], n2 = [52, 66, 67, 92, 96, 106, 107, 110, 112, 113, 123, 124, 125, 126, 127, 128], S = [1, 150], m = [1, 158], V = [1, 159], x = [1, 160], v = [1, 161], R = [1, 145], L = [1, 146], J = [1, 141], $ = [1, 142], I = [1, 153], N = [1, 154], O = [1, 155], P = [1, 156], w = [1, 157], U = [1, 162], M = [1, 163], G = [1, 148], K = [1, 151], j = [1, 147], W = [1, 144], V2 = [20, 21, 22, 23, 38, 42, 44, 46, 48, 52, 66, 67, 87, 88, 89, 90, 91, 92, 96, 106, 107, 110, 112, 113, 119, 120, 121, 122, 123, 124, 125, 126, 127, 128], Y2 = [1, 166], $1 = [20, 21, 22, 23, 26, 52, 66, 67, 92, 106, 107, 110, 112, 113, 123, 124, 125, 126, 127, 128], y = [20, 21, 22, 23, 24, 26, 38, 40, 41, 42, 52, 56, 58, 60, 62, 64, 66, 67, 68, 70, 72, 73, 75, 77, 81, 82, 87, 88, 89, 90, 91, 92, 93, 96, 106, 107, 110, 112, 113, 114, 115, 123, 124, 125, 126, 127, 128], z2 = [12, 21, 22, 24], H2 = [22, 107], b1 = [1, 251], k1 = [1, 246], D1 = [1, 247], g1 = [1, 255], F1 = [1, 252], C1 = [1, 249], _1 = [1, 248], B1 = [1, 250], T1 = [1, 253], y1 = [1, 254], S1 = [1, 256], z1 = [1, 274], Q2 = [20, 21, 23, 107], o1 = [20, 21, 22, 23, 66, 67, 87, 103, 106, 107, 110, 111, 112, 113, 114], x2 = {
-    trace: function() {
-    },
-    yy: {},
-    symbols_: { error: 2, start: 3, mermaidDoc: 4, directive: 5, openDirective: 6, typeDirective: 7, closeDirective: 8, separator: 9, ":": 10, argDirective: 11, open_directive: 12, type_directive: 13, arg_directive: 14, close_directive: 15, graphConfig: 16, document: 17, line: 18, statement: 19, SEMI: 20, NEWLINE: 21, SPACE: 22, EOF: 23, GRAPH: 24, NODIR: 25, DIR: 26, FirstStmtSeperator: 27, ending: 28, endToken: 29, spaceList: 30, spaceListNewline: 31, verticeStatement: 32, styleStatement: 33, linkStyleStatement: 34, classDefStatement: 35, classStatement: 36, clickStatement: 37, subgraph: 38, text: 39, SQS: 40, SQE: 41, end: 42, direction: 43, acc_title: 44, acc_title_value: 45, acc_descr: 46, acc_descr_value: 47, acc_descr_multiline_value: 48, link: 49, node: 50, vertex: 51, AMP: 52, STYLE_SEPARATOR: 53, idString: 54, DOUBLECIRCLESTART: 55, DOUBLECIRCLEEND: 56, PS: 57, PE: 58, "(-": 59, "-)": 60, STADIUMSTART: 61, STADIUMEND: 62, SUBROUTINESTART: 63, SUBROUTINEEND: 64, VERTEX_WITH_PROPS_START: 65, ALPHA: 66, COLON: 67, PIPE: 68, CYLINDERSTART: 69, CYLINDEREND: 70, DIAMOND_START: 71, DIAMOND_STOP: 72, TAGEND: 73, TRAPSTART: 74, TRAPEND: 75, INVTRAPSTART: 76, INVTRAPEND: 77, linkStatement: 78, arrowText: 79, TESTSTR: 80, START_LINK: 81, LINK: 82, textToken: 83, STR: 84, MD_STR: 85, keywords: 86, STYLE: 87, LINKSTYLE: 88, CLASSDEF: 89, CLASS: 90, CLICK: 91, DOWN: 92, UP: 93, textNoTags: 94, textNoTagsToken: 95, DEFAULT: 96, stylesOpt: 97, alphaNum: 98, CALLBACKNAME: 99, CALLBACKARGS: 100, HREF: 101, LINK_TARGET: 102, HEX: 103, numList: 104, INTERPOLATE: 105, NUM: 106, COMMA: 107, style: 108, styleComponent: 109, MINUS: 110, UNIT: 111, BRKT: 112, DOT: 113, PCT: 114, TAGSTART: 115, alphaNumToken: 116, idStringToken: 117, alphaNumStatement: 118, direction_tb: 119, direction_bt: 120, direction_rl: 121, direction_lr: 122, PUNCTUATION: 123, UNICODE_TEXT: 124, PLUS: 125, EQUALS: 126, MULT: 127, UNDERSCORE: 128, graphCodeTokens: 129, ARROW_CROSS: 130, ARROW_POINT: 131, ARROW_CIRCLE: 132, ARROW_OPEN: 133, QUOTE: 134, $accept: 0, $end: 1 },
-    terminals_: { 2: "error", 10: ":", 12: "open_directive", 13: "type_directive", 14: "arg_directive", 15: "close_directive", 20: "SEMI", 21: "NEWLINE", 22: "SPACE", 23: "EOF", 24: "GRAPH", 25: "NODIR", 26: "DIR", 38: "subgraph", 40: "SQS", 41: "SQE", 42: "end", 44: "acc_title", 45: "acc_title_value", 46: "acc_descr", 47: "acc_descr_value", 48: "acc_descr_multiline_value", 52: "AMP", 53: "STYLE_SEPARATOR", 55: "DOUBLECIRCLESTART", 56: "DOUBLECIRCLEEND", 57: "PS", 58: "PE", 59: "(-", 60: "-)", 61: "STADIUMSTART", 62: "STADIUMEND", 63: "SUBROUTINESTART", 64: "SUBROUTINEEND", 65: "VERTEX_WITH_PROPS_START", 66: "ALPHA", 67: "COLON", 68: "PIPE", 69: "CYLINDERSTART", 70: "CYLINDEREND", 71: "DIAMOND_START", 72: "DIAMOND_STOP", 73: "TAGEND", 74: "TRAPSTART", 75: "TRAPEND", 76: "INVTRAPSTART", 77: "INVTRAPEND", 80: "TESTSTR", 81: "START_LINK", 82: "LINK", 84: "STR", 85: "MD_STR", 87: "STYLE", 88: "LINKSTYLE", 89: "CLASSDEF", 90: "CLASS", 91: "CLICK", 92: "DOWN", 93: "UP", 96: "DEFAULT", 99: "CALLBACKNAME", 100: "CALLBACKARGS", 101: "HREF", 102: "LINK_TARGET", 103: "HEX", 105: "INTERPOLATE", 106: "NUM", 107: "COMMA", 110: "MINUS", 111: "UNIT", 112: "BRKT", 113: "DOT", 114: "PCT", 115: "TAGSTART", 119: "direction_tb", 120: "direction_bt", 121: "direction_rl", 122: "direction_lr", 123: "PUNCTUATION", 124: "UNICODE_TEXT", 125: "PLUS", 126: "EQUALS", 127: "MULT", 128: "UNDERSCORE", 130: "ARROW_CROSS", 131: "ARROW_POINT", 132: "ARROW_CIRCLE", 133: "ARROW_OPEN", 134: "QUOTE" },
-    productions_: [0, [3, 1], [3, 2], [5, 4], [5, 6], [6, 1], [7, 1], [11, 1], [8, 1], [4, 2], [17, 0], [17, 2], [18, 1], [18, 1], [18, 1], [18, 1], [18, 1], [16, 2], [16, 2], [16, 2], [16, 3], [28, 2], [28, 1], [29, 1], [29, 1], [29, 1], [27, 1], [27, 1], [27, 2], [31, 2], [31, 2], [31, 1], [31, 1], [30, 2], [30, 1], [19, 2], [19, 2], [19, 2], [19, 2], [19, 2], [19, 2], [19, 9], [19, 6], [19, 4], [19, 1], [19, 2], [19, 2], [19, 1], [9, 1], [9, 1], [9, 1], [32, 3], [32, 4], [32, 2], [32, 1], [50, 1], [50, 5], [50, 3], [51, 4], [51, 4], [51, 6], [51, 4], [51, 4], [51, 4], [51, 8], [51, 4], [51, 4], [51, 4], [51, 6], [51, 4], [51, 4], [51, 4], [51, 4], [51, 4], [51, 1], [49, 2], [49, 3], [49, 3], [49, 1], [49, 3], [78, 1], [79, 3], [39, 1], [39, 2], [39, 1], [39, 1], [86, 1], [86, 1], [86, 1], [86, 1], [86, 1], [86, 1], [86, 1], [86, 1], [86, 1], [86, 1], [86, 1], [94, 1], [94, 2], [35, 5], [35, 5], [36, 5], [37, 2], [37, 4], [37, 3], [37, 5], [37, 2], [37, 4], [37, 4], [37, 6], [37, 2], [37, 4], [37, 2], [37, 4], [37, 4], [37, 6], [33, 5], [33, 5], [34, 5], [34, 5], [34, 9], [34, 9], [34, 7], [34, 7], [104, 1], [104, 3], [97, 1], [97, 3], [108, 1], [108, 2], [109, 1], [109, 1], [109, 1], [109, 1], [109, 1], [109, 1], [109, 1], [109, 1], [109, 1], [109, 1], [109, 1], [83, 1], [83, 1], [83, 1], [83, 1], [83, 1], [83, 1], [95, 1], [95, 1], [95, 1], [95, 1], [54, 1], [54, 2], [98, 1], [98, 2], [118, 1], [118, 1], [118, 1], [118, 1], [43, 1], [43, 1], [43, 1], [43, 1], [116, 1], [116, 1], [116, 1], [116, 1], [116, 1], [116, 1], [116, 1], [116, 1], [116, 1], [116, 1], [116, 1], [116, 1], [116, 1], [117, 1], [117, 1], [117, 1], [117, 1], [117, 1], [117, 1], [117, 1], [117, 1], [117, 1], [117, 1], [117, 1], [117, 1], [117, 1], [117, 1], [117, 1], [117, 1], [129, 1], [129, 1], [129, 1], [129, 1], [129, 1], [129, 1], [129, 1], [129, 1], [129, 1], [129, 1], [129, 1], [129, 1], [129, 1], [129, 1], [129, 1], [129, 1], [129, 1], [129, 1], [129, 1], [129, 1], [129, 1], [129, 1], [129, 1], [129, 1], [129, 1], [129, 1]],
-    performAction: function(a, o, T, r, X, t, r2) {
-      var s = t.length - 1;
-      switch (X) {
-        case 5:
-          r.parseDirective("%%{", "open_directive");
-          break;
-        case 6:
-          r.parseDirective(t[s], "type_directive");
-          break;
-        case 7:
-          t[s] = t[s].trim().replace(/'/g, '"'), r.parseDirective(t[s], "arg_directive");
-          break;
-        case 8:
-          r.parseDirective("}%%", "close_directive", "flowchart");
-          break;
-        case 10:
-          this.$ = [];
-          break;
-        case 11:
-          (!Array.isArray(t[s]) || t[s].length > 0) && t[s - 1].push(t[s]), this.$ = t[s - 1];
-          break;
-        case 12:
-        case 97:
-        case 153:
-        case 155:
-        case 156:
-          this.$ = t[s];
-          break;
-        case 19:
-          r.setDirection("TB"), this.$ = "TB";
-          break;
-        case 20:
-          r.setDirection(t[s - 1]), this.$ = t[s - 1];
-          break;
-        case 35:
-          this.$ = t[s - 1].nodes;
-          break;
-        case 36:
-        case 37:
-        case 38:
-        case 39:
-        case 40:
-          this.$ = [];
-          break;
-        case 41:
-          this.$ = r.addSubGraph(t[s - 6], t[s - 1], t[s - 4]);
-          break;
-        case 42:
-          this.$ = r.addSubGraph(t[s - 3], t[s - 1], t[s - 3]);
-          break;
-        case 43:
-          this.$ = r.addSubGraph(void 0, t[s - 1], void 0);
-          break;
-        case 45:
-          this.$ = t[s].trim(), r.setAccTitle(this.$);
-          break;
-        case 46:
-        case 47:
-          this.$ = t[s].trim(), r.setAccDescription(this.$);
-          break;
-        case 51:
-          r.addLink(t[s - 2].stmt, t[s], t[s - 1]), this.$ = { stmt: t[s], nodes: t[s].concat(t[s - 2].nodes) };
-          break;
-        case 52:
-          r.addLink(t[s - 3].stmt, t[s - 1], t[s - 2]), this.$ = { stmt: t[s - 1], nodes: t[s - 1].concat(t[s - 3].nodes) };
-          break;
-        case 53:
-          this.$ = { stmt: t[s - 1], nodes: t[s - 1] };
-          break;
-        case 54:
-          this.$ = { stmt: t[s], nodes: t[s] };
-          break;
-        case 55:
-          this.$ = [t[s]];
-          break;
-        case 56:
-          this.$ = t[s - 4].concat(t[s]);
-          break;
-        case 57:
-          this.$ = [t[s - 2]], r.setClass(t[s - 2], t[s]);
-          break;
-        case 58:
-          this.$ = t[s - 3], r.addVertex(t[s - 3], t[s - 1], "square");
-          break;
-        case 59:
-          this.$ = t[s - 3], r.addVertex(t[s - 3], t[s - 1], "doublecircle");
-          break;
-        case 60:
-          this.$ = t[s - 5], r.addVertex(t[s - 5], t[s - 2], "circle");
-          break;
-        case 61:
-          this.$ = t[s - 3], r.addVertex(t[s - 3], t[s - 1], "ellipse");
-          break;
-        case 62:
-          this.$ = t[s - 3], r.addVertex(t[s - 3], t[s - 1], "stadium");
-          break;
-        case 63:
-          this.$ = t[s - 3], r.addVertex(t[s - 3], t[s - 1], "subroutine");
-          break;
-        case 64:
-          this.$ = t[s - 7], r.addVertex(t[s - 7], t[s - 1], "rect", void 0, void 0, void 0, Object.fromEntries([[t[s - 5], t[s - 3]]]));
-          break;
-        case 65:
-          this.$ = t[s - 3], r.addVertex(t[s - 3], t[s - 1], "cylinder");
-          break;
-        case 66:
-          this.$ = t[s - 3], r.addVertex(t[s - 3], t[s - 1], "round");
-          break;
-        case 67:
-          this.$ = t[s - 3], r.addVertex(t[s - 3], t[s - 1], "diamond");
-          break;
-        case 68:
-          this.$ = t[s - 5], r.addVertex(t[s - 5], t[s - 2], "hexagon");
-          break;
-        case 69:
-          this.$ = t[s - 3], r.addVertex(t[s - 3], t[s - 1], "odd");
-          break;
-        case 70:
-          this.$ = t[s - 3], r.addVertex(t[s - 3], t[s - 1], "trapezoid");
-          break;
-        case 71:
-          this.$ = t[s - 3], r.addVertex(t[s - 3], t[s - 1], "inv_trapezoid");
-          break;
-        case 72:
-          this.$ = t[s - 3], r.addVertex(t[s - 3], t[s - 1], "lean_right");
-          break;
-        case 73:
-          this.$ = t[s - 3], r.addVertex(t[s - 3], t[s - 1], "lean_left");
-          break;
-        case 74:
-          this.$ = t[s], r.addVertex(t[s]);
-          break;
-        case 75:
-          t[s - 1].text = t[s], this.$ = t[s - 1];
-          break;
-        case 76:
-        case 77:
-          t[s - 2].text = t[s - 1], this.$ = t[s - 2];
-          break;
-        case 78:
-          this.$ = t[s];
-          break;
-        case 79:
-          var l1 = r.destructLink(t[s], t[s - 2]);
-          this.$ = { type: l1.type, stroke: l1.stroke, length: l1.length, text: t[s - 1] };
-          break;
-        case 80:
-          var l1 = r.destructLink(t[s]);
-          this.$ = { type: l1.type, stroke: l1.stroke, length: l1.length };
-          break;
-        case 81:
-          this.$ = t[s - 1];
-          break;
-        case 82:
-          this.$ = { text: t[s], type: "text" };
-          break;
-        case 83:
-          this.$ = { text: t[s - 1].text + "" + t[s], type: t[s - 1].type };
-          break;
-        case 84:
-          this.$ = { text: t[s], type: "text" };
-          break;
-        case 85:
-          this.$ = { text: t[s], type: "markdown" };
-          break;
-        case 98:
-        case 154:
-          this.$ = t[s - 1] + "" + t[s];
-          break;
-        case 99:
-        case 100:
-          this.$ = t[s - 4], r.addClass(t[s - 2], t[s]);
-          break;
-        case 101:
-          this.$ = t[s - 4], r.setClass(t[s - 2], t[s]);
-          break;
-        case 102:
-        case 110:
-          this.$ = t[s - 1], r.setClickEvent(t[s - 1], t[s]);
-          break;
-        case 103:
-        case 111:
-          this.$ = t[s - 3], r.setClickEvent(t[s - 3], t[s - 2]), r.setTooltip(t[s - 3], t[s]);
-          break;
-        case 104:
-          this.$ = t[s - 2], r.setClickEvent(t[s - 2], t[s - 1], t[s]);
-          break;
-        case 105:
-          this.$ = t[s - 4], r.setClickEvent(t[s - 4], t[s - 3], t[s - 2]), r.setTooltip(t[s - 4], t[s]);
-          break;
-        case 106:
-        case 112:
-          this.$ = t[s - 1], r.setLink(t[s - 1], t[s]);
-          break;
-        case 107:
-        case 113:
-          this.$ = t[s - 3], r.setLink(t[s - 3], t[s - 2]), r.setTooltip(t[s - 3], t[s]);
-          break;
-        case 108:
-        case 114:
-          this.$ = t[s - 3], r.setLink(t[s - 3], t[s - 2], t[s]);
-          break;
-        case 109:
-        case 115:
-          this.$ = t[s - 5], r.setLink(t[s - 5], t[s - 4], t[s]), r.setTooltip(t[s - 5], t[s - 2]);
-          break;
-        case 116:
-          this.$ = t[s - 4], r.addVertex(t[s - 2], void 0, void 0, t[s]);
-          break;
-        case 117:
-        case 119:
-          this.$ = t[s - 4], r.updateLink(t[s - 2], t[s]);
-          break;
-        case 118:
-          this.$ = t[s - 4], r.updateLink([t[s - 2]], t[s]);
-          break;
-        case 120:
-          this.$ = t[s - 8], r.updateLinkInterpolate([t[s - 6]], t[s - 2]), r.updateLink([t[s - 6]], t[s]);
-          break;
-        case 121:
-          this.$ = t[s - 8], r.updateLinkInterpolate(t[s - 6], t[s - 2]), r.updateLink(t[s - 6], t[s]);
-          break;
-        case 122:
-          this.$ = t[s - 6], r.updateLinkInterpolate([t[s - 4]], t[s]);
-          break;
-        case 123:
-          this.$ = t[s - 6], r.updateLinkInterpolate(t[s - 4], t[s]);
-          break;
-        case 124:
-        case 126:
-          this.$ = [t[s]];
-          break;
-        case 125:
-        case 127:
-          t[s - 2].push(t[s]), this.$ = t[s - 2];
-          break;
-        case 129:
-          this.$ = t[s - 1] + t[s];
-          break;
-        case 151:
-          this.$ = t[s];
-          break;
-        case 152:
-          this.$ = t[s - 1] + "" + t[s];
-          break;
-        case 157:
-          this.$ = "v";
-          break;
-        case 158:
-          this.$ = "-";
-          break;
-        case 159:
-          this.$ = { stmt: "dir", value: "TB" };
-          break;
-        case 160:
-          this.$ = { stmt: "dir", value: "BT" };
-          break;
-        case 161:
-          this.$ = { stmt: "dir", value: "RL" };
-          break;
-        case 162:
-          this.$ = { stmt: "dir", value: "LR" };
-          break;
-      }
-    },
-    table: [{ 3: 1, 4: 2, 5: 3, 6: 5, 12: u, 16: 4, 21: n, 22: i, 24: c }, { 1: [3] }, { 1: [2, 1] }, { 3: 10, 4: 2, 5: 3, 6: 5, 12: u, 16: 4, 21: n, 22: i, 24: c }, e(_, B, { 17: 11 }), { 7: 12, 13: [1, 13] }, { 16: 14, 21: n, 22: i, 24: c }, { 16: 15, 21: n, 22: i, 24: c }, { 25: [1, 16], 26: [1, 17] }, { 13: [2, 5] }, { 1: [2, 2] }, { 1: [2, 9], 18: 18, 19: 19, 20: u1, 21: H, 22: s1, 23: A1, 32: 24, 33: 25, 34: 26, 35: 27, 36: 28, 37: 29, 38: Y1, 43: 31, 44: Q1, 46: X1, 48: s2, 50: 35, 51: 45, 52: q, 54: 46, 66: c1, 67: m1, 87: o2, 88: l2, 89: h2, 90: f2, 91: A2, 92: V1, 96: x1, 106: v1, 107: R1, 110: L1, 112: I1, 113: N1, 117: 47, 119: d2, 120: p2, 121: E2, 122: b2, 123: O1, 124: P1, 125: w1, 126: U1, 127: M1, 128: G1 }, { 8: 64, 10: [1, 65], 15: j2 }, e([10, 15], [2, 6]), e(_, [2, 17]), e(_, [2, 18]), e(_, [2, 19]), { 20: [1, 68], 21: [1, 69], 22: u2, 27: 67, 30: 70 }, e(Q, [2, 11]), e(Q, [2, 12]), e(Q, [2, 13]), e(Q, [2, 14]), e(Q, [2, 15]), e(Q, [2, 16]), { 9: 72, 20: d1, 21: p1, 23: E1, 49: 73, 78: 77, 81: [1, 78], 82: [1, 79] }, { 9: 80, 20: d1, 21: p1, 23: E1 }, { 9: 81, 20: d1, 21: p1, 23: E1 }, { 9: 82, 20: d1, 21: p1, 23: E1 }, { 9: 83, 20: d1, 21: p1, 23: E1 }, { 9: 84, 20: d1, 21: p1, 23: E1 }, { 9: 86, 20: d1, 21: p1, 22: [1, 85], 23: E1 }, e(Q, [2, 44]), { 45: [1, 87] }, { 47: [1, 88] }, e(Q, [2, 47]), e(k2, [2, 54], { 30: 89, 22: u2 }), { 22: [1, 90] }, { 22: [1, 91] }, { 22: [1, 92] }, { 22: [1, 93] }, { 26: n1, 52: l, 66: h, 67: f, 84: [1, 97], 92: r1, 98: 96, 99: [1, 94], 101: [1, 95], 106: A, 107: d, 110: i1, 112: p, 113: E, 116: 100, 118: 98, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, e(Q, [2, 159]), e(Q, [2, 160]), e(Q, [2, 161]), e(Q, [2, 162]), e(S2, [2, 55], { 53: [1, 116] }), e(t1, [2, 74], { 117: 129, 40: [1, 117], 52: q, 55: [1, 118], 57: [1, 119], 59: [1, 120], 61: [1, 121], 63: [1, 122], 65: [1, 123], 66: c1, 67: m1, 69: [1, 124], 71: [1, 125], 73: [1, 126], 74: [1, 127], 76: [1, 128], 92: V1, 96: x1, 106: v1, 107: R1, 110: L1, 112: I1, 113: N1, 123: O1, 124: P1, 125: w1, 126: U1, 127: M1, 128: G1 }), e(Z, [2, 151]), e(Z, [2, 176]), e(Z, [2, 177]), e(Z, [2, 178]), e(Z, [2, 179]), e(Z, [2, 180]), e(Z, [2, 181]), e(Z, [2, 182]), e(Z, [2, 183]), e(Z, [2, 184]), e(Z, [2, 185]), e(Z, [2, 186]), e(Z, [2, 187]), e(Z, [2, 188]), e(Z, [2, 189]), e(Z, [2, 190]), e(Z, [2, 191]), { 9: 130, 20: d1, 21: p1, 23: E1 }, { 11: 131, 14: [1, 132] }, e(Y, [2, 8]), e(_, [2, 20]), e(_, [2, 26]), e(_, [2, 27]), { 21: [1, 133] }, e(W2, [2, 34], { 30: 134, 22: u2 }), e(Q, [2, 35]), { 50: 135, 51: 45, 52: q, 54: 46, 66: c1, 67: m1, 92: V1, 96: x1, 106: v1, 107: R1, 110: L1, 112: I1, 113: N1, 117: 47, 123: O1, 124: P1, 125: w1, 126: U1, 127: M1, 128: G1 }, e(m2, [2, 48]), e(m2, [2, 49]), e(m2, [2, 50]), e(n2, [2, 78], { 79: 136, 68: [1, 138], 80: [1, 137] }), { 22: S, 24: m, 26: V, 38: x, 39: 139, 42: v, 52: l, 66: h, 67: f, 73: R, 81: L, 83: 140, 84: J, 85: $, 86: 152, 87: I, 88: N, 89: O, 90: P, 91: w, 92: U, 93: M, 95: 143, 96: G, 106: A, 107: d, 110: K, 112: p, 113: E, 114: j, 115: W, 116: 149, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, e([52, 66, 67, 68, 80, 92, 96, 106, 107, 110, 112, 113, 123, 124, 125, 126, 127, 128], [2, 80]), e(Q, [2, 36]), e(Q, [2, 37]), e(Q, [2, 38]), e(Q, [2, 39]), e(Q, [2, 40]), { 22: S, 24: m, 26: V, 38: x, 39: 164, 42: v, 52: l, 66: h, 67: f, 73: R, 81: L, 83: 140, 84: J, 85: $, 86: 152, 87: I, 88: N, 89: O, 90: P, 91: w, 92: U, 93: M, 95: 143, 96: G, 106: A, 107: d, 110: K, 112: p, 113: E, 114: j, 115: W, 116: 149, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, e(V2, B, { 17: 165 }), e(Q, [2, 45]), e(Q, [2, 46]), e(k2, [2, 53], { 52: Y2 }), { 26: n1, 52: l, 66: h, 67: f, 92: r1, 98: 167, 103: [1, 168], 106: A, 107: d, 110: i1, 112: p, 113: E, 116: 100, 118: 98, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, { 96: [1, 169], 104: 170, 106: [1, 171] }, { 26: n1, 52: l, 66: h, 67: f, 92: r1, 96: [1, 172], 98: 173, 106: A, 107: d, 110: i1, 112: p, 113: E, 116: 100, 118: 98, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, { 26: n1, 52: l, 66: h, 67: f, 92: r1, 98: 174, 106: A, 107: d, 110: i1, 112: p, 113: E, 116: 100, 118: 98, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, e(Y, [2, 102], { 22: [1, 175], 100: [1, 176] }), e(Y, [2, 106], { 22: [1, 177] }), e(Y, [2, 110], { 116: 100, 118: 179, 22: [1, 178], 26: n1, 52: l, 66: h, 67: f, 92: r1, 106: A, 107: d, 110: i1, 112: p, 113: E, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }), e(Y, [2, 112], { 22: [1, 180] }), e($1, [2, 153]), e($1, [2, 155]), e($1, [2, 156]), e($1, [2, 157]), e($1, [2, 158]), e(y, [2, 163]), e(y, [2, 164]), e(y, [2, 165]), e(y, [2, 166]), e(y, [2, 167]), e(y, [2, 168]), e(y, [2, 169]), e(y, [2, 170]), e(y, [2, 171]), e(y, [2, 172]), e(y, [2, 173]), e(y, [2, 174]), e(y, [2, 175]), { 52: q, 54: 181, 66: c1, 67: m1, 92: V1, 96: x1, 106: v1, 107: R1, 110: L1, 112: I1, 113: N1, 117: 47, 123: O1, 124: P1, 125: w1, 126: U1, 127: M1, 128: G1 }, { 22: S, 24: m, 26: V, 38: x, 39: 182, 42: v, 52: l, 66: h, 67: f, 73: R, 81: L, 83: 140, 84: J, 85: $, 86: 152, 87: I, 88: N, 89: O, 90: P, 91: w, 92: U, 93: M, 95: 143, 96: G, 106: A, 107: d, 110: K, 112: p, 113: E, 114: j, 115: W, 116: 149, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, { 22: S, 24: m, 26: V, 38: x, 39: 183, 42: v, 52: l, 66: h, 67: f, 73: R, 81: L, 83: 140, 84: J, 85: $, 86: 152, 87: I, 88: N, 89: O, 90: P, 91: w, 92: U, 93: M, 95: 143, 96: G, 106: A, 107: d, 110: K, 112: p, 113: E, 114: j, 115: W, 116: 149, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, { 22: S, 24: m, 26: V, 38: x, 39: 185, 42: v, 52: l, 57: [1, 184], 66: h, 67: f, 73: R, 81: L, 83: 140, 84: J, 85: $, 86: 152, 87: I, 88: N, 89: O, 90: P, 91: w, 92: U, 93: M, 95: 143, 96: G, 106: A, 107: d, 110: K, 112: p, 113: E, 114: j, 115: W, 116: 149, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, { 22: S, 24: m, 26: V, 38: x, 39: 186, 42: v, 52: l, 66: h, 67: f, 73: R, 81: L, 83: 140, 84: J, 85: $, 86: 152, 87: I, 88: N, 89: O, 90: P, 91: w, 92: U, 93: M, 95: 143, 96: G, 106: A, 107: d, 110: K, 112: p, 113: E, 114: j, 115: W, 116: 149, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, { 22: S, 24: m, 26: V, 38: x, 39: 187, 42: v, 52: l, 66: h, 67: f, 73: R, 81: L, 83: 140, 84: J, 85: $, 86: 152, 87: I, 88: N, 89: O, 90: P, 91: w, 92: U, 93: M, 95: 143, 96: G, 106: A, 107: d, 110: K, 112: p, 113: E, 114: j, 115: W, 116: 149, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, { 22: S, 24: m, 26: V, 38: x, 39: 188, 42: v, 52: l, 66: h, 67: f, 73: R, 81: L, 83: 140, 84: J, 85: $, 86: 152, 87: I, 88: N, 89: O, 90: P, 91: w, 92: U, 93: M, 95: 143, 96: G, 106: A, 107: d, 110: K, 112: p, 113: E, 114: j, 115: W, 116: 149, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, { 66: [1, 189] }, { 22: S, 24: m, 26: V, 38: x, 39: 190, 42: v, 52: l, 66: h, 67: f, 73: R, 81: L, 83: 140, 84: J, 85: $, 86: 152, 87: I, 88: N, 89: O, 90: P, 91: w, 92: U, 93: M, 95: 143, 96: G, 106: A, 107: d, 110: K, 112: p, 113: E, 114: j, 115: W, 116: 149, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, { 22: S, 24: m, 26: V, 38: x, 39: 191, 42: v, 52: l, 66: h, 67: f, 71: [1, 192], 73: R, 81: L, 83: 140, 84: J, 85: $, 86: 152, 87: I, 88: N, 89: O, 90: P, 91: w, 92: U, 93: M, 95: 143, 96: G, 106: A, 107: d, 110: K, 112: p, 113: E, 114: j, 115: W, 116: 149, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, { 22: S, 24: m, 26: V, 38: x, 39: 193, 42: v, 52: l, 66: h, 67: f, 73: R, 81: L, 83: 140, 84: J, 85: $, 86: 152, 87: I, 88: N, 89: O, 90: P, 91: w, 92: U, 93: M, 95: 143, 96: G, 106: A, 107: d, 110: K, 112: p, 113: E, 114: j, 115: W, 116: 149, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, { 22: S, 24: m, 26: V, 38: x, 39: 194, 42: v, 52: l, 66: h, 67: f, 73: R, 81: L, 83: 140, 84: J, 85: $, 86: 152, 87: I, 88: N, 89: O, 90: P, 91: w, 92: U, 93: M, 95: 143, 96: G, 106: A, 107: d, 110: K, 112: p, 113: E, 114: j, 115: W, 116: 149, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, { 22: S, 24: m, 26: V, 38: x, 39: 195, 42: v, 52: l, 66: h, 67: f, 73: R, 81: L, 83: 140, 84: J, 85: $, 86: 152, 87: I, 88: N, 89: O, 90: P, 91: w, 92: U, 93: M, 95: 143, 96: G, 106: A, 107: d, 110: K, 112: p, 113: E, 114: j, 115: W, 116: 149, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, e(Z, [2, 152]), e(z2, [2, 3]), { 8: 196, 15: j2 }, { 15: [2, 7] }, e(_, [2, 28]), e(W2, [2, 33]), e(k2, [2, 51], { 30: 197, 22: u2 }), e(n2, [2, 75], { 22: [1, 198] }), { 22: [1, 199] }, { 22: S, 24: m, 26: V, 38: x, 39: 200, 42: v, 52: l, 66: h, 67: f, 73: R, 81: L, 83: 140, 84: J, 85: $, 86: 152, 87: I, 88: N, 89: O, 90: P, 91: w, 92: U, 93: M, 95: 143, 96: G, 106: A, 107: d, 110: K, 112: p, 113: E, 114: j, 115: W, 116: 149, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, { 22: S, 24: m, 26: V, 38: x, 42: v, 52: l, 66: h, 67: f, 73: R, 81: L, 82: [1, 201], 83: 202, 86: 152, 87: I, 88: N, 89: O, 90: P, 91: w, 92: U, 93: M, 95: 143, 96: G, 106: A, 107: d, 110: K, 112: p, 113: E, 114: j, 115: W, 116: 149, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, e(y, [2, 82]), e(y, [2, 84]), e(y, [2, 85]), e(y, [2, 141]), e(y, [2, 142]), e(y, [2, 143]), e(y, [2, 144]), e(y, [2, 145]), e(y, [2, 146]), e(y, [2, 147]), e(y, [2, 148]), e(y, [2, 149]), e(y, [2, 150]), e(y, [2, 86]), e(y, [2, 87]), e(y, [2, 88]), e(y, [2, 89]), e(y, [2, 90]), e(y, [2, 91]), e(y, [2, 92]), e(y, [2, 93]), e(y, [2, 94]), e(y, [2, 95]), e(y, [2, 96]), { 9: 204, 20: d1, 21: p1, 22: S, 23: E1, 24: m, 26: V, 38: x, 40: [1, 203], 42: v, 52: l, 66: h, 67: f, 73: R, 81: L, 83: 202, 86: 152, 87: I, 88: N, 89: O, 90: P, 91: w, 92: U, 93: M, 95: 143, 96: G, 106: A, 107: d, 110: K, 112: p, 113: E, 114: j, 115: W, 116: 149, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, { 18: 18, 19: 19, 20: u1, 21: H, 22: s1, 23: A1, 32: 24, 33: 25, 34: 26, 35: 27, 36: 28, 37: 29, 38: Y1, 42: [1, 205], 43: 31, 44: Q1, 46: X1, 48: s2, 50: 35, 51: 45, 52: q, 54: 46, 66: c1, 67: m1, 87: o2, 88: l2, 89: h2, 90: f2, 91: A2, 92: V1, 96: x1, 106: v1, 107: R1, 110: L1, 112: I1, 113: N1, 117: 47, 119: d2, 120: p2, 121: E2, 122: b2, 123: O1, 124: P1, 125: w1, 126: U1, 127: M1, 128: G1 }, { 22: u2, 30: 206 }, { 22: [1, 207], 26: n1, 52: l, 66: h, 67: f, 92: r1, 106: A, 107: d, 110: i1, 112: p, 113: E, 116: 100, 118: 179, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, { 22: [1, 208] }, { 22: [1, 209] }, { 22: [1, 210], 107: [1, 211] }, e(H2, [2, 124]), { 22: [1, 212] }, { 22: [1, 213], 26: n1, 52: l, 66: h, 67: f, 92: r1, 106: A, 107: d, 110: i1, 112: p, 113: E, 116: 100, 118: 179, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, { 22: [1, 214], 26: n1, 52: l, 66: h, 67: f, 92: r1, 106: A, 107: d, 110: i1, 112: p, 113: E, 116: 100, 118: 179, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, { 84: [1, 215] }, e(Y, [2, 104], { 22: [1, 216] }), { 84: [1, 217], 102: [1, 218] }, { 84: [1, 219] }, e($1, [2, 154]), { 84: [1, 220], 102: [1, 221] }, e(S2, [2, 57], { 117: 129, 52: q, 66: c1, 67: m1, 92: V1, 96: x1, 106: v1, 107: R1, 110: L1, 112: I1, 113: N1, 123: O1, 124: P1, 125: w1, 126: U1, 127: M1, 128: G1 }), { 22: S, 24: m, 26: V, 38: x, 41: [1, 222], 42: v, 52: l, 66: h, 67: f, 73: R, 81: L, 83: 202, 86: 152, 87: I, 88: N, 89: O, 90: P, 91: w, 92: U, 93: M, 95: 143, 96: G, 106: A, 107: d, 110: K, 112: p, 113: E, 114: j, 115: W, 116: 149, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, { 22: S, 24: m, 26: V, 38: x, 42: v, 52: l, 56: [1, 223], 66: h, 67: f, 73: R, 81: L, 83: 202, 86: 152, 87: I, 88: N, 89: O, 90: P, 91: w, 92: U, 93: M, 95: 143, 96: G, 106: A, 107: d, 110: K, 112: p, 113: E, 114: j, 115: W, 116: 149, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, { 22: S, 24: m, 26: V, 38: x, 39: 224, 42: v, 52: l, 66: h, 67: f, 73: R, 81: L, 83: 140, 84: J, 85: $, 86: 152, 87: I, 88: N, 89: O, 90: P, 91: w, 92: U, 93: M, 95: 143, 96: G, 106: A, 107: d, 110: K, 112: p, 113: E, 114: j, 115: W, 116: 149, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, { 22: S, 24: m, 26: V, 38: x, 42: v, 52: l, 58: [1, 225], 66: h, 67: f, 73: R, 81: L, 83: 202, 86: 152, 87: I, 88: N, 89: O, 90: P, 91: w, 92: U, 93: M, 95: 143, 96: G, 106: A, 107: d, 110: K, 112: p, 113: E, 114: j, 115: W, 116: 149, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, { 22: S, 24: m, 26: V, 38: x, 42: v, 52: l, 60: [1, 226], 66: h, 67: f, 73: R, 81: L, 83: 202, 86: 152, 87: I, 88: N, 89: O, 90: P, 91: w, 92: U, 93: M, 95: 143, 96: G, 106: A, 107: d, 110: K, 112: p, 113: E, 114: j, 115: W, 116: 149, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, { 22: S, 24: m, 26: V, 38: x, 42: v, 52: l, 62: [1, 227], 66: h, 67: f, 73: R, 81: L, 83: 202, 86: 152, 87: I, 88: N, 89: O, 90: P, 91: w, 92: U, 93: M, 95: 143, 96: G, 106: A, 107: d, 110: K, 112: p, 113: E, 114: j, 115: W, 116: 149, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, { 22: S, 24: m, 26: V, 38: x, 42: v, 52: l, 64: [1, 228], 66: h, 67: f, 73: R, 81: L, 83: 202, 86: 152, 87: I, 88: N, 89: O, 90: P, 91: w, 92: U, 93: M, 95: 143, 96: G, 106: A, 107: d, 110: K, 112: p, 113: E, 114: j, 115: W, 116: 149, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, { 67: [1, 229] }, { 22: S, 24: m, 26: V, 38: x, 42: v, 52: l, 66: h, 67: f, 70: [1, 230], 73: R, 81: L, 83: 202, 86: 152, 87: I, 88: N, 89: O, 90: P, 91: w, 92: U, 93: M, 95: 143, 96: G, 106: A, 107: d, 110: K, 112: p, 113: E, 114: j, 115: W, 116: 149, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, { 22: S, 24: m, 26: V, 38: x, 42: v, 52: l, 66: h, 67: f, 72: [1, 231], 73: R, 81: L, 83: 202, 86: 152, 87: I, 88: N, 89: O, 90: P, 91: w, 92: U, 93: M, 95: 143, 96: G, 106: A, 107: d, 110: K, 112: p, 113: E, 114: j, 115: W, 116: 149, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, { 22: S, 24: m, 26: V, 38: x, 39: 232, 42: v, 52: l, 66: h, 67: f, 73: R, 81: L, 83: 140, 84: J, 85: $, 86: 152, 87: I, 88: N, 89: O, 90: P, 91: w, 92: U, 93: M, 95: 143, 96: G, 106: A, 107: d, 110: K, 112: p, 113: E, 114: j, 115: W, 116: 149, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, { 22: S, 24: m, 26: V, 38: x, 41: [1, 233], 42: v, 52: l, 66: h, 67: f, 73: R, 81: L, 83: 202, 86: 152, 87: I, 88: N, 89: O, 90: P, 91: w, 92: U, 93: M, 95: 143, 96: G, 106: A, 107: d, 110: K, 112: p, 113: E, 114: j, 115: W, 116: 149, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, { 22: S, 24: m, 26: V, 38: x, 42: v, 52: l, 66: h, 67: f, 73: R, 75: [1, 234], 77: [1, 235], 81: L, 83: 202, 86: 152, 87: I, 88: N, 89: O, 90: P, 91: w, 92: U, 93: M, 95: 143, 96: G, 106: A, 107: d, 110: K, 112: p, 113: E, 114: j, 115: W, 116: 149, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, { 22: S, 24: m, 26: V, 38: x, 42: v, 52: l, 66: h, 67: f, 73: R, 75: [1, 237], 77: [1, 236], 81: L, 83: 202, 86: 152, 87: I, 88: N, 89: O, 90: P, 91: w, 92: U, 93: M, 95: 143, 96: G, 106: A, 107: d, 110: K, 112: p, 113: E, 114: j, 115: W, 116: 149, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, { 9: 238, 20: d1, 21: p1, 23: E1 }, e(k2, [2, 52], { 52: Y2 }), e(n2, [2, 77]), e(n2, [2, 76]), { 22: S, 24: m, 26: V, 38: x, 42: v, 52: l, 66: h, 67: f, 68: [1, 239], 73: R, 81: L, 83: 202, 86: 152, 87: I, 88: N, 89: O, 90: P, 91: w, 92: U, 93: M, 95: 143, 96: G, 106: A, 107: d, 110: K, 112: p, 113: E, 114: j, 115: W, 116: 149, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, e(n2, [2, 79]), e(y, [2, 83]), { 22: S, 24: m, 26: V, 38: x, 39: 240, 42: v, 52: l, 66: h, 67: f, 73: R, 81: L, 83: 140, 84: J, 85: $, 86: 152, 87: I, 88: N, 89: O, 90: P, 91: w, 92: U, 93: M, 95: 143, 96: G, 106: A, 107: d, 110: K, 112: p, 113: E, 114: j, 115: W, 116: 149, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, e(V2, B, { 17: 241 }), e(Q, [2, 43]), { 51: 242, 52: q, 54: 46, 66: c1, 67: m1, 92: V1, 96: x1, 106: v1, 107: R1, 110: L1, 112: I1, 113: N1, 117: 47, 123: O1, 124: P1, 125: w1, 126: U1, 127: M1, 128: G1 }, { 22: b1, 66: k1, 67: D1, 87: g1, 97: 243, 103: F1, 106: C1, 108: 244, 109: 245, 110: _1, 111: B1, 112: T1, 113: y1, 114: S1 }, { 22: b1, 66: k1, 67: D1, 87: g1, 97: 257, 103: F1, 106: C1, 108: 244, 109: 245, 110: _1, 111: B1, 112: T1, 113: y1, 114: S1 }, { 22: b1, 66: k1, 67: D1, 87: g1, 97: 258, 103: F1, 105: [1, 259], 106: C1, 108: 244, 109: 245, 110: _1, 111: B1, 112: T1, 113: y1, 114: S1 }, { 22: b1, 66: k1, 67: D1, 87: g1, 97: 260, 103: F1, 105: [1, 261], 106: C1, 108: 244, 109: 245, 110: _1, 111: B1, 112: T1, 113: y1, 114: S1 }, { 106: [1, 262] }, { 22: b1, 66: k1, 67: D1, 87: g1, 97: 263, 103: F1, 106: C1, 108: 244, 109: 245, 110: _1, 111: B1, 112: T1, 113: y1, 114: S1 }, { 22: b1, 66: k1, 67: D1, 87: g1, 97: 264, 103: F1, 106: C1, 108: 244, 109: 245, 110: _1, 111: B1, 112: T1, 113: y1, 114: S1 }, { 26: n1, 52: l, 66: h, 67: f, 92: r1, 98: 265, 106: A, 107: d, 110: i1, 112: p, 113: E, 116: 100, 118: 98, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, e(Y, [2, 103]), { 84: [1, 266] }, e(Y, [2, 107], { 22: [1, 267] }), e(Y, [2, 108]), e(Y, [2, 111]), e(Y, [2, 113], { 22: [1, 268] }), e(Y, [2, 114]), e(t1, [2, 58]), e(t1, [2, 59]), { 22: S, 24: m, 26: V, 38: x, 42: v, 52: l, 58: [1, 269], 66: h, 67: f, 73: R, 81: L, 83: 202, 86: 152, 87: I, 88: N, 89: O, 90: P, 91: w, 92: U, 93: M, 95: 143, 96: G, 106: A, 107: d, 110: K, 112: p, 113: E, 114: j, 115: W, 116: 149, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, e(t1, [2, 66]), e(t1, [2, 61]), e(t1, [2, 62]), e(t1, [2, 63]), { 66: [1, 270] }, e(t1, [2, 65]), e(t1, [2, 67]), { 22: S, 24: m, 26: V, 38: x, 42: v, 52: l, 66: h, 67: f, 72: [1, 271], 73: R, 81: L, 83: 202, 86: 152, 87: I, 88: N, 89: O, 90: P, 91: w, 92: U, 93: M, 95: 143, 96: G, 106: A, 107: d, 110: K, 112: p, 113: E, 114: j, 115: W, 116: 149, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, e(t1, [2, 69]), e(t1, [2, 70]), e(t1, [2, 72]), e(t1, [2, 71]), e(t1, [2, 73]), e(z2, [2, 4]), e([22, 52, 66, 67, 92, 96, 106, 107, 110, 112, 113, 123, 124, 125, 126, 127, 128], [2, 81]), { 22: S, 24: m, 26: V, 38: x, 41: [1, 272], 42: v, 52: l, 66: h, 67: f, 73: R, 81: L, 83: 202, 86: 152, 87: I, 88: N, 89: O, 90: P, 91: w, 92: U, 93: M, 95: 143, 96: G, 106: A, 107: d, 110: K, 112: p, 113: E, 114: j, 115: W, 116: 149, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, { 18: 18, 19: 19, 20: u1, 21: H, 22: s1, 23: A1, 32: 24, 33: 25, 34: 26, 35: 27, 36: 28, 37: 29, 38: Y1, 42: [1, 273], 43: 31, 44: Q1, 46: X1, 48: s2, 50: 35, 51: 45, 52: q, 54: 46, 66: c1, 67: m1, 87: o2, 88: l2, 89: h2, 90: f2, 91: A2, 92: V1, 96: x1, 106: v1, 107: R1, 110: L1, 112: I1, 113: N1, 117: 47, 119: d2, 120: p2, 121: E2, 122: b2, 123: O1, 124: P1, 125: w1, 126: U1, 127: M1, 128: G1 }, e(S2, [2, 56]), e(Y, [2, 116], { 107: z1 }), e(Q2, [2, 126], { 109: 275, 22: b1, 66: k1, 67: D1, 87: g1, 103: F1, 106: C1, 110: _1, 111: B1, 112: T1, 113: y1, 114: S1 }), e(o1, [2, 128]), e(o1, [2, 130]), e(o1, [2, 131]), e(o1, [2, 132]), e(o1, [2, 133]), e(o1, [2, 134]), e(o1, [2, 135]), e(o1, [2, 136]), e(o1, [2, 137]), e(o1, [2, 138]), e(o1, [2, 139]), e(o1, [2, 140]), e(Y, [2, 117], { 107: z1 }), e(Y, [2, 118], { 107: z1 }), { 22: [1, 276] }, e(Y, [2, 119], { 107: z1 }), { 22: [1, 277] }, e(H2, [2, 125]), e(Y, [2, 99], { 107: z1 }), e(Y, [2, 100], { 107: z1 }), e(Y, [2, 101], { 116: 100, 118: 179, 26: n1, 52: l, 66: h, 67: f, 92: r1, 106: A, 107: d, 110: i1, 112: p, 113: E, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }), e(Y, [2, 105]), { 102: [1, 278] }, { 102: [1, 279] }, { 58: [1, 280] }, { 68: [1, 281] }, { 72: [1, 282] }, { 9: 283, 20: d1, 21: p1, 23: E1 }, e(Q, [2, 42]), { 22: b1, 66: k1, 67: D1, 87: g1, 103: F1, 106: C1, 108: 284, 109: 245, 110: _1, 111: B1, 112: T1, 113: y1, 114: S1 }, e(o1, [2, 129]), { 26: n1, 52: l, 66: h, 67: f, 92: r1, 98: 285, 106: A, 107: d, 110: i1, 112: p, 113: E, 116: 100, 118: 98, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, { 26: n1, 52: l, 66: h, 67: f, 92: r1, 98: 286, 106: A, 107: d, 110: i1, 112: p, 113: E, 116: 100, 118: 98, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, e(Y, [2, 109]), e(Y, [2, 115]), e(t1, [2, 60]), { 22: S, 24: m, 26: V, 38: x, 39: 287, 42: v, 52: l, 66: h, 67: f, 73: R, 81: L, 83: 140, 84: J, 85: $, 86: 152, 87: I, 88: N, 89: O, 90: P, 91: w, 92: U, 93: M, 95: 143, 96: G, 106: A, 107: d, 110: K, 112: p, 113: E, 114: j, 115: W, 116: 149, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, e(t1, [2, 68]), e(V2, B, { 17: 288 }), e(Q2, [2, 127], { 109: 275, 22: b1, 66: k1, 67: D1, 87: g1, 103: F1, 106: C1, 110: _1, 111: B1, 112: T1, 113: y1, 114: S1 }), e(Y, [2, 122], { 116: 100, 118: 179, 22: [1, 289], 26: n1, 52: l, 66: h, 67: f, 92: r1, 106: A, 107: d, 110: i1, 112: p, 113: E, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }), e(Y, [2, 123], { 116: 100, 118: 179, 22: [1, 290], 26: n1, 52: l, 66: h, 67: f, 92: r1, 106: A, 107: d, 110: i1, 112: p, 113: E, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }), { 22: S, 24: m, 26: V, 38: x, 41: [1, 291], 42: v, 52: l, 66: h, 67: f, 73: R, 81: L, 83: 202, 86: 152, 87: I, 88: N, 89: O, 90: P, 91: w, 92: U, 93: M, 95: 143, 96: G, 106: A, 107: d, 110: K, 112: p, 113: E, 114: j, 115: W, 116: 149, 123: b, 124: k, 125: D, 126: g, 127: F, 128: C }, { 18: 18, 19: 19, 20: u1, 21: H, 22: s1, 23: A1, 32: 24, 33: 25, 34: 26, 35: 27, 36: 28, 37: 29, 38: Y1, 42: [1, 292], 43: 31, 44: Q1, 46: X1, 48: s2, 50: 35, 51: 45, 52: q, 54: 46, 66: c1, 67: m1, 87: o2, 88: l2, 89: h2, 90: f2, 91: A2, 92: V1, 96: x1, 106: v1, 107: R1, 110: L1, 112: I1, 113: N1, 117: 47, 119: d2, 120: p2, 121: E2, 122: b2, 123: O1, 124: P1, 125: w1, 126: U1, 127: M1, 128: G1 }, { 22: b1, 66: k1, 67: D1, 87: g1, 97: 293, 103: F1, 106: C1, 108: 244, 109: 245, 110: _1, 111: B1, 112: T1, 113: y1, 114: S1 }, { 22: b1, 66: k1, 67: D1, 87: g1, 97: 294, 103: F1, 106: C1, 108: 244, 109: 245, 110: _1, 111: B1, 112: T1, 113: y1, 114: S1 }, e(t1, [2, 64]), e(Q, [2, 41]), e(Y, [2, 120], { 107: z1 }), e(Y, [2, 121], { 107: z1 })],
-    defaultActions: { 2: [2, 1], 9: [2, 5], 10: [2, 2], 132: [2, 7] },
-    parseError: function(a, o) {
-      if (o.recoverable)
-        this.trace(a);
-      else {
-        var T = new Error(a);
-        throw T.hash = o, T;
-      }
-    },
-    parse: function(a) {
-      var o = this, T = [0], r = [], X = [null], t = [], r2 = this.table, s = "", l1 = 0, X2 = 0, ve = 2, q2 = 1, Re = t.slice.call(arguments, 1), e1 = Object.create(this.lexer), q1 = { yy: {} };
-      for (var R2 in this.yy)
-        Object.prototype.hasOwnProperty.call(this.yy, R2) && (q1.yy[R2] = this.yy[R2]);
-      e1.setInput(a, q1.yy), q1.yy.lexer = e1, q1.yy.parser = this, typeof e1.yylloc > "u" && (e1.yylloc = {});
-      var L2 = e1.yylloc;
-      t.push(L2);
-      var Le = e1.options && e1.options.ranges;
-      typeof q1.yy.parseError == "function" ? this.parseError = q1.yy.parseError : this.parseError = Object.getPrototypeOf(this).parseError;
-      function Ie() {
-        var j1;
-        return j1 = r.pop() || e1.lex() || q2, typeof j1 != "number" && (j1 instanceof Array && (r = j1, j1 = r.pop()), j1 = o.symbols_[j1] || j1), j1;
-      }
-      for (var a1, Z1, h1, I2, e2 = {}, D2, K1, Z2, g2; ; ) {
-        if (Z1 = T[T.length - 1], this.defaultActions[Z1] ? h1 = this.defaultActions[Z1] : ((a1 === null || typeof a1 > "u") && (a1 = Ie()), h1 = r2[Z1] && r2[Z1][a1]), typeof h1 > "u" || !h1.length || !h1[0]) {
-          var N2 = "";
-          g2 = [];
-          for (D2 in r2[Z1])
-            this.terminals_[D2] && D2 > ve && g2.push("'" + this.terminals_[D2] + "'");
-          e1.showPosition ? N2 = "Parse error on line " + (l1 + 1) + `:
-` + e1.showPosition() + `
-Expecting ` + g2.join(", ") + ", got '" + (this.terminals_[a1] || a1) + "'" : N2 = "Parse error on line " + (l1 + 1) + ": Unexpected " + (a1 == q2 ? "end of input" : "'" + (this.terminals_[a1] || a1) + "'"), this.parseError(N2, {
-            text: e1.match,
-            token: this.terminals_[a1] || a1,
-            line: e1.yylineno,
-            loc: L2,
-            expected: g2
-          });
-        }
-        if (h1[0] instanceof Array && h1.length > 1)
-          throw new Error("Parse Error: multiple actions possible at state: " + Z1 + ", token: " + a1);
-        switch (h1[0]) {
-          case 1:
-            T.push(a1), X.push(e1.yytext), t.push(e1.yylloc), T.push(h1[1]), a1 = null, X2 = e1.yyleng, s = e1.yytext, l1 = e1.yylineno, L2 = e1.yylloc;
-            break;
-          case 2:
-            if (K1 = this.productions_[h1[1]][1], e2.$ = X[X.length - K1], e2._$ = {
-              first_line: t[t.length - (K1 || 1)].first_line,
-              last_line: t[t.length - 1].last_line,
-              first_column: t[t.length - (K1 || 1)].first_column,
-              last_column: t[t.length - 1].last_column
-            }, Le && (e2._$.range = [
-              t[t.length - (K1 || 1)].range[0],
-              t[t.length - 1].range[1]
-            ]), I2 = this.performAction.apply(e2, [
-              s,
-              X2,
-              l1,
-              q1.yy,
-              h1[1],
-              X,
-              t
-            ].concat(Re)), typeof I2 < "u")
-              return I2;
-            K1 && (T = T.slice(0, -1 * K1 * 2), X = X.slice(0, -1 * K1), t = t.slice(0, -1 * K1)), T.push(this.productions_[h1[1]][0]), X.push(e2.$), t.push(e2._$), Z2 = r2[T[T.length - 2]][T[T.length - 1]], T.push(Z2);
-            break;
-          case 3:
-            return !0;
-        }
-      }
-      return !0;
-    }
-  }, xe = function() {
-    var H1 = {
-      EOF: 1,
-      parseError: function(o, T) {
-        if (this.yy.parser)
-          this.yy.parser.parseError(o, T);
-        else
-          throw new Error(o);
-      },
-      // resets the lexer, sets new input
-      setInput: function(a, o) {
-        return this.yy = o || this.yy || {}, this._input = a, this._more = this._backtrack = this.done = !1, this.yylineno = this.yyleng = 0, this.yytext = this.matched = this.match = "", this.conditionStack = ["INITIAL"], this.yylloc = {
-          first_line: 1,
-          first_column: 0,
-          last_line: 1,
-          last_column: 0
-        }, this.options.ranges && (this.yylloc.range = [0, 0]), this.offset = 0, this;
-      },
-      // consumes and returns one char from the input
-      input: function() {
-        var a = this._input[0];
-        this.yytext += a, this.yyleng++, this.offset++, this.match += a, this.matched += a;
-        var o = a.match(/(?:\r\n?|\n).*/g);
-        return o ? (this.yylineno++, this.yylloc.last_line++) : this.yylloc.last_column++, this.options.ranges && this.yylloc.range[1]++, this._input = this._input.slice(1), a;
-      },
-      // unshifts one char (or a string) into the input
-      unput: function(a) {
-        var o = a.length, T = a.split(/(?:\r\n?|\n)/g);
-        this._input = a + this._input, this.yytext = this.yytext.substr(0, this.yytext.length - o), this.offset -= o;
-        var r = this.match.split(/(?:\r\n?|\n)/g);
-        this.match = this.match.substr(0, this.match.length - 1), this.matched = this.matched.substr(0, this.matched.length - 1), T.length - 1 && (this.yylineno -= T.length - 1);
-        var X = this.yylloc.range;
-        return this.yylloc = {
-          first_line: this.yylloc.first_line,
-          last_line: this.yylineno + 1,
-          first_column: this.yylloc.first_column,
-          last_column: T ? (T.length === r.length ? this.yylloc.first_column : 0) + r[r.length - T.length].length - T[0].length : this.yylloc.first_column - o
-        }, this.options.ranges && (this.yylloc.range = [X[0], X[0] + this.yyleng - o]), this.yyleng = this.yytext.length, this;
-      },
-      // When called from action, caches matched text and appends it on next action
-      more: function() {
-        return this._more = !0, this;
-      },
-      // When called from action, signals the lexer that this rule fails to match the input, so the next matching rule (regex) should be tested instead.
-      reject: function() {
-        if (this.options.backtrack_lexer)
-          this._backtrack = !0;
-        else
-          return this.parseError("Lexical error on line " + (this.yylineno + 1) + `. You can only invoke reject() in the lexer when the lexer is of the backtracking persuasion (options.backtrack_lexer = true).
-` + this.showPosition(), {
-            text: "",
-            token: null,
-            line: this.yylineno
-          });
-        return this;
-      },
-      // retain first n characters of the match
-      less: function(a) {
-        this.unput(this.match.slice(a));
-      },
-      // displays already matched input, i.e. for error messages
-      pastInput: function() {
-        var a = this.matched.substr(0, this.matched.length - this.match.length);
-        return (a.length > 20 ? "..." : "") + a.substr(-20).replace(/\n/g, "");
-      },
-      // displays upcoming input, i.e. for error messages
-      upcomingInput: function() {
-        var a = this.match;
-        return a.length < 20 && (a += this._input.substr(0, 20 - a.length)), (a.substr(0, 20) + (a.length > 20 ? "..." : "")).replace(/\n/g, "");
-      },
-      // displays the character position where the lexing error occurred, i.e. for error messages
-      showPosition: function() {
-        var a = this.pastInput(), o = new Array(a.length + 1).join("-");
-        return a + this.upcomingInput() + `
-` + o + "^";
-      },
-      // test the lexed token: return FALSE when not a match, otherwise return token
-      test_match: function(a, o) {
-        var T, r, X;
-        if (this.options.backtrack_lexer && (X = {
-          yylineno: this.yylineno,
-          yylloc: {
-            first_line: this.yylloc.first_line,
-            last_line: this.last_line,
-            first_column: this.yylloc.first_column,
-            last_column: this.yylloc.last_column
-          },
-          yytext: this.yytext,
-          match: this.match,
-          matches: this.matches,
-          matched: this.matched,
-          yyleng: this.yyleng,
-          offset: this.offset,
-          _more: this._more,
-          _input: this._input,
-          yy: this.yy,
-          conditionStack: this.conditionStack.slice(0),
-          done: this.done
-        }, this.options.ranges && (X.yylloc.range = this.yylloc.range.slice(0))), r = a[0].match(/(?:\r\n?|\n).*/g), r && (this.yylineno += r.length), this.yylloc = {
-          first_line: this.yylloc.last_line,
-          last_line: this.yylineno + 1,
-          first_column: this.yylloc.last_column,
-          last_column: r ? r[r.length - 1].length - r[r.length - 1].match(/\r?\n?/)[0].length : this.yylloc.last_column + a[0].length
-        }, this.yytext += a[0], this.match += a[0], this.matches = a, this.yyleng = this.yytext.length, this.options.ranges && (this.yylloc.range = [this.offset, this.offset += this.yyleng]), this._more = !1, this._backtrack = !1, this._input = this._input.slice(a[0].length), this.matched += a[0], T = this.performAction.call(this, this.yy, this, o, this.conditionStack[this.conditionStack.length - 1]), this.done && this._input && (this.done = !1), T)
-          return T;
-        if (this._backtrack) {
-          for (var t in X)
-            this[t] = X[t];
-          return !1;
-        }
-        return !1;
-      },
-      // return next match in input
-      next: function() {
-        if (this.done)
-          return this.EOF;
-        this._input || (this.done = !0);
-        var a, o, T, r;
-        this._more || (this.yytext = "", this.match = "");
-        for (var X = this._currentRules(), t = 0; t < X.length; t++)
-          if (T = this._input.match(this.rules[X[t]]), T && (!o || T[0].length > o[0].length)) {
-            if (o = T, r = t, this.options.backtrack_lexer) {
-              if (a = this.test_match(T, X[t]), a !== !1)
-                return a;
-              if (this._backtrack) {
-                o = !1;
-                continue;
-              } else
-                return !1;
-            } else if (!this.options.flex)
-              break;
-          }
-        return o ? (a = this.test_match(o, X[r]), a !== !1 ? a : !1) : this._input === "" ? this.EOF : this.parseError("Lexical error on line " + (this.yylineno + 1) + `. Unrecognized text.
-` + this.showPosition(), {
-          text: "",
-          token: null,
-          line: this.yylineno
-        });
-      },
-      // return next match that has a token
-      lex: function() {
-        var o = this.next();
-        return o || this.lex();
-      },
-      // activates a new lexer condition state (pushes the new lexer condition state onto the condition stack)
-      begin: function(o) {
-        this.conditionStack.push(o);
-      },
-      // pop the previously active lexer condition state off the condition stack
-      popState: function() {
-        var o = this.conditionStack.length - 1;
-        return o > 0 ? this.conditionStack.pop() : this.conditionStack[0];
-      },
-      // produce the lexer rule set which is active for the currently active lexer condition state
-      _currentRules: function() {
-        return this.conditionStack.length && this.conditionStack[this.conditionStack.length - 1] ? this.conditions[this.conditionStack[this.conditionStack.length - 1]].rules : this.conditions.INITIAL.rules;
-      },
-      // return the currently active lexer condition state; when an index argument is provided it produces the N-th previous condition state, if available
-      topState: function(o) {
-        return o = this.conditionStack.length - 1 - Math.abs(o || 0), o >= 0 ? this.conditionStack[o] : "INITIAL";
-      },
-      // alias for begin(condition)
-      pushState: function(o) {
-        this.begin(o);
-      },
-      // return the number of states currently on the stack
-      stateStackSize: function() {
-        return this.conditionStack.length;
-      },
-      options: {},
-      performAction: function(o, T, r, X) {
-        switch (r) {
-          case 0:
-            return this.begin("open_directive"), 12;
-          case 1:
-            return this.begin("type_directive"), 13;
-          case 2:
-            return this.popState(), this.begin("arg_directive"), 10;
-          case 3:
-            return this.popState(), this.popState(), 15;
-          case 4:
-            return 14;
-          case 5:
-            return this.begin("acc_title"), 44;
-          case 6:
-            return this.popState(), "acc_title_value";
-          case 7:
-            return this.begin("acc_descr"), 46;
-          case 8:
-            return this.popState(), "acc_descr_value";
-          case 9:
-            this.begin("acc_descr_multiline");
-            break;
-          case 10:
-            this.popState();
-            break;
-          case 11:
-            return "acc_descr_multiline_value";
-          case 12:
-            this.begin("md_string");
-            break;
-          case 13:
-            return "MD_STR";
-          case 14:
-            this.popState();
-            break;
-          case 15:
-            this.begin("string");
-            break;
-          case 16:
-            this.popState();
-            break;
-          case 17:
-            return "STR";
-          case 18:
-            return 87;
-          case 19:
-            return 96;
-          case 20:
-            return 88;
-          case 21:
-            return 105;
-          case 22:
-            return 89;
-          case 23:
-            return 90;
-          case 24:
-            this.begin("href");
-            break;
-          case 25:
-            this.popState();
-            break;
-          case 26:
-            return 101;
-          case 27:
-            this.begin("callbackname");
-            break;
-          case 28:
-            this.popState();
-            break;
-          case 29:
-            this.popState(), this.begin("callbackargs");
-            break;
-          case 30:
-            return 99;
-          case 31:
-            this.popState();
-            break;
-          case 32:
-            return 100;
-          case 33:
-            this.begin("click");
-            break;
-          case 34:
-            this.popState();
-            break;
-          case 35:
-            return 91;
-          case 36:
-            return o.lex.firstGraph() && this.begin("dir"), 24;
-          case 37:
-            return o.lex.firstGraph() && this.begin("dir"), 24;
-          case 38:
-            return o.lex.firstGraph() && this.begin("dir"), 24;
-          case 39:
-            return 38;
-          case 40:
-            return 42;
-          case 41:
-            return 102;
-          case 42:
-            return 102;
-          case 43:
-            return 102;
-          case 44:
-            return 102;
-          case 45:
-            return this.popState(), 25;
-          case 46:
-            return this.popState(), 26;
-          case 47:
-            return this.popState(), 26;
-          case 48:
-            return this.popState(), 26;
-          case 49:
-            return this.popState(), 26;
-          case 50:
-            return this.popState(), 26;
-          case 51:
-            return this.popState(), 26;
-          case 52:
-            return this.popState(), 26;
-          case 53:
-            return this.popState(), 26;
-          case 54:
-            return this.popState(), 26;
-          case 55:
-            return this.popState(), 26;
-          case 56:
-            return 119;
-          case 57:
-            return 120;
-          case 58:
-            return 121;
-          case 59:
-            return 122;
-          case 60:
-            return 106;
-          case 61:
-            return 112;
-          case 62:
-            return 53;
-          case 63:
-            return 67;
-          case 64:
-            return 52;
-          case 65:
-            return 20;
-          case 66:
-            return 107;
-          case 67:
-            return 127;
-          case 68:
-            return 82;
-          case 69:
-            return 82;
-          case 70:
-            return 82;
-          case 71:
-            return 82;
-          case 72:
-            return 81;
-          case 73:
-            return 81;
-          case 74:
-            return 81;
-          case 75:
-            return 59;
-          case 76:
-            return 60;
-          case 77:
-            return 61;
-          case 78:
-            return 62;
-          case 79:
-            return 63;
-          case 80:
-            return 64;
-          case 81:
-            return 65;
-          case 82:
-            return 69;
-          case 83:
-            return 70;
-          case 84:
-            return 55;
-          case 85:
-            return 56;
-          case 86:
-            return 110;
-          case 87:
-            return 113;
-          case 88:
-            return 128;
-          case 89:
-            return 125;
-          case 90:
-            return 114;
-          case 91:
-            return 126;
-          case 92:
-            return 126;
-          case 93:
-            return 115;
-          case 94:
-            return 73;
-          case 95:
-            return 93;
-          case 96:
-            return "SEP";
-          case 97:
-            return 92;
-          case 98:
-            return 66;
-          case 99:
-            return 75;
-          case 100:
-            return 74;
-          case 101:
-            return 77;
-          case 102:
-            return 76;
-          case 103:
-            return 123;
-          case 104:
-            return 124;
-          case 105:
-            return 68;
-          case 106:
-            return 57;
-          case 107:
-            return 58;
-          case 108:
-            return 40;
-          case 109:
-            return 41;
-          case 110:
-            return 71;
-          case 111:
-            return 72;
-          case 112:
-            return 134;
-          case 113:
-            return 21;
-          case 114:
-            return 22;
-          case 115:
-            return 23;
-        }
-      },
-      rules: [/^(?:%%\{)/, /^(?:((?:(?!\}%%)[^:.])*))/, /^(?::)/, /^(?:\}%%)/, /^(?:((?:(?!\}%%).|\n)*))/, /^(?:accTitle\s*:\s*)/, /^(?:(?!\n||)*[^\n]*)/, /^(?:accDescr\s*:\s*)/, /^(?:(?!\n||)*[^\n]*)/, /^(?:accDescr\s*\{\s*)/, /^(?:[\}])/, /^(?:[^\}]*)/, /^(?:["][`])/, /^(?:[^`"]+)/, /^(?:[`]["])/, /^(?:["])/, /^(?:["])/, /^(?:[^"]*)/, /^(?:style\b)/, /^(?:default\b)/, /^(?:linkStyle\b)/, /^(?:interpolate\b)/, /^(?:classDef\b)/, /^(?:class\b)/, /^(?:href[\s]+["])/, /^(?:["])/, /^(?:[^"]*)/, /^(?:call[\s]+)/, /^(?:\([\s]*\))/, /^(?:\()/, /^(?:[^(]*)/, /^(?:\))/, /^(?:[^)]*)/, /^(?:click[\s]+)/, /^(?:[\s\n])/, /^(?:[^\s\n]*)/, /^(?:flowchart-elk\b)/, /^(?:graph\b)/, /^(?:flowchart\b)/, /^(?:subgraph\b)/, /^(?:end\b\s*)/, /^(?:_self\b)/, /^(?:_blank\b)/, /^(?:_parent\b)/, /^(?:_top\b)/, /^(?:(\r?\n)*\s*\n)/, /^(?:\s*LR\b)/, /^(?:\s*RL\b)/, /^(?:\s*TB\b)/, /^(?:\s*BT\b)/, /^(?:\s*TD\b)/, /^(?:\s*BR\b)/, /^(?:\s*<)/, /^(?:\s*>)/, /^(?:\s*\^)/, /^(?:\s*v\b)/, /^(?:.*direction\s+TB[^\n]*)/, /^(?:.*direction\s+BT[^\n]*)/, /^(?:.*direction\s+RL[^\n]*)/, /^(?:.*direction\s+LR[^\n]*)/, /^(?:[0-9]+)/, /^(?:#)/, /^(?::::)/, /^(?::)/, /^(?:&)/, /^(?:;)/, /^(?:,)/, /^(?:\*)/, /^(?:\s*[xo<]?--+[-xo>]\s*)/, /^(?:\s*[xo<]?==+[=xo>]\s*)/, /^(?:\s*[xo<]?-?\.+-[xo>]?\s*)/, /^(?:\s*~~[\~]+\s*)/, /^(?:\s*[xo<]?--\s*)/, /^(?:\s*[xo<]?==\s*)/, /^(?:\s*[xo<]?-\.\s*)/, /^(?:\(-)/, /^(?:-\))/, /^(?:\(\[)/, /^(?:\]\))/, /^(?:\[\[)/, /^(?:\]\])/, /^(?:\[\|)/, /^(?:\[\()/, /^(?:\)\])/, /^(?:\(\(\()/, /^(?:\)\)\))/, /^(?:-)/, /^(?:\.)/, /^(?:[\_])/, /^(?:\+)/, /^(?:%)/, /^(?:=)/, /^(?:=)/, /^(?:<)/, /^(?:>)/, /^(?:\^)/, /^(?:\\\|)/, /^(?:v\b)/, /^(?:[A-Za-z]+)/, /^(?:\\\])/, /^(?:\[\/)/, /^(?:\/\])/, /^(?:\[\\)/, /^(?:[!"#$%&'*+,-.`?\\_/])/, /^(?:[\u00AA\u00B5\u00BA\u00C0-\u00D6\u00D8-\u00F6]|[\u00F8-\u02C1\u02C6-\u02D1\u02E0-\u02E4\u02EC\u02EE\u0370-\u0374\u0376\u0377]|[\u037A-\u037D\u0386\u0388-\u038A\u038C\u038E-\u03A1\u03A3-\u03F5]|[\u03F7-\u0481\u048A-\u0527\u0531-\u0556\u0559\u0561-\u0587\u05D0-\u05EA]|[\u05F0-\u05F2\u0620-\u064A\u066E\u066F\u0671-\u06D3\u06D5\u06E5\u06E6\u06EE]|[\u06EF\u06FA-\u06FC\u06FF\u0710\u0712-\u072F\u074D-\u07A5\u07B1\u07CA-\u07EA]|[\u07F4\u07F5\u07FA\u0800-\u0815\u081A\u0824\u0828\u0840-\u0858\u08A0]|[\u08A2-\u08AC\u0904-\u0939\u093D\u0950\u0958-\u0961\u0971-\u0977]|[\u0979-\u097F\u0985-\u098C\u098F\u0990\u0993-\u09A8\u09AA-\u09B0\u09B2]|[\u09B6-\u09B9\u09BD\u09CE\u09DC\u09DD\u09DF-\u09E1\u09F0\u09F1\u0A05-\u0A0A]|[\u0A0F\u0A10\u0A13-\u0A28\u0A2A-\u0A30\u0A32\u0A33\u0A35\u0A36\u0A38\u0A39]|[\u0A59-\u0A5C\u0A5E\u0A72-\u0A74\u0A85-\u0A8D\u0A8F-\u0A91\u0A93-\u0AA8]|[\u0AAA-\u0AB0\u0AB2\u0AB3\u0AB5-\u0AB9\u0ABD\u0AD0\u0AE0\u0AE1\u0B05-\u0B0C]|[\u0B0F\u0B10\u0B13-\u0B28\u0B2A-\u0B30\u0B32\u0B33\u0B35-\u0B39\u0B3D\u0B5C]|[\u0B5D\u0B5F-\u0B61\u0B71\u0B83\u0B85-\u0B8A\u0B8E-\u0B90\u0B92-\u0B95\u0B99]|[\u0B9A\u0B9C\u0B9E\u0B9F\u0BA3\u0BA4\u0BA8-\u0BAA\u0BAE-\u0BB9\u0BD0]|[\u0C05-\u0C0C\u0C0E-\u0C10\u0C12-\u0C28\u0C2A-\u0C33\u0C35-\u0C39\u0C3D]|[\u0C58\u0C59\u0C60\u0C61\u0C85-\u0C8C\u0C8E-\u0C90\u0C92-\u0CA8\u0CAA-\u0CB3]|[\u0CB5-\u0CB9\u0CBD\u0CDE\u0CE0\u0CE1\u0CF1\u0CF2\u0D05-\u0D0C\u0D0E-\u0D10]|[\u0D12-\u0D3A\u0D3D\u0D4E\u0D60\u0D61\u0D7A-\u0D7F\u0D85-\u0D96\u0D9A-\u0DB1]|[\u0DB3-\u0DBB\u0DBD\u0DC0-\u0DC6\u0E01-\u0E30\u0E32\u0E33\u0E40-\u0E46\u0E81]|[\u0E82\u0E84\u0E87\u0E88\u0E8A\u0E8D\u0E94-\u0E97\u0E99-\u0E9F\u0EA1-\u0EA3]|[\u0EA5\u0EA7\u0EAA\u0EAB\u0EAD-\u0EB0\u0EB2\u0EB3\u0EBD\u0EC0-\u0EC4\u0EC6]|[\u0EDC-\u0EDF\u0F00\u0F40-\u0F47\u0F49-\u0F6C\u0F88-\u0F8C\u1000-\u102A]|[\u103F\u1050-\u1055\u105A-\u105D\u1061\u1065\u1066\u106E-\u1070\u1075-\u1081]|[\u108E\u10A0-\u10C5\u10C7\u10CD\u10D0-\u10FA\u10FC-\u1248\u124A-\u124D]|[\u1250-\u1256\u1258\u125A-\u125D\u1260-\u1288\u128A-\u128D\u1290-\u12B0]|[\u12B2-\u12B5\u12B8-\u12BE\u12C0\u12C2-\u12C5\u12C8-\u12D6\u12D8-\u1310]|[\u1312-\u1315\u1318-\u135A\u1380-\u138F\u13A0-\u13F4\u1401-\u166C]|[\u166F-\u167F\u1681-\u169A\u16A0-\u16EA\u1700-\u170C\u170E-\u1711]|[\u1720-\u1731\u1740-\u1751\u1760-\u176C\u176E-\u1770\u1780-\u17B3\u17D7]|[\u17DC\u1820-\u1877\u1880-\u18A8\u18AA\u18B0-\u18F5\u1900-\u191C]|[\u1950-\u196D\u1970-\u1974\u1980-\u19AB\u19C1-\u19C7\u1A00-\u1A16]|[\u1A20-\u1A54\u1AA7\u1B05-\u1B33\u1B45-\u1B4B\u1B83-\u1BA0\u1BAE\u1BAF]|[\u1BBA-\u1BE5\u1C00-\u1C23\u1C4D-\u1C4F\u1C5A-\u1C7D\u1CE9-\u1CEC]|[\u1CEE-\u1CF1\u1CF5\u1CF6\u1D00-\u1DBF\u1E00-\u1F15\u1F18-\u1F1D]|[\u1F20-\u1F45\u1F48-\u1F4D\u1F50-\u1F57\u1F59\u1F5B\u1F5D\u1F5F-\u1F7D]|[\u1F80-\u1FB4\u1FB6-\u1FBC\u1FBE\u1FC2-\u1FC4\u1FC6-\u1FCC\u1FD0-\u1FD3]|[\u1FD6-\u1FDB\u1FE0-\u1FEC\u1FF2-\u1FF4\u1FF6-\u1FFC\u2071\u207F]|[\u2090-\u209C\u2102\u2107\u210A-\u2113\u2115\u2119-\u211D\u2124\u2126\u2128]|[\u212A-\u212D\u212F-\u2139\u213C-\u213F\u2145-\u2149\u214E\u2183\u2184]|[\u2C00-\u2C2E\u2C30-\u2C5E\u2C60-\u2CE4\u2CEB-\u2CEE\u2CF2\u2CF3]|[\u2D00-\u2D25\u2D27\u2D2D\u2D30-\u2D67\u2D6F\u2D80-\u2D96\u2DA0-\u2DA6]|[\u2DA8-\u2DAE\u2DB0-\u2DB6\u2DB8-\u2DBE\u2DC0-\u2DC6\u2DC8-\u2DCE]|[\u2DD0-\u2DD6\u2DD8-\u2DDE\u2E2F\u3005\u3006\u3031-\u3035\u303B\u303C]|[\u3041-\u3096\u309D-\u309F\u30A1-\u30FA\u30FC-\u30FF\u3105-\u312D]|[\u3131-\u318E\u31A0-\u31BA\u31F0-\u31FF\u3400-\u4DB5\u4E00-\u9FCC]|[\uA000-\uA48C\uA4D0-\uA4FD\uA500-\uA60C\uA610-\uA61F\uA62A\uA62B]|[\uA640-\uA66E\uA67F-\uA697\uA6A0-\uA6E5\uA717-\uA71F\uA722-\uA788]|[\uA78B-\uA78E\uA790-\uA793\uA7A0-\uA7AA\uA7F8-\uA801\uA803-\uA805]|[\uA807-\uA80A\uA80C-\uA822\uA840-\uA873\uA882-\uA8B3\uA8F2-\uA8F7\uA8FB]|[\uA90A-\uA925\uA930-\uA946\uA960-\uA97C\uA984-\uA9B2\uA9CF\uAA00-\uAA28]|[\uAA40-\uAA42\uAA44-\uAA4B\uAA60-\uAA76\uAA7A\uAA80-\uAAAF\uAAB1\uAAB5]|[\uAAB6\uAAB9-\uAABD\uAAC0\uAAC2\uAADB-\uAADD\uAAE0-\uAAEA\uAAF2-\uAAF4]|[\uAB01-\uAB06\uAB09-\uAB0E\uAB11-\uAB16\uAB20-\uAB26\uAB28-\uAB2E]|[\uABC0-\uABE2\uAC00-\uD7A3\uD7B0-\uD7C6\uD7CB-\uD7FB\uF900-\uFA6D]|[\uFA70-\uFAD9\uFB00-\uFB06\uFB13-\uFB17\uFB1D\uFB1F-\uFB28\uFB2A-\uFB36]|[\uFB38-\uFB3C\uFB3E\uFB40\uFB41\uFB43\uFB44\uFB46-\uFBB1\uFBD3-\uFD3D]|[\uFD50-\uFD8F\uFD92-\uFDC7\uFDF0-\uFDFB\uFE70-\uFE74\uFE76-\uFEFC]|[\uFF21-\uFF3A\uFF41-\uFF5A\uFF66-\uFFBE\uFFC2-\uFFC7\uFFCA-\uFFCF]|[\uFFD2-\uFFD7\uFFDA-\uFFDC])/, /^(?:\|)/, /^(?:\()/, /^(?:\))/, /^(?:\[)/, /^(?:\])/, /^(?:\{)/, /^(?:\})/, /^(?:")/, /^(?:(\r?\n)+)/, /^(?:\s)/, /^(?:$)/],
-      conditions: { close_directive: { rules: [], inclusive: !1 }, arg_directive: { rules: [3, 4], inclusive: !1 }, type_directive: { rules: [2, 3], inclusive: !1 }, open_directive: { rules: [1], inclusive: !1 }, callbackargs: { rules: [31, 32], inclusive: !1 }, callbackname: { rules: [28, 29, 30], inclusive: !1 }, href: { rules: [25, 26], inclusive: !1 }, click: { rules: [34, 35], inclusive: !1 }, vertex: { rules: [], inclusive: !1 }, dir: { rules: [45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55], inclusive: !1 }, acc_descr_multiline: { rules: [10, 11], inclusive: !1 }, acc_descr: { rules: [8], inclusive: !1 }, acc_title: { rules: [6], inclusive: !1 }, md_string: { rules: [13, 14], inclusive: !1 }, string: { rules: [16, 17], inclusive: !1 }, INITIAL: { rules: [0, 5, 7, 9, 12, 15, 18, 19, 20, 21, 22, 23, 24, 27, 33, 36, 37, 38, 39, 40, 41, 42, 43, 44, 56, 57, 58, 59, 60, 61, 62, 63, 64, 65, 66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 111, 112, 113, 114, 115], inclusive: !0 } }
-    };
-    return H1;
-  }();
-  x2.lexer = xe;
-  function v2() {
-    this.yy = {};
-  }
-  return v2.prototype = x2, x2.Parser = v2, new v2();
-}();
-O2.parser = O2;
-const st = O2, Ye = "flowchart-";
-let J2 = 0, G2 = w2(), z = {}, J1 = [], t2 = {}, W1 = [], C2 = {}, K2 = {}, F2 = 0, P2 = !0, f1, _2, B2 = [];
-const T2 = (e) => je.sanitizeText(e, G2), $2 = function(e, u, n) {
-  We.parseDirective(this, e, u, n);
-}, c2 = function(e) {
-  const u = Object.keys(z);
-  for (const n of u)
-    if (z[n].id === e)
-      return z[n].domId;
-  return e;
-}, ee = function(e, u, n, i, c, _, B = {}) {
-  let u1, H = e;
-  H !== void 0 && H.trim().length !== 0 && (z[H] === void 0 && (z[H] = {
-    id: H,
-    labelType: "text",
-    domId: Ye + H + "-" + J2,
-    styles: [],
-    classes: []
-  }), J2++, u !== void 0 ? (G2 = w2(), u1 = T2(u.text.trim()), z[H].labelType = u.type, u1[0] === '"' && u1[u1.length - 1] === '"' && (u1 = u1.substring(1, u1.length - 1)), z[H].text = u1) : z[H].text === void 0 && (z[H].text = e), n !== void 0 && (z[H].type = n), i != null && i.forEach(function(s1) {
-    z[H].styles.push(s1);
-  }), c != null && c.forEach(function(s1) {
-    z[H].classes.push(s1);
-  }), _ !== void 0 && (z[H].dir = _), z[H].props === void 0 ? z[H].props = B : B !== void 0 && Object.assign(z[H].props, B));
-}, te = function(e, u, n) {
-  const _ = { start: e, end: u, type: void 0, text: "", labelType: "text" };
-  U2.info("abc78 Got edge...", _);
-  const B = n.text;
-  B !== void 0 && (_.text = T2(B.text.trim()), _.text[0] === '"' && _.text[_.text.length - 1] === '"' && (_.text = _.text.substring(1, _.text.length - 1)), _.labelType = B.type), n !== void 0 && (_.type = n.type, _.stroke = n.stroke, _.length = n.length), J1.push(_);
-}, se = function(e, u, n) {
-  U2.info("addLink (abc78)", e, u, n);
-  let i, c;
-  for (i = 0; i < e.length; i++)
-    for (c = 0; c < u.length; c++)
-      te(e[i], u[c], n);
-}, ue = function(e, u) {
-  e.forEach(function(n) {
-    n === "default" ? J1.defaultInterpolate = u : J1[n].interpolate = u;
-  });
-}, ne = function(e, u) {
-  e.forEach(function(n) {
-    n === "default" ? J1.defaultStyle = u : (M2.isSubstringInArray("fill", u) === -1 && u.push("fill:none"), J1[n].style = u);
-  });
-}, re = function(e, u) {
-  t2[e] === void 0 && (t2[e] = { id: e, styles: [], textStyles: [] }), u != null && u.forEach(function(n) {
-    if (n.match("color")) {
-      const c = n.replace("fill", "bgFill").replace("color", "fill");
-      t2[e].textStyles.push(c);
-    }
-    t2[e].styles.push(n);
-  });
-}, ie = function(e) {
-  f1 = e, f1.match(/.*</) && (f1 = "RL"), f1.match(/.*\^/) && (f1 = "BT"), f1.match(/.*>/) && (f1 = "LR"), f1.match(/.*v/) && (f1 = "TB"), f1 === "TD" && (f1 = "TB");
-}, y2 = function(e, u) {
-  e.split(",").forEach(function(n) {
-    let i = n;
-    z[i] !== void 0 && z[i].classes.push(u), C2[i] !== void 0 && C2[i].classes.push(u);
-  });
-}, ze = function(e, u) {
-  e.split(",").forEach(function(n) {
-    u !== void 0 && (K2[_2 === "gen-1" ? c2(n) : n] = T2(u));
-  });
-}, He = function(e, u, n) {
-  let i = c2(e);
-  if (w2().securityLevel !== "loose" || u === void 0)
-    return;
-  let c = [];
-  if (typeof n == "string") {
-    c = n.split(/,(?=(?:(?:[^"]*"){2})*[^"]*$)/);
-    for (let _ = 0; _ < c.length; _++) {
-      let B = c[_].trim();
-      B.charAt(0) === '"' && B.charAt(B.length - 1) === '"' && (B = B.substr(1, B.length - 2)), c[_] = B;
-    }
-  }
-  c.length === 0 && c.push(e), z[e] !== void 0 && (z[e].haveCallback = !0, B2.push(function() {
-    const _ = document.querySelector(`[id="${i}"]`);
-    _ !== null && _.addEventListener(
-      "click",
-      function() {
-        M2.runFunc(u, ...c);
-      },
-      !1
-    );
-  }));
-}, ae = function(e, u, n) {
-  e.split(",").forEach(function(i) {
-    z[i] !== void 0 && (z[i].link = M2.formatUrl(u, G2), z[i].linkTarget = n);
-  }), y2(e, "clickable");
-}, ce = function(e) {
-  return K2[e];
-}, oe = function(e, u, n) {
-  e.split(",").forEach(function(i) {
-    He(i, u, n);
-  }), y2(e, "clickable");
-}, le = function(e) {
-  B2.forEach(function(u) {
-    u(e);
-  });
-}, he = function() {
-  return f1.trim();
-}, fe = function() {
-  return z;
-}, Ae = function() {
-  return J1;
-}, de = function() {
-  return t2;
-}, pe = function(e) {
-  let u = i2(".mermaidTooltip");
-  (u._groups || u)[0][0] === null && (u = i2("body").append("div").attr("class", "mermaidTooltip").style("opacity", 0)), i2(e).select("svg").selectAll("g.node").on("mouseover", function() {
-    const c = i2(this);
-    if (c.attr("title") === null)
-      return;
-    const B = this.getBoundingClientRect();
-    u.transition().duration(200).style("opacity", ".9"), u.text(c.attr("title")).style("left", window.scrollX + B.left + (B.right - B.left) / 2 + "px").style("top", window.scrollY + B.top - 14 + document.body.scrollTop + "px"), u.html(u.html().replace(/&lt;br\/&gt;/g, "<br/>")), c.classed("hover", !0);
-  }).on("mouseout", function() {
-    u.transition().duration(500).style("opacity", 0), i2(this).classed("hover", !1);
-  });
-};
-B2.push(pe);
-const Ee = function(e = "gen-1") {
-  z = {}, t2 = {}, J1 = [], B2 = [pe], W1 = [], C2 = {}, F2 = 0, K2 = [], P2 = !0, _2 = e, Ke();
-}, be = (e) => {
-  _2 = e || "gen-2";
-}, ke = function() {
-  return "fill:#ffa;stroke: #f66; stroke-width: 3px; stroke-dasharray: 5, 5;fill:#ffa;stroke: #666;";
-}, De = function(e, u, n) {
-  let i = e.text.trim(), c = n.text;
-  e === n && n.text.match(/\s/) && (i = void 0);
-  function _(A1) {
-    const Y1 = { boolean: {}, number: {}, string: {} }, Q1 = [];
-    let X1;
-    return { nodeList: A1.filter(function(q) {
-      const c1 = typeof q;
-      return q.stmt && q.stmt === "dir" ? (X1 = q.value, !1) : q.trim() === "" ? !1 : c1 in Y1 ? Y1[c1].hasOwnProperty(q) ? !1 : Y1[c1][q] = !0 : Q1.includes(q) ? !1 : Q1.push(q);
-    }), dir: X1 };
-  }
-  let B = [];
-  const { nodeList: u1, dir: H } = _(B.concat.apply(B, u));
-  if (B = u1, _2 === "gen-1")
-    for (let A1 = 0; A1 < B.length; A1++)
-      B[A1] = c2(B[A1]);
-  i = i || "subGraph" + F2, c = c || "", c = T2(c), F2 = F2 + 1;
-  const s1 = {
-    id: i,
-    nodes: B,
-    title: c.trim(),
-    classes: [],
-    dir: H,
-    labelType: n.type
-  };
-  return U2.info("Adding", s1.id, s1.nodes, s1.dir), s1.nodes = me(s1, W1).nodes, W1.push(s1), C2[i] = s1, i;
-}, Qe = function(e) {
-  for (const [u, n] of W1.entries())
-    if (n.id === e)
-      return u;
-  return -1;
-};
-let a2 = -1;
-const ge = [], Fe = function(e, u) {
-  const n = W1[u].nodes;
-  if (a2 = a2 + 1, a2 > 2e3)
-    return;
-  if (ge[a2] = u, W1[u].id === e)
-    return {
-      result: !0,
-      count: 0
-    };
-  let i = 0, c = 1;
-  for (; i < n.length; ) {
-    const _ = Qe(n[i]);
-    if (_ >= 0) {
-      const B = Fe(e, _);
-      if (B.result)
-        return {
-          result: !0,
-          count: c + B.count
-        };
-      c = c + B.count;
-    }
-    i = i + 1;
-  }
-  return {
-    result: !1,
-    count: c
-  };
-}, Ce = function(e) {
-  return ge[e];
-}, _e = function() {
-  a2 = -1, W1.length > 0 && Fe("none", W1.length - 1);
-}, Be = function() {
-  return W1;
-}, Te = () => P2 ? (P2 = !1, !0) : !1, Xe = (e) => {
-  let u = e.trim(), n = "arrow_open";
-  switch (u[0]) {
-    case "<":
-      n = "arrow_point", u = u.slice(1);
-      break;
-    case "x":
-      n = "arrow_cross", u = u.slice(1);
-      break;
-    case "o":
-      n = "arrow_circle", u = u.slice(1);
-      break;
-  }
-  let i = "normal";
-  return u.includes("=") && (i = "thick"), u.includes(".") && (i = "dotted"), { type: n, stroke: i };
-}, qe = (e, u) => {
-  const n = u.length;
-  let i = 0;
-  for (let c = 0; c < n; ++c)
-    u[c] === e && ++i;
-  return i;
-}, Ze = (e) => {
-  const u = e.trim();
-  let n = u.slice(0, -1), i = "arrow_open";
-  switch (u.slice(-1)) {
-    case "x":
-      i = "arrow_cross", u[0] === "x" && (i = "double_" + i, n = n.slice(1));
-      break;
-    case ">":
-      i = "arrow_point", u[0] === "<" && (i = "double_" + i, n = n.slice(1));
-      break;
-    case "o":
-      i = "arrow_circle", u[0] === "o" && (i = "double_" + i, n = n.slice(1));
-      break;
-  }
-  let c = "normal", _ = n.length - 1;
-  n[0] === "=" && (c = "thick"), n[0] === "~" && (c = "invisible");
-  let B = qe(".", n);
-  return B && (c = "dotted", _ = B), { type: i, stroke: c, length: _ };
-}, ye = (e, u) => {
-  const n = Ze(e);
-  let i;
-  if (u) {
-    if (i = Xe(u), i.stroke !== n.stroke)
-      return { type: "INVALID", stroke: "INVALID" };
-    if (i.type === "arrow_open")
-      i.type = n.type;
-    else {
-      if (i.type !== n.type)
-        return { type: "INVALID", stroke: "INVALID" };
-      i.type = "double_" + i.type;
-    }
-    return i.type === "double_arrow" && (i.type = "double_arrow_point"), i.length = n.length, i;
-  }
-  return n;
-}, Se = (e, u) => {
-  let n = !1;
-  return e.forEach((i) => {
-    i.nodes.indexOf(u) >= 0 && (n = !0);
-  }), n;
-}, me = (e, u) => {
-  const n = [];
-  return e.nodes.forEach((i, c) => {
-    Se(u, i) || n.push(e.nodes[c]);
-  }), { nodes: n };
-}, Ve = {
-  firstGraph: Te
-}, Je = {
-  parseDirective: $2,
-  defaultConfig: () => Ne.flowchart,
-  setAccTitle: Oe,
-  getAccTitle: Pe,
-  getAccDescription: we,
-  setAccDescription: Ue,
-  addVertex: ee,
-  lookUpDomId: c2,
-  addLink: se,
-  updateLinkInterpolate: ue,
-  updateLink: ne,
-  addClass: re,
-  setDirection: ie,
-  setClass: y2,
-  setTooltip: ze,
-  getTooltip: ce,
-  setClickEvent: oe,
-  setLink: ae,
-  bindFunctions: le,
-  getDirection: he,
-  getVertices: fe,
-  getEdges: Ae,
-  getClasses: de,
-  clear: Ee,
-  setGen: be,
-  defaultStyle: ke,
-  addSubGraph: De,
-  getDepthFirstPos: Ce,
-  indexNodes: _e,
-  getSubGraphs: Be,
-  destructLink: ye,
-  lex: Ve,
-  exists: Se,
-  makeUniq: me,
-  setDiagramTitle: Me,
-  getDiagramTitle: Ge
-}, ut = /* @__PURE__ */ Object.freeze(/* @__PURE__ */ Object.defineProperty({
-  __proto__: null,
-  addClass: re,
-  addLink: se,
-  addSingleLink: te,
-  addSubGraph: De,
-  addVertex: ee,
-  bindFunctions: le,
-  clear: Ee,
-  default: Je,
-  defaultStyle: ke,
-  destructLink: ye,
-  firstGraph: Te,
-  getClasses: de,
-  getDepthFirstPos: Ce,
-  getDirection: he,
-  getEdges: Ae,
-  getSubGraphs: Be,
-  getTooltip: ce,
-  getVertices: fe,
-  indexNodes: _e,
-  lex: Ve,
-  lookUpDomId: c2,
-  parseDirective: $2,
-  setClass: y2,
-  setClickEvent: oe,
-  setDirection: ie,
-  setGen: be,
-  setLink: ae,
-  updateLink: ne,
-  updateLinkInterpolate: ue
-}, Symbol.toStringTag, { value: "Module" }));
-export {
-  ut as d,
-  Je as f,
-  st as p
-};
-//# sourceMappingURL=flowDb-39497bf7.js.map
@@ -1,27 +0,0 @@
-import { p as e, f as o } from "./flowDb-39497bf7.js";
-import { f as t, g as a } from "./styles-3ce90e7a.js";
-import { i } from "./commonDb-41f8b4c5.js";
-import "./utils-8ea37061.js";
-import "./mermaidAPI-67f627de.js";
-import "./layout-492ec81d.js";
-import "./index-6271e032.js";
-import "./edges-de377bae.js";
-import "./createText-23817c58.js";
-import "./svgDraw-0a992cdb.js";
-import "./line-05ccbb85.js";
-import "./array-2ff2c7a6.js";
-import "./constant-2fe7eae5.js";
-import "./selectAll-4d781168.js";
-const n = {
-  parser: e,
-  db: o,
-  renderer: t,
-  styles: a,
-  init: (r) => {
-    r.flowchart || (r.flowchart = {}), r.flowchart.arrowMarkerAbsolute = r.arrowMarkerAbsolute, i({ flowchart: { arrowMarkerAbsolute: r.arrowMarkerAbsolute } }), t.setConf(r.flowchart), o.clear(), o.setGen("gen-2");
-  }
-};
-export {
-  n as diagram
-};
-//# sourceMappingURL=flowDiagram-v2-6bfe9e0e.js.map
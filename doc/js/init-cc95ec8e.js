@@ -1,17 +0,0 @@
-function initRange(domain, range) {
-  switch (arguments.length) {
-    case 0:
-      break;
-    case 1:
-      this.range(domain);
-      break;
-    default:
-      this.range(range).domain(domain);
-      break;
-  }
-  return this;
-}
-export {
-  initRange as i
-};
-//# sourceMappingURL=init-cc95ec8e.js.map
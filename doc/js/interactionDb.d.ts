@@ -1,2 +0,0 @@
-export declare const addFunction: (func: () => void) => void;
-export declare const attachFunctions: () => void;
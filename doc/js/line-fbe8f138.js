@@ -1,51 +0,0 @@
-import { a as array } from "./array-b7dcf730.js";
-import { c as constant, p as path } from "./constant-b644328d.js";
-import { y as curveLinear } from "./utils-1aebe9b6.js";
-function x(p) {
-  return p[0];
-}
-function y(p) {
-  return p[1];
-}
-function line(x$1, y$1) {
-  var defined = constant(true), context = null, curve = curveLinear, output = null;
-  x$1 = typeof x$1 === "function" ? x$1 : x$1 === void 0 ? x : constant(x$1);
-  y$1 = typeof y$1 === "function" ? y$1 : y$1 === void 0 ? y : constant(y$1);
-  function line2(data) {
-    var i, n = (data = array(data)).length, d, defined0 = false, buffer;
-    if (context == null)
-      output = curve(buffer = path());
-    for (i = 0; i <= n; ++i) {
-      if (!(i < n && defined(d = data[i], i, data)) === defined0) {
-        if (defined0 = !defined0)
-          output.lineStart();
-        else
-          output.lineEnd();
-      }
-      if (defined0)
-        output.point(+x$1(d, i, data), +y$1(d, i, data));
-    }
-    if (buffer)
-      return output = null, buffer + "" || null;
-  }
-  line2.x = function(_) {
-    return arguments.length ? (x$1 = typeof _ === "function" ? _ : constant(+_), line2) : x$1;
-  };
-  line2.y = function(_) {
-    return arguments.length ? (y$1 = typeof _ === "function" ? _ : constant(+_), line2) : y$1;
-  };
-  line2.defined = function(_) {
-    return arguments.length ? (defined = typeof _ === "function" ? _ : constant(!!_), line2) : defined;
-  };
-  line2.curve = function(_) {
-    return arguments.length ? (curve = _, context != null && (output = curve(context)), line2) : curve;
-  };
-  line2.context = function(_) {
-    return arguments.length ? (_ == null ? context = output = null : output = curve(context = _), line2) : context;
-  };
-  return line2;
-}
-export {
-  line as l
-};
-//# sourceMappingURL=line-fbe8f138.js.map
@@ -1 +0,0 @@
-import './diagram-api/diagram-orchestration';
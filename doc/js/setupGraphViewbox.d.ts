@@ -1,3 +0,0 @@
-export function calculateSvgSizeAttrs(height: number, width: number, useMaxWidth: boolean): Map<'height' | 'width' | 'style', string>;
-export function configureSvgSize(svgElem: SVGSVGElement, height: number, width: number, useMaxWidth: boolean): void;
-export function setupGraphViewbox(graph: any, svgElem: any, padding: any, useMaxWidth: any): void;
@@ -0,0 +1,109 @@
+#!/usr/bin/env node
+
+// Build the classic mermaid script from the ES module files, for the releases published without one.
+// Each module becomes a function of a registry, run once when it's first required, and the
+// default export of the entry file is set as `window.mermaid`.
+// Only the rollup output of the mermaid releases is handled: relative imports at the top of
+// the files, `export { ... };` blocks at their end and `import("./...")` of the lazy chunks.
+// Usage: scripts/esm_to_classic.mjs <dist dir> <entry file> <output file>
+
+import fs from "node:fs";
+import path from "node:path";
+
+const [dir, entry, output] = process.argv.slice(2);
+if (!dir || !entry || !output) {
+  console.error("usage: esm_to_classic.mjs <dist dir> <entry file> <output file>");
+  process.exit(2);
+}
+
+const modules = new Map();
+const todo = [entry];
+while (todo.length) {
+  const name = todo.shift();
+  if (modules.has(name)) {
+    continue;
+  }
+  const source = fs.readFileSync(path.join(dir, name), "utf8");
+  const { body, requires } = convert(name, source);
+  modules.set(name, body);
+  todo.push(...requires);
+}
+
+let script = "(function () {\n  var modules = {};\n  var cache = {};\n";
+script += "  function __classicRequire(name) {\n";
+script += "    if (!(name in cache)) {\n";
+script += "      cache[name] = {};\n";
+script += "      modules[name](cache[name], __classicRequire);\n";
+script += "    }\n";
+script += "    return cache[name];\n";
+script += "  }\n";
+for (const [name, body] of modules) {
+  script += `  modules[${JSON.stringify(name)}] = function (__classicExports, __classicRequire) {\n"use strict";\n${body}\n};\n`;
+}
+script += `  window.mermaid = __classicRequire(${JSON.stringify(entry)}).default;\n})();\n`;
+fs.writeFileSync(output, script);
+
+function convert(name, source) {
+  const requires = [];
+  const exports = [];
+  const lines = [];
+  let exporting = false;
+  for (const line of source.split("\n")) {
+    if (exporting) {
+      if (/^\};?$/.test(line)) {
+        exporting = false;
+        continue;
+      }
+      const binding = line.trim().replace(/,$/, "");
+      const [local, exported = local] = binding.split(/\s+as\s+/);
+      exports.push([local, exported]);
+      continue;
+    }
+    if (/^export \{$/.test(line)) {
+      exporting = true;
+      continue;
+    }
+    if (/^export\b/.test(line)) {
+      fail(name, line);
+    }
+    let m;
+    if ((m = /^import \{([^}]*)\} from "\.\/([^"]+)";$/.exec(line))) {
+      requires.push(m[2]);
+      const bindings = m[1]
+        .split(",")
+        .map((binding) => binding.trim())
+        .filter(Boolean)
+        .map((binding) => binding.replace(/\s+as\s+/, ": "));
+      lines.push(`const { ${bindings.join(", ")} } = __classicRequire("${m[2]}");`);
+      continue;
+    }
+    if ((m = /^import "\.\/([^"]+)";$/.exec(line))) {
+      requires.push(m[1]);
+      lines.push(`__classicRequire("${m[1]}");`);
+      continue;
+    }
+    if (/^import\b/.test(line)) {
+      fail(name, line);
+    }
+    if (/^\/\/# sourceMappingURL=/.test(line)) {
+      continue;
+    }
+    lines.push(
+      line.replace(/\bimport\("\.\/([^"]+)"\)/g, (_, chunk) => {
+        requires.push(chunk);
+        return `Promise.resolve().then(function () { return __classicRequire("${chunk}"); })`;
+      })
+    );
+  }
+  // the exports are read when they are used, as the bindings of the modules are
+  const getters = exports.map(
+    ([local, exported]) =>
+      `Object.defineProperty(__classicExports, ${JSON.stringify(exported)}, { enumerable: true, get: function () { return ${local}; } });`
+  );
+  return { body: getters.concat(lines).join("\n"), requires };
+}
+
+function fail(name, line) {
+  console.error(`error: ${name}: can't convert \`${line}\``);
+  process.exit(1);
+}
@@ -1,15 +1,20 @@
 #!/bin/sh
 
-# Download and bundle mermaid module files into single Zip
+# Download mermaid module files and bundle the ones loaded by the browser into a compressed archive
 # Author: Frank Rehberger
 # Date: 2023.03.09
-# Usage: scripts/package_mermaid_release.sh doc/js https://cdn.jsdelivr.net/npm/mermaid@10/dist/ mermaid.esm.min.mjs
+# Usage: scripts/package_mermaid_release.sh doc https://cdn.jsdelivr.net/npm/mermaid@10/dist/ mermaid.esm.min.mjs
 
-PKG_DIR="${1:-./doc/js}"
-PKG_URL="${2:-https://cdn.jsdelivr.net/npm/mermaid@11.1.0/dist/}"
+PKG_DIR="${1:-./doc}"
+PKG_URL="${2:-https://cdn.jsdelivr.net/npm/mermaid@10.1.0/dist/}"
 PKG_NAME="${3:-mermaid.esm.min.mjs}"
 
-DOWNLOAD_DIR="${PKG_DIR}.dl"
+DOWNLOAD_DIR="${PKG_DIR}/mermaid.dl"
+# IIFE build, loaded by the docs opened from file://
+PKG_CLASSIC_NAME="mermaid.min.js"
+BUNDLE_NAME="mermaid.tar.gz"
+# list of the bundled files, checked when the bundle is extracted
+MANIFEST_NAME="MANIFEST"
 
 pkg_download() {
   url="$1"
@@ -20,18 +25,46 @@ pkg_download() {
   done
 }
 
+# Entry files and the chunks they import, transitively.
+# Typings, source maps and the chunks of the non-minified builds are left out.
+runtime_files() {
+  todo="$*"
+  seen=""
+  while [ -n "$todo" ]; do
+    set -- $todo
+    file="$1"
+    shift
+    todo="$*"
+    case " $seen " in *" $file "*) continue;; esac
+    # bundled libraries mention files of their own which aren't published
+    test -f "$file" || continue
+    seen="$seen $file"
+    todo="$todo $(grep -oE '"\./[^"]+\.m?js"' "$file" | tr -d '"' | sed 's|^\./||' | sort -u)"
+  done
+  echo $seen | tr ' ' '\n' | sort
+}
+
 echo "Downloading packages from $PKG_URL"
 if ! test -d "$DOWNLOAD_DIR"; then
   mkdir -p "$DOWNLOAD_DIR"
   ( cd "$DOWNLOAD_DIR";  pkg_download "$PKG_URL")
-fi 
+fi
 
-mkdir -p "$PKG_DIR"
-echo "Creating bundle $PKG_DIR/$PKG_NAME.zip"
-PKG_FILE=$(realpath $PKG_DIR/$PKG_NAME.zip)
-( cd "$DOWNLOAD_DIR"; zip -9 -r "$PKG_FILE" *)
-echo 
-echo "Bundle size"
-ls -alh "$PKG_DIR/$PKG_NAME.zip"
+# releases without the classic build get one converted from the module files
+if ! test -f "$DOWNLOAD_DIR/$PKG_CLASSIC_NAME"; then
+  echo "Converting $PKG_NAME into $PKG_CLASSIC_NAME"
+  node "$(dirname "$0")/esm_to_classic.mjs" "$DOWNLOAD_DIR" "$PKG_NAME" "$DOWNLOAD_DIR/$PKG_CLASSIC_NAME" || exit 1
+fi
 
+mkdir -p "$PKG_DIR"
+echo "Creating bundle $PKG_DIR/$BUNDLE_NAME"
+PKG_FILE=$(realpath "$PKG_DIR")/$BUNDLE_NAME
+(
+  cd "$DOWNLOAD_DIR"
+  runtime_files "$PKG_NAME" "$PKG_CLASSIC_NAME" > "$MANIFEST_NAME"
+  tar -czf "$PKG_FILE" "$MANIFEST_NAME" $(cat "$MANIFEST_NAME")
+)
+echo
+echo "Bundle size, $(wc -l < "$DOWNLOAD_DIR/$MANIFEST_NAME") files"
+ls -alh "$PKG_DIR/$BUNDLE_NAME"
 
@@ -1,102 +1,218 @@
-use include_dir::{include_dir, Dir};
+use crate::bundle::{self, IconPack, Source};
+use crate::config::{
+    self, html_attr_escape, json_str, Config, DiagramOptions, Extension, ExtensionKind,
+    BUNDLED_INTEGRITY, MERMAID_BUNDLED_VERSION,
+};
+use crate::docs_dir::{self, DocsDir};
+use crate::include::{self, Include};
+use crate::registry::{self, Definition};
+use crate::{highlight, lint, template, validate};
+use base64::Engine;
 use itertools::Itertools;
-use proc_macro::Span;
-use proc_macro2::TokenStream;
-use proc_macro_error2::{abort, emit_call_site_warning, emit_error};
-use quote::quote;
+use proc_macro2::{Group, Literal, Span, TokenStream, TokenTree};
+use proc_macro_error2::{abort, emit_call_site_error, emit_call_site_warning, emit_error};
+use quote::{quote, quote_spanned};
+use sha2::{Digest, Sha384};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::fs;
+use std::ops::Range;
 use std::path::Path;
+use std::sync::{Mutex, OnceLock};
+use std::time::{SystemTime, UNIX_EPOCH};
 use std::{iter, path::PathBuf};
-use syn::{Attribute, Ident, MetaNameValue};
-
-// embedded JS code being inserted as html script elements
-static MERMAID_JS_DIR: Dir = include_dir!("$CARGO_MANIFEST_DIR/doc/js/");
+use syn::{spanned::Spanned, Attribute, MetaNameValue};
 
 // Note: relative path depends on sub-module the macro is invoked in:
 //  base=document.getElementById("rustdoc-vars").attributes["data-root-path"]
-const MERMAID_JS_LOCAL: &str = "static.files.mermaid/mermaid.esm.min.mjs";
-const MERMAID_JS_LOCAL_DIR: &str = "static.files.mermaid";
-const MERMAID_JS_CDN: &str = "https://unpkg.com/mermaid@11.1/dist/mermaid.esm.min.mjs";
+// IIFE build, loadable by pages opened from file://,
+// also the bundled file hashed by `integrity = "bundled"`
+const MERMAID_JS_CLASSIC_FILE: &str = "mermaid.min.js";
 
 const UNEXPECTED_ATTR_ERROR: &str =
     "unexpected attribute inside a diagram definition: only #[doc] is allowed";
 
 #[derive(Clone, Default)]
-pub struct Attrs(Vec<Attr>);
+pub struct Attrs {
+    attrs: Vec<Attr>,
+    config: Config,
+}
 
+/// Doc attribute parts, with the span of the line they originate from
 #[derive(Clone)]
 pub enum Attr {
     /// Attribute that is to be forwarded as-is
     Forward(Attribute),
     /// Doc comment that cannot be forwarded as-is
-    DocComment(Ident, String),
-    /// Diagram start token
-    DiagramStart(Ident),
+    DocComment(Span, String),
+    /// Diagram start token, with the options from the info string
+    DiagramStart(Span, DiagramOptions),
     /// Diagram entry (line)
-    DiagramEntry(Ident, String),
+    DiagramEntry(Span, String),
     /// Diagram end token
-    DiagramEnd(Ident),
+    DiagramEnd(Span),
     /// Include Anchor
-    DiagramIncludeAnchor(Ident, PathBuf),
+    DiagramIncludeAnchor(Span, Include),
+    /// Anchor of a diagram defined with `define=<name>`
+    DiagramUseAnchor(Span, String),
 }
 
 impl Attr {
-    pub fn as_ident(&self) -> Option<&Ident> {
+    pub fn span(&self) -> Span {
         match self {
-            Attr::Forward(attr) => attr.path().get_ident(),
-            Attr::DocComment(ident, _) => Some(ident),
-            Attr::DiagramStart(ident) => Some(ident),
-            Attr::DiagramEntry(ident, _) => Some(ident),
-            Attr::DiagramEnd(ident) => Some(ident),
-            Attr::DiagramIncludeAnchor(ident, _) => Some(ident),
+            Attr::Forward(attr) => attr.span(),
+            Attr::DocComment(span, _) => *span,
+            Attr::DiagramStart(span, _) => *span,
+            Attr::DiagramEntry(span, _) => *span,
+            Attr::DiagramEnd(span) => *span,
+            Attr::DiagramIncludeAnchor(span, _) => *span,
+            Attr::DiagramUseAnchor(span, _) => *span,
         }
     }
 
     pub fn is_diagram_end(&self) -> bool {
-        match self {
-            Attr::DiagramEnd(_) => true,
-            _ => false,
-        }
+        matches!(self, Attr::DiagramEnd(_))
     }
 
     pub fn is_diagram_start(&self) -> bool {
-        match self {
-            Attr::DiagramStart(_) => true,
-            _ => false,
-        }
+        matches!(self, Attr::DiagramStart(..))
     }
 
     pub fn expect_diagram_entry_text(&self) -> &str {
         match self {
             Attr::DiagramEntry(_, body) => body.as_str(),
-            _ => abort!(self.as_ident(), UNEXPECTED_ATTR_ERROR),
+            _ => abort!(self.span(), UNEXPECTED_ATTR_ERROR),
         }
     }
 }
-impl From<Vec<Attribute>> for Attrs {
-    fn from(attrs: Vec<Attribute>) -> Self {
-        let mut out = Attrs::default();
+impl Attrs {
+    pub fn new(config: Config, attrs: Vec<Attribute>) -> Self {
+        let mut out = Attrs {
+            attrs: Vec::new(),
+            config,
+        };
         out.push_attrs(attrs);
         out
     }
+
+    /// Files loaded with `include_mmd!`
+    pub fn includes(&self) -> impl Iterator<Item = PathBuf> + '_ {
+        self.attrs.iter().filter_map(|attr| match attr {
+            Attr::DiagramIncludeAnchor(_, include) => Some(include.resolve()),
+            _ => None,
+        })
+    }
+
+    /// Whether there are diagrams to render, inline, included or reused
+    pub fn has_diagrams(&self) -> bool {
+        self.attrs.iter().any(|attr| {
+            matches!(
+                attr,
+                Attr::DiagramStart(..)
+                    | Attr::DiagramIncludeAnchor(..)
+                    | Attr::DiagramUseAnchor(..)
+            )
+        })
+    }
+
+    /// Environment variables read by the `{{env.NAME}}` and `{{CARGO_*}}` placeholders
+    pub fn env_vars(&self) -> Vec<String> {
+        let mut vars = Vec::new();
+        for attr in &self.attrs {
+            match attr {
+                Attr::DiagramEntry(_, line) => {
+                    vars.extend(template::env_vars(line).map(String::from))
+                }
+                Attr::DiagramIncludeAnchor(_, include) => {
+                    // unreadable files and selections are reported when the diagram is expanded,
+                    // only the selected lines are read
+                    let data = fs::read_to_string(include.resolve()).unwrap_or_default();
+                    let lines = include.select(&data).unwrap_or_default();
+                    vars.extend(
+                        lines
+                            .into_iter()
+                            .flat_map(|(_, line)| template::env_vars(line))
+                            .map(String::from),
+                    );
+                }
+                _ => {}
+            }
+        }
+        vars
+    }
+}
+
+impl Attrs {
+    /// The icon packs of the diagrams, and the bootstrap for the first diagram of the docs block:
+    /// any block can be shown on its own, e.g. the docs of a trait method on the implementor
+    /// pages, where rustdoc shows the first HTML block of them only
+    fn bootstrap<'a>(
+        &self,
+        icon_packs: &'a mut Option<Vec<IconPack>>,
+    ) -> (&'a [IconPack], Option<TokenStream>) {
+        let mut bootstrap = None;
+        let icon_packs = icon_packs.get_or_insert_with(|| {
+            let packs = load_icon_packs(&self.config);
+            bootstrap = Some(generate_bootstrap_rustdoc(&self.config, &packs));
+            packs
+        });
+        (icon_packs, bootstrap)
+    }
 }
 
 impl quote::ToTokens for Attrs {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let mut attrs = self.0.iter();
+        let mut icon_packs = None;
+        let mut attrs = self.attrs.iter();
         while let Some(attr) = attrs.next() {
             match attr {
                 Attr::Forward(attr) => attr.to_tokens(tokens),
-                Attr::DocComment(_, comment) => tokens.extend(quote! {
-                    #[doc = #comment]
-                }),
-                Attr::DiagramStart(_) => {
-                    let diagram = attrs
-                        .by_ref()
-                        .take_while(|x| !x.is_diagram_end())
-                        .map(Attr::expect_diagram_entry_text);
+                Attr::DocComment(span, comment) => {
+                    let mut comment = Literal::string(comment);
+                    comment.set_span(*span);
+                    tokens.extend(quote_spanned! {*span=>
+                        #[doc = #comment]
+                    })
+                }
+                Attr::DiagramStart(span, options) => {
+                    let span = *span;
+                    let entries: Vec<&Attr> =
+                        attrs.by_ref().take_while(|x| !x.is_diagram_end()).collect();
+                    let locate = |line: usize| (entries[line].span(), None);
+                    let diagram = entries.iter().map(|x| x.expect_diagram_entry_text());
+                    let vars = self.config.vars.clone().unwrap_or_default();
+                    let expanded = match expand_vars(diagram, &vars, locate) {
+                        Some(expanded) => expanded,
+                        None => continue,
+                    };
 
-                    tokens.extend(generate_diagram_rustdoc(diagram));
+                    let lines: Vec<&str> = expanded.iter().map(AsRef::as_ref).collect();
+                    check_diagram(&lines, &self.config, locate);
+
+                    if let Some(name) = &options.define {
+                        let definition = Definition {
+                            lines: lines.iter().map(|line| line.to_string()).collect(),
+                            location: source_location(span),
+                        };
+                        if let Err(e) =
+                            registry::define(&registry::current_crate(), name, definition)
+                        {
+                            emit_error!(span, "{}", e);
+                        }
+                    }
+                    let highlight = highlight_statements(&lines, options, span);
+
+                    let (packs, bootstrap) = self.bootstrap(&mut icon_packs);
+                    let rustdoc = generate_diagram_rustdoc(
+                        lines
+                            .into_iter()
+                            .chain(highlight.iter().map(String::as_str)),
+                        &self.config,
+                        options,
+                        packs,
+                    );
+                    tokens.extend(respan(rustdoc, span));
+                    tokens.extend(bootstrap.map(|bootstrap| respan(bootstrap, span)));
                 }
                 // If that happens, then the parsing stage is faulty: doc comments outside of
                 // in between Start and End tokens are to be emitted as Attr::Forward or Attr::DocComment
@@ -104,19 +220,14 @@ impl quote::ToTokens for Attrs {
                     emit_call_site_warning!("encountered an unexpected attribute that's going to be ignored, this is a bug! ({})", body);
                 }
                 Attr::DiagramEnd(_) => (),
-                Attr::DiagramIncludeAnchor(_, path) => {
-                    // get cargo manifest dir
-                    let manifest_dir =
-                        std::env::var("CARGO_MANIFEST_DIR").unwrap_or(".".to_string());
-
-                    // append path to cargo manifest dir using PathBuf
-                    let path = &PathBuf::new().join(manifest_dir).join(path);
+                Attr::DiagramIncludeAnchor(span, include) => {
+                    let path = &include.resolve();
 
                     let data = match std::fs::read_to_string(path) {
                         Ok(data) => data,
                         Err(e) => {
                             emit_error!(
-                                Span::call_site(),
+                                span,
                                 "failed to read mermaid file from path {:?}: {}",
                                 path,
                                 e,
@@ -124,59 +235,484 @@ impl quote::ToTokens for Attrs {
                             continue;
                         }
                     };
-                    tokens.extend(generate_diagram_rustdoc(Some(data.as_str()).into_iter()))
+                    let selected = match include.select(&data) {
+                        Ok(selected) => selected,
+                        Err(e) => {
+                            emit_error!(span, "{}: {}", include.path.display(), e);
+                            continue;
+                        }
+                    };
+                    let locate = |line: usize| {
+                        let file = format!("{}:{}", include.path.display(), selected[line].0);
+                        (*span, Some(file))
+                    };
+                    let mut vars = self.config.vars.clone().unwrap_or_default();
+                    vars.extend(include.vars.clone());
+                    let lines = selected.iter().map(|(_, line)| *line);
+                    let expanded = match expand_vars(lines, &vars, locate) {
+                        Some(expanded) => expanded,
+                        None => continue,
+                    };
+
+                    let lines: Vec<&str> = expanded.iter().map(AsRef::as_ref).collect();
+                    check_diagram(&lines, &self.config, locate);
+                    let options = DiagramOptions {
+                        highlight: include.highlight.clone(),
+                        ..Default::default()
+                    };
+                    let highlight = highlight_statements(&lines, &options, *span);
+
+                    let (packs, bootstrap) = self.bootstrap(&mut icon_packs);
+                    let rustdoc = generate_diagram_rustdoc(
+                        lines
+                            .into_iter()
+                            .chain(highlight.iter().map(String::as_str)),
+                        &self.config,
+                        &options,
+                        packs,
+                    );
+                    tokens.extend(respan(rustdoc, *span));
+                    tokens.extend(bootstrap.map(|bootstrap| respan(bootstrap, *span)));
+                }
+                Attr::DiagramUseAnchor(span, name) => {
+                    let lines = match registry::lookup(&registry::current_crate(), name) {
+                        Ok(lines) => lines,
+                        Err(e) => {
+                            emit_error!(span, "{}", e);
+                            continue;
+                        }
+                    };
+                    let (packs, bootstrap) = self.bootstrap(&mut icon_packs);
+                    let rustdoc = generate_diagram_rustdoc(
+                        lines.iter().map(String::as_str),
+                        &self.config,
+                        &DiagramOptions::default(),
+                        packs,
+                    );
+                    tokens.extend(respan(rustdoc, *span));
+                    tokens.extend(bootstrap.map(|bootstrap| respan(bootstrap, *span)));
                 }
             }
         }
     }
 }
 
-fn place_mermaid_js() -> std::io::Result<()> {
-    let target_dir = std::env::var("CARGO_TARGET_DIR").unwrap_or("./target".to_string());
-    let docs_dir = Path::new(&target_dir).join("doc");
-    // extract mermaid module iff rustdoc folder exists already
-    if docs_dir.exists() {
-        let static_files_mermaid_dir = docs_dir.join(MERMAID_JS_LOCAL_DIR);
-        if static_files_mermaid_dir.exists() {
-            Ok(())
-        } else {
-            fs::create_dir_all(&static_files_mermaid_dir).unwrap();
-            MERMAID_JS_DIR.extract(static_files_mermaid_dir)?;
-            Ok(())
+/// Diagram lines with the `{{name}}` placeholders replaced
+///
+/// `None` if some variables are undefined, they are reported at their line
+fn expand_vars<'a>(
+    lines: impl Iterator<Item = &'a str>,
+    vars: &BTreeMap<String, String>,
+    locate: impl Fn(usize) -> (Span, Option<String>),
+) -> Option<Vec<Cow<'a, str>>> {
+    let mut undefined = false;
+    let expanded = lines
+        .enumerate()
+        .map(|(index, line)| {
+            template::expand(line, vars).unwrap_or_else(|e| {
+                match locate(index) {
+                    (span, Some(file)) => emit_error!(span, "{}: {}", file, e),
+                    (span, None) => emit_error!(span, "{}", e),
+                }
+                undefined = true;
+                Cow::Borrowed(line)
+            })
+        })
+        .collect();
+    (!undefined).then_some(expanded)
+}
+
+/// Statements emphasising the nodes of `options.highlight`, an invalid highlight reported at `span`
+fn highlight_statements(lines: &[&str], options: &DiagramOptions, span: Span) -> Vec<String> {
+    highlight::statements(lines, &options.highlight).unwrap_or_else(|e| {
+        emit_error!(span, "{}", e);
+        Vec::new()
+    })
+}
+
+/// Move the generated docs to the diagram they replace
+///
+/// rustdoc skips the intra-doc link diagnostics of the docs with parts at the macro call site
+fn respan(tokens: TokenStream, span: Span) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|tree| match tree {
+            TokenTree::Group(group) => {
+                let mut respanned = Group::new(group.delimiter(), respan(group.stream(), span));
+                respanned.set_span(span);
+                TokenTree::Group(respanned)
+            }
+            mut tree => {
+                tree.set_span(span);
+                tree
+            }
+        })
+        .collect()
+}
+
+/// Report the syntax errors of a diagram, or the lint warnings if there are none
+///
+/// `locate` maps a line of the diagram to the span reporting it, and to the file and line
+/// of the diagrams included from a file
+fn check_diagram(
+    lines: &[&str],
+    config: &Config,
+    locate: impl Fn(usize) -> (Span, Option<String>),
+) {
+    if config.is_validating() {
+        let errors = validate::check(lines);
+        for error in &errors {
+            match locate(error.line) {
+                (span, Some(file)) => {
+                    emit_error!(span, "mermaid syntax error: {}: {}", file, error.message)
+                }
+                (span, None) => emit_error!(span, "mermaid syntax error: {}", error.message),
+            }
+        }
+        if !errors.is_empty() {
+            return;
+        }
+    }
+
+    // warnings of proc-macros are only reported on nightly, so they are printed instead,
+    // once per crate: by rustdoc rendering its docs
+    if !docs_dir::Context::current().reports_lints() {
+        return;
+    }
+    let allowed = config.allow.as_deref().unwrap_or_default();
+    for warning in lint::check(lines, allowed) {
+        let (span, file) = locate(warning.line);
+        let location = source_location(span);
+        let location = match file {
+            Some(file) => format!("{}, included at {}", file, location),
+            None => location,
+        };
+        eprintln!("{}\n", warning.render(&location));
+    }
+}
+
+/// `file:line:column` of a span, for the messages that can't be attached to it
+///
+/// Locations are known to rustc 1.88 and newer, older compilers report `<token stream>:0:1`
+fn source_location(span: Span) -> String {
+    let start = span.start();
+    format!("{}:{}:{}", span.file(), start.line, start.column + 1)
+}
+
+fn place_mermaid_js(
+    context: &docs_dir::Context,
+    source: &Source,
+    icon_packs: &[IconPack],
+    offline: bool,
+) -> std::io::Result<()> {
+    let docs_dir = match context.resolve() {
+        DocsDir::Rendering(dir) => {
+            fs::create_dir_all(&dir)?;
+            dir
+        }
+        DocsDir::Existing(dir) => dir,
+        // builds, checks and IDEs don't render docs, the copy is placed by rustdoc
+        DocsDir::Missing(_) if !context.is_rustdoc() => return Ok(()),
+        DocsDir::Missing(candidates) => {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!(
+                    "no rustdoc output directory found among {}, set {} to point to it",
+                    candidates.iter().map(|dir| dir.display()).join(", "),
+                    docs_dir::DOC_DIR_ENV
+                ),
+            ))
+        }
+    };
+
+    let static_files_mermaid_dir = docs_dir.join(source.dir_name());
+    if !static_files_mermaid_dir.exists() {
+        extract_atomically(source, &static_files_mermaid_dir)?;
+    }
+
+    let entry = static_files_mermaid_dir.join(source.entry());
+    if offline && !entry.exists() {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            format!("{} is missing", entry.display()),
+        ));
+    }
+
+    let icon_packs_dir = docs_dir.join(bundle::ICON_PACKS_DIR);
+    for pack in icon_packs {
+        let path = icon_packs_dir.join(pack.file_name());
+        if !path.exists() {
+            fs::create_dir_all(&icon_packs_dir)?;
+            place_atomically(&path, |tmp| fs::write(tmp, pack.contents()))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Extract the build into a temporary directory and rename it into place,
+/// so that concurrent invocations never observe a partially written directory
+fn extract_atomically(source: &Source, dest: &Path) -> std::io::Result<()> {
+    place_atomically(dest, |tmp| {
+        fs::create_dir_all(tmp)?;
+        source.extract(tmp)
+    })
+}
+
+/// Write a file or a directory under a temporary name and rename it into place
+fn place_atomically(
+    dest: &Path,
+    write: impl FnOnce(&Path) -> std::io::Result<()>,
+) -> std::io::Result<()> {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or_default();
+    let name = dest.file_name().unwrap_or_default().to_string_lossy();
+    let tmp = dest.with_file_name(format!(".{}.tmp-{}-{}", name, std::process::id(), nanos));
+
+    match write(&tmp).and_then(|_| fs::rename(&tmp, dest)) {
+        Ok(()) => Ok(()),
+        Err(e) => {
+            let _ = fs::remove_dir_all(&tmp);
+            let _ = fs::remove_file(&tmp);
+            // another invocation has won the race, its copy is complete
+            if dest.exists() {
+                Ok(())
+            } else {
+                Err(e)
+            }
         }
-    } else {
-        // no rustdocs rendering
-        Ok(())
     }
 }
 
 const MERMAID_INIT_SCRIPT: &str = r#"
-    const mermaidModuleFile = "{mermaidModuleFile}";
-    const fallbackRemoteUrl = "{fallbackRemoteUrl}";
+    const mermaidModuleFile = {mermaidModuleFile};
+    const mermaidClassicFile = {mermaidClassicFile};
+    // empty in the offline mode
+    const fallbackRemoteUrl = {fallbackRemoteUrl};
+    // the remote is loaded as a classic script verified by the browser when non-empty
+    const fallbackIntegrity = {fallbackIntegrity};
     const rustdocVarsId= "rustdoc-vars";
     const dataRootPathAttr = "data-root-path";
 
 
-    function initializeMermaid(mermaid) {
-     var amrn_mermaid_theme =
-         window.matchMedia && window.matchMedia('(prefers-color-scheme: dark)').matches
-         ? 'dark'
-         : 'default';
+    // theme map of the diagrams without a `data-theme-map` of their own
+    const defaultThemeMap = {themeMap};
+
+    // remote extensions enabled with cargo features, registered once per page
+    const extensions = {extensions};
+
+    // Every docs block with diagrams carries a copy of the bootstrap, as rustdoc shows
+    // some docs on their own, e.g. trait methods on the implementor pages. The copies
+    // share mermaid, loaded by the first one, and the state of the rendered diagrams.
+    const shared = window.aquamarine || (window.aquamarine = {
+      mermaid: null,
+      // diagrams are rendered one at a time, as every diagram may carry
+      // its own configuration in the `data-*` attributes
+      renderQueue: Promise.resolve(),
+      // rendered diagrams and their mermaid source, to re-render them on theme change
+      diagramSources: new Map(),
+      // files of the registered icon packs
+      iconPacks: new Set(),
+    });
+
+    function prefersDark() {
+      return window.matchMedia && window.matchMedia('(prefers-color-scheme: dark)').matches;
+    }
+
+    // Name of the active rustdoc theme, following the logic of rustdoc's settings
+    function rustdocTheme() {
+      const dataTheme = document.documentElement.getAttribute("data-theme");
+      if (dataTheme) {
+        return dataTheme;
+      }
+      try {
+        if (localStorage.getItem("rustdoc-use-system-theme") !== "false") {
+          return prefersDark()
+            ? localStorage.getItem("rustdoc-preferred-dark-theme") || "dark"
+            : localStorage.getItem("rustdoc-preferred-light-theme") || "light";
+        }
+        const stored = localStorage.getItem("rustdoc-theme");
+        if (stored) {
+          return stored;
+        }
+      } catch (e) {
+        // storage is not accessible, e.g. over file:// in some browsers
+      }
+      return prefersDark() ? "dark" : "light";
+    }
 
-      mermaid.initialize({
-        'startOnLoad':'true',
-        'theme': amrn_mermaid_theme,
-        'logLevel': 3 });
-      mermaid.run();
+    // Custom rustdoc themes are mapped by the brightness of the page background
+    function hasDarkBackground() {
+      const rgb = getComputedStyle(document.body).backgroundColor.match(/[\d.]+/g);
+      if (!rgb || rgb.length < 3) {
+        return prefersDark();
+      }
+      return 0.299 * rgb[0] + 0.587 * rgb[1] + 0.114 * rgb[2] < 128;
+    }
+
+    function defaultMermaidTheme(themeMap) {
+      const theme = rustdocTheme();
+      if (theme in themeMap) {
+        return themeMap[theme];
+      }
+      return hasDarkBackground() ? themeMap.dark : themeMap.light;
+    }
+
+    // Variables of the mermaid `base` theme, taken from the rustdoc theme CSS
+    function rustdocThemeVariables() {
+      const style = getComputedStyle(document.documentElement);
+      const cssVar = function (name, fallback) {
+        return style.getPropertyValue(name).trim() || fallback;
+      };
+
+      const background = cssVar("--main-background-color", "white");
+      const text = cssVar("--main-color", "black");
+      const link = cssVar("--link-color", text);
+      const code = cssVar("--code-block-background-color", background);
+      const border = cssVar("--border-color", text);
+      const alt = cssVar("--table-alt-row-background-color", code);
+
+      return {
+        darkMode: hasDarkBackground(),
+        background: background,
+        fontFamily: getComputedStyle(document.body).fontFamily,
+        textColor: text,
+        lineColor: text,
+        primaryColor: code,
+        primaryTextColor: text,
+        primaryBorderColor: link,
+        secondaryColor: alt,
+        secondaryTextColor: text,
+        secondaryBorderColor: border,
+        tertiaryColor: background,
+        tertiaryTextColor: text,
+        tertiaryBorderColor: border,
+        mainBkg: code,
+        nodeBorder: link,
+        clusterBkg: alt,
+        clusterBorder: border,
+        edgeLabelBackground: background,
+        noteBkgColor: alt,
+        noteTextColor: text,
+        noteBorderColor: border,
+        actorBkg: code,
+        actorBorder: link,
+        actorTextColor: text,
+        signalColor: text,
+        signalTextColor: text,
+        labelBoxBkgColor: code,
+        labelBoxBorderColor: border,
+        labelTextColor: text,
+      };
+    }
+
+    // The `rustdoc` theme is the mermaid `base` theme in the rustdoc theme colors
+    function resolveTheme(config) {
+      if (config.theme === "rustdoc") {
+        config.theme = "base";
+        config.themeVariables = Object.assign(rustdocThemeVariables(), config.themeVariables);
+      }
+      return config;
+    }
+
+    // Changes with the rustdoc theme, even if the mermaid theme stays the same
+    function themeSignature() {
+      return rustdocTheme() + JSON.stringify(rustdocThemeVariables());
+    }
+
+    function renderDiagram(mermaid, elem) {
+      if (elem.dataset.amrnScheduled) {
+        return;
+      }
+      elem.dataset.amrnScheduled = "true";
+      if (!shared.diagramSources.has(elem)) {
+        shared.diagramSources.set(elem, elem.innerHTML);
+      }
+      const themeMap = JSON.parse(elem.dataset.themeMap || "null") || defaultThemeMap;
+      registerIconPacks(mermaid, JSON.parse(elem.dataset.iconPacks || "[]"));
+      shared.renderQueue = shared.renderQueue.then(async function () {
+        mermaid.initialize(resolveTheme(Object.assign({
+          'startOnLoad': false,
+          'theme': defaultMermaidTheme(themeMap),
+          'logLevel': 3 }, JSON.parse(elem.dataset.config || "{}"))));
+        await mermaid.run({ nodes: [elem] });
+      }).catch(console.error);
+    }
+
+    function rerenderDiagrams(mermaid) {
+      for (const [elem, source] of shared.diagramSources) {
+        if (elem.hasAttribute("data-processed")) {
+          elem.removeAttribute("data-processed");
+          delete elem.dataset.amrnScheduled;
+          elem.innerHTML = source;
+          renderDiagram(mermaid, elem);
+        }
+      }
+    }
+
+    function watchThemeChanges(mermaid) {
+      var currentTheme = themeSignature();
+      const onChange = function () {
+        const theme = themeSignature();
+        if (theme !== currentTheme) {
+          currentTheme = theme;
+          rerenderDiagrams(mermaid);
+        }
+      };
+
+      // rustdoc switches themes through the `data-theme` attribute, older versions
+      // replace the theme stylesheet, and other tabs report through the storage
+      const observer = new MutationObserver(onChange);
+      observer.observe(document.documentElement, {
+        attributes: true, attributeFilter: ["data-theme"] });
+      observer.observe(document.head, {
+        childList: true, subtree: true, attributes: true, attributeFilter: ["href", "disabled"] });
+      window.addEventListener("storage", onChange);
+      if (window.matchMedia) {
+        window.matchMedia('(prefers-color-scheme: dark)').addEventListener("change", onChange);
+      }
+    }
+
+    function initializeMermaid(mermaid) {
+      const observer = 'IntersectionObserver' in window
+        ? new IntersectionObserver(function (entries, observer) {
+            for (const entry of entries) {
+              if (entry.isIntersecting) {
+                observer.unobserve(entry.target);
+                renderDiagram(mermaid, entry.target);
+              }
+            }
+          })
+        : null;
+
+      for (const elem of document.getElementsByClassName("mermaid")) {
+        const details = elem.closest("details");
+        if (details && !details.open) {
+          // hidden diagrams cannot be laid out, render on the first expand
+          details.addEventListener("toggle", function () {
+            renderDiagram(mermaid, elem);
+          }, { once: true });
+        } else if (observer && elem.hasAttribute("data-lazy")) {
+          observer.observe(elem);
+        } else {
+          renderDiagram(mermaid, elem);
+        }
+      }
     }
 
 	function failedToLoadWarnings() {
+		const locations = fallbackRemoteUrl
+			? "from the local copy and from the remote location also"
+			: "from the local copy, the remote location is disabled in offline mode";
 		for(var elem of document.getElementsByClassName("mermaid")) {
 			 elem.innerHTML =
 			 `<div> <mark>
-			  &#9888; Cannot render diagram! Failed to import module from local
-			  file and remote location also!
-			  Either access the rustdocs via HTTP/S using a
+			  &#9888; Cannot render diagram! Failed to load mermaid ${locations}!
+			  The local copy is placed into the <code>static.files.mermaid-*</code> directory
+			  of the docs when they are generated, re-running <code>cargo doc</code> might help.
+			  Otherwise, access the rustdocs via HTTP/S using a
 			  <a href="https://developer.mozilla.org/en-US/docs/Learn/Common_questions/Tools_and_setup/set_up_a_local_testing_server">
 			   local web server
 			  </a>, for example:
@@ -188,226 +724,696 @@ const MERMAID_INIT_SCRIPT: &str = r#"
 	}
 
 
-    // If rustdoc is read from file directly, the import of mermaid module
-    // from file will fail. In this case falling back to remote location.
-    // If neither succeeds, the mermaid markdown is replaced by notice to
-    // enable file acecss in browser.
+    // ES modules can't be imported by pages opened from file://, but classic
+    // scripts can be loaded from there. The global `mermaid` is set by the
+    // IIFE build of mermaid.
+    function loadClassicScript(url, integrity) {
+      return new Promise(function (resolve, reject) {
+        const script = document.createElement("script");
+        if (integrity) {
+          script.integrity = integrity;
+          script.crossOrigin = "anonymous";
+        }
+        script.src = url;
+        script.onload = function () {
+          if (window.mermaid) {
+            resolve(window.mermaid);
+          } else {
+            reject(new Error("mermaid global is not defined by " + url));
+          }
+        };
+        script.onerror = reject;
+        document.head.appendChild(script);
+      });
+    }
+
+    async function importModule(url) {
+      const {
+        default: mermaid,
+      } = await import(url);
+      return mermaid;
+    }
+
+    function docsRootPath() {
+      const rootVars = document.getElementById(rustdocVarsId);
+      return rootVars ? rootVars.attributes[dataRootPathAttr].value : "./";
+    }
+
+    // Local copies come first: the classic script when the docs are opened
+    // from file://, the ES module otherwise. The remote location is the last resort.
+    async function loadMermaid() {
+      const rootPath = docsRootPath();
+
+      const loaders = [
+        function () { return importModule(rootPath + mermaidModuleFile); },
+        function () { return loadClassicScript(rootPath + mermaidClassicFile); },
+      ];
+      if (window.location.protocol === "file:") {
+        loaders.reverse();
+      }
+      if (fallbackRemoteUrl && fallbackIntegrity) {
+        loaders.push(function () { return loadClassicScript(fallbackRemoteUrl, fallbackIntegrity); });
+      } else if (fallbackRemoteUrl) {
+        loaders.push(function () { return importModule(fallbackRemoteUrl); });
+      }
+
+      for (const load of loaders) {
+        try {
+          return await load();
+        } catch (e) {
+          console.debug("aquamarine: failed to load mermaid", e);
+        }
+      }
+      throw new Error("failed to load mermaid");
+    }
+
+    // Failed extensions are reported, the diagrams are rendered without them.
+    async function registerExtensions(mermaid) {
+      const warn = function (what, e) {
+        console.warn("aquamarine: failed to register " + what, e);
+      };
+
+      for (const url of extensions.layouts) {
+        try {
+          const { default: loaders } = await import(url);
+          mermaid.registerLayoutLoaders(loaders);
+        } catch (e) {
+          warn(url, e);
+        }
+      }
+
+      const diagrams = [];
+      for (const url of extensions.diagrams) {
+        try {
+          diagrams.push((await import(url)).default);
+        } catch (e) {
+          warn(url, e);
+        }
+      }
+      if (diagrams.length > 0) {
+        try {
+          await mermaid.registerExternalDiagrams(diagrams);
+        } catch (e) {
+          warn("external diagrams", e);
+        }
+      }
+      return mermaid;
+    }
+
+    // Icon packs of a diagram are registered before it's rendered, once per page.
+    // The registration API appeared in mermaid 11, older versions and failed
+    // icon packs are reported, the diagrams are rendered without the icons.
+    function registerIconPacks(mermaid, packs) {
+      const rootPath = docsRootPath();
+      const iconPacks = packs.filter(function (pack) {
+        return !shared.iconPacks.has(pack.file);
+      });
+      for (const pack of iconPacks) {
+        shared.iconPacks.add(pack.file);
+      }
+      if (iconPacks.length > 0) {
+        try {
+          mermaid.registerIconPacks(iconPacks.map(function (pack) {
+            return {
+              name: pack.name,
+              loader: function () {
+                return fetch(rootPath + pack.file).then(function (response) {
+                  return response.json();
+                });
+              },
+            };
+          }));
+        } catch (e) {
+          console.warn("aquamarine: failed to register icon packs", e);
+        }
+      }
+    }
+
+    // The first copy of the bootstrap on the page loads mermaid, every copy renders
+    // the diagrams not rendered yet, e.g. the ones added to the page after the first one.
+    if (!shared.mermaid) {
+      shared.mermaid = loadMermaid().then(registerExtensions);
+      shared.mermaid.then(watchThemeChanges, function () {});
+    }
+
+    // If neither of the locations succeeds, the mermaid markdown
+    // is replaced by a notice.
     try {
-       var rootPath = document
-         .getElementById(rustdocVarsId)
-         .attributes[dataRootPathAttr]
-         .value;
-       const {
-         default: mermaid,
-       } = await import(rootPath + mermaidModuleFile);
-	   initializeMermaid(mermaid);
+      initializeMermaid(await shared.mermaid);
     } catch (e) {
-       try {
-         const {
-            default: mermaid,
-         } = await import(fallbackRemoteUrl);
-	     initializeMermaid(mermaid);
-       } catch (e) {
-		 failedToLoadWarnings();
-	   }
+      failedToLoadWarnings();
     }
 "#;
 
-fn generate_diagram_rustdoc<'a>(parts: impl Iterator<Item = &'a str>) -> TokenStream {
-    let preamble = iter::once(r#"<div class="mermaid">"#);
-    let postamble = iter::once("</div>");
+fn generate_diagram_rustdoc<'a>(
+    mut parts: impl Iterator<Item = &'a str>,
+    config: &Config,
+    options: &DiagramOptions,
+    icon_packs: &[IconPack],
+) -> TokenStream {
+    let config = options.apply(config);
+
+    let mut div = String::from(r#"<div class="mermaid""#);
+    if let Some(json) = config.mermaid_config_json() {
+        div.push_str(&format!(r#" data-config="{}""#, html_attr_escape(&json)));
+    }
+    // the bootstrap rendering the diagram may come from the docs of another item
+    let theme_map = config.theme_map_json();
+    if theme_map != Config::default().theme_map_json() {
+        div.push_str(&format!(
+            r#" data-theme-map="{}""#,
+            html_attr_escape(&theme_map)
+        ));
+    }
+    if !icon_packs.is_empty() {
+        div.push_str(&format!(
+            r#" data-icon-packs="{}""#,
+            html_attr_escape(&icon_packs_json(icon_packs))
+        ));
+    }
+    if config.is_lazy() {
+        div.push_str(" data-lazy");
+    }
+    div.push('>');
+
+    let mut preamble = vec![div];
+    let mut postamble = vec!["</div>".to_string()];
+
+    if options.is_figure() {
+        let mut figure = String::from("<figure");
+        if let Some(id) = &options.id {
+            figure.push_str(&format!(r#" id="{}""#, html_attr_escape(id)));
+        }
+        figure.push_str(&format!(r#" style="{}">"#, figure_style(options)));
+        preamble.insert(0, figure);
+
+        if let Some(caption) = &options.caption {
+            postamble.push(format!(
+                "<figcaption>{}</figcaption>",
+                html_attr_escape(caption)
+            ));
+        }
+        postamble.push("</figure>".to_string());
+    }
+
+    if config.is_collapsible() {
+        preamble.insert(0, "<details><summary>Diagram</summary>".to_string());
+        postamble.push("</details>".to_string());
+    }
+
+    let diagram = parts.join("\n");
+    let diagram = match &options.title {
+        Some(title) => with_title(&diagram, title),
+        None => diagram,
+    };
+
+    let body = preamble
+        .into_iter()
+        .chain(iter::once(diagram))
+        .chain(postamble)
+        .join("\n");
+
+    quote! {
+        #[doc = #body]
+    }
+}
 
+/// Script loading mermaid and rendering the diagrams, emitted once per docs block
+fn generate_bootstrap_rustdoc(config: &Config, icon_packs: &[IconPack]) -> TokenStream {
+    let source = Source::new(config).unwrap_or_else(|e| {
+        emit_call_site_error!(e);
+        Source::Bundled
+    });
+    if let Some(version) = source
+        .version()
+        .filter(|version| version != MERMAID_BUNDLED_VERSION)
+    {
+        warn_once(&format!(
+            "placing mermaid {} into the docs, while aquamarine is tested with the bundled {}",
+            version, MERMAID_BUNDLED_VERSION
+        ));
+    }
+    let integrity = fallback_integrity(config).unwrap_or_else(|e| {
+        emit_call_site_error!(e);
+        String::new()
+    });
+    // a blank line would end the HTML block of the diagram the script follows
     let mermaid_js_init = format!(
         r#"<script type="module">{}</script>"#,
-        MERMAID_INIT_SCRIPT
-            .replace("{mermaidModuleFile}", MERMAID_JS_LOCAL)
-            .replace("{fallbackRemoteUrl}", MERMAID_JS_CDN)
+        init_script(config, &source, &integrity)
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .join("\n")
     );
 
-    let body = preamble.chain(parts).chain(postamble).join("\n");
-
-    place_mermaid_js().unwrap_or_else(|e| {
-        eprintln!("failed to place mermaid.js on the filesystem: {}", e);
-    });
+    let context = docs_dir::Context::current();
+    match place_mermaid_js(&context, &source, icon_packs, config.is_offline()) {
+        Ok(()) => (),
+        // the copy placed ahead of rustdoc is a convenience only
+        Err(_) if !context.is_rustdoc() => (),
+        Err(e) if config.is_offline() => emit_call_site_error!(
+            "failed to place mermaid.js into the docs, which is required in offline mode: {}",
+            e
+        ),
+        Err(e) => warn_once(&format!(
+            "failed to place mermaid.js on the filesystem: {}",
+            e
+        )),
+    }
 
     quote! {
         #[doc = #mermaid_js_init]
-        #[doc = #body]
+    }
+}
+
+/// Body of the bootstrap script, the values are inserted as JSON
+fn init_script(config: &Config, source: &Source, integrity: &str) -> String {
+    let dir_name = source.dir_name();
+    MERMAID_INIT_SCRIPT
+        .replace(
+            "{mermaidModuleFile}",
+            &json_str(&format!("{}/{}", dir_name, source.entry())),
+        )
+        .replace(
+            "{mermaidClassicFile}",
+            &json_str(&format!("{}/{}", dir_name, MERMAID_JS_CLASSIC_FILE)),
+        )
+        .replace(
+            "{fallbackRemoteUrl}",
+            &json_str(&config.remote_url().unwrap_or_default()),
+        )
+        .replace("{fallbackIntegrity}", &json_str(integrity))
+        .replace("{themeMap}", &Config::default().theme_map_json())
+        .replace("{extensions}", &extensions_json(&config::extensions()))
+}
+
+/// `{ layouts, diagrams }` of the extensions to register
+fn extensions_json(extensions: &[Extension]) -> String {
+    let urls = |kind: ExtensionKind| {
+        extensions
+            .iter()
+            .filter(|extension| extension.kind == kind)
+            .map(|extension| json_str(extension.url))
+            .join(",")
+    };
+    format!(
+        r#"{{"layouts":[{}],"diagrams":[{}]}}"#,
+        urls(ExtensionKind::Layout),
+        urls(ExtensionKind::Diagram)
+    )
+}
+
+fn load_icon_packs(config: &Config) -> Vec<IconPack> {
+    config
+        .icon_pack_paths()
+        .filter_map(|path| {
+            IconPack::load(path)
+                .map_err(|e| emit_call_site_error!(e))
+                .ok()
+        })
+        .collect()
+}
+
+/// `[{ name, file }]` of the icon packs to register, with paths relative to the docs root
+fn icon_packs_json(icon_packs: &[IconPack]) -> String {
+    let icon_packs = icon_packs
+        .iter()
+        .map(|pack| {
+            format!(
+                r#"{{"name":{},"file":{}}}"#,
+                json_str(&pack.name),
+                json_str(&format!("{}/{}", bundle::ICON_PACKS_DIR, pack.file_name()))
+            )
+        })
+        .join(",");
+    format!("[{}]", icon_packs)
+}
+
+/// The bootstrap is generated for every page, the same warning is reported once
+fn warn_once(warning: &str) {
+    static WARNED: Mutex<Vec<String>> = Mutex::new(Vec::new());
+    let mut warned = WARNED.lock().unwrap_or_else(|e| e.into_inner());
+    if !warned.iter().any(|w| w == warning) {
+        eprintln!("warning: aquamarine: {}", warning);
+        warned.push(warning.to_string());
+    }
+}
+
+/// Subresource Integrity hash of the remote mermaid, empty when not verified
+fn fallback_integrity(config: &Config) -> Result<String, String> {
+    match config.integrity.as_deref() {
+        None => Ok(String::new()),
+        Some(BUNDLED_INTEGRITY) => bundled_integrity(),
+        Some(integrity) => Ok(integrity.to_string()),
+    }
+}
+
+/// `sha384` hash of the bundled classic mermaid build
+///
+/// The bundle is unpacked once, the hash is shared by all the documented entities
+fn bundled_integrity() -> Result<String, String> {
+    static INTEGRITY: OnceLock<Result<String, String>> = OnceLock::new();
+    INTEGRITY
+        .get_or_init(|| {
+            let file = bundle::file(MERMAID_JS_CLASSIC_FILE)
+                .map_err(|e| e.to_string())?
+                .ok_or_else(|| {
+                    format!(
+                        "`integrity = \"{}\"` requires {} in the bundled mermaid, which is missing from this build",
+                        BUNDLED_INTEGRITY, MERMAID_JS_CLASSIC_FILE
+                    )
+                })?;
+            Ok(sha384_integrity(&file))
+        })
+        .clone()
+}
+
+fn sha384_integrity(contents: &[u8]) -> String {
+    format!(
+        "sha384-{}",
+        base64::engine::general_purpose::STANDARD.encode(Sha384::digest(contents))
+    )
+}
+
+fn figure_style(options: &DiagramOptions) -> String {
+    let (margin_left, margin_right) = match options.align.as_deref() {
+        Some("center") => ("auto", "auto"),
+        Some("right") => ("auto", "0"),
+        _ => ("0", "auto"),
+    };
+
+    let mut style = format!("margin-left:{};margin-right:{}", margin_left, margin_right);
+    if let Some(align) = &options.align {
+        style.push_str(&format!(";text-align:{}", align));
+    }
+    if let Some(width) = &options.width {
+        style.push_str(&format!(";width:{}", width));
+    }
+    style
+}
+
+/// Set the diagram title through the mermaid frontmatter
+fn with_title(diagram: &str, title: &str) -> String {
+    let title = format!(
+        "title: \"{}\"",
+        html_attr_escape(&title.replace('\\', "\\\\").replace('"', "\\\""))
+    );
+
+    let trimmed = diagram.trim_start();
+    match trimmed.strip_prefix("---") {
+        // there's a frontmatter already, prepend the title to it
+        Some(rest) if rest.starts_with('\n') => format!("---\n{}{}", title, rest),
+        _ => format!("---\n{}\n---\n{}", title, diagram),
     }
 }
 
 impl Attrs {
     pub fn push_attrs(&mut self, attrs: Vec<Attribute>) {
-        use syn::Expr;
-        use syn::ExprLit;
-        use syn::Lit::*;
+        let base_indent = attrs
+            .iter()
+            .filter_map(doc_attr_value)
+            .flat_map(|doc| {
+                doc.value
+                    .lines()
+                    .filter(|line| !line.trim().is_empty())
+                    .map(indentation)
+                    .collect::<Vec<_>>()
+            })
+            .min()
+            .unwrap_or(0);
 
         let mut current_location = Location::OutsideDiagram;
-        let mut diagram_start_ident = None;
+        let mut diagram_start = None;
 
         for attr in attrs {
-            match attr.meta.require_name_value() {
-                Ok(MetaNameValue {
-                    value: Expr::Lit(ExprLit { lit: Str(s), .. }),
-                    path,
-                    ..
-                }) if path.is_ident("doc") => {
-                    let ident = path.get_ident().unwrap();
-                    for attr in split_attr_body(ident, &s.value(), &mut current_location) {
+            match doc_attr_value(&attr) {
+                Some(doc) => {
+                    let parts = split_attr_body(&doc, &mut current_location, base_indent);
+                    // attributes without diagram parts are forwarded untouched,
+                    // so that the rustdoc lints keep pointing at them
+                    if let [Attr::DocComment(_, comment)] = parts.as_slice() {
+                        if *comment == doc.value {
+                            self.attrs.push(Attr::Forward(attr));
+                            continue;
+                        }
+                    }
+                    for attr in parts {
                         if attr.is_diagram_start() {
-                            diagram_start_ident.replace(ident.clone());
+                            diagram_start.replace(attr.span());
                         }
-                        self.0.push(attr);
+                        self.attrs.push(attr);
                     }
                 }
-                _ => {
-                    if let Location::InsideDiagram = current_location {
+                None => {
+                    if let Location::InsideDiagram(_) = current_location {
                         abort!(attr, UNEXPECTED_ATTR_ERROR)
                     } else {
-                        self.0.push(Attr::Forward(attr))
+                        self.attrs.push(Attr::Forward(attr))
                     }
                 }
             }
         }
 
-        if current_location.is_inside() {
-            abort!(diagram_start_ident, "diagram code block is not terminated");
+        if let (true, Some(start)) = (current_location.is_inside(), diagram_start) {
+            abort!(start, "diagram code block is not terminated");
         }
     }
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
-enum Location {
-    OutsideDiagram,
-    InsideDiagram,
+/// String value of a `#[doc = "..."]` attribute, with the spans of its parts
+struct DocValue {
+    value: String,
+    /// The literal, if the value maps into it byte for byte
+    lit: Option<Literal>,
+    /// Span of the whole literal, the `///` line of a doc comment
+    span: Span,
 }
 
-impl Location {
-    fn is_inside(self) -> bool {
-        match self {
-            Location::InsideDiagram => true,
-            _ => false,
+impl DocValue {
+    /// Span of a `range` of the value, narrowed down to the range
+    /// where the compiler supports it, the span of the literal otherwise
+    fn span_of(&self, range: Range<usize>) -> Span {
+        // a single line is covered by the span of the literal already
+        if range.len() == self.value.len() {
+            return self.span;
         }
+        self.lit
+            .as_ref()
+            .and_then(|lit| {
+                // the value starts after the opening quote of `"..."`, `r"..."` or `r#"..."#`
+                let start = lit.to_string().find('"')? + 1;
+                lit.subspan(range.start + start..range.end + start)
+            })
+            .unwrap_or(self.span)
     }
 }
 
-fn split_attr_body(ident: &Ident, input: &str, loc: &mut Location) -> Vec<Attr> {
-    use self::Location::*;
+/// Extract the string value of a `#[doc = "..."]` attribute
+fn doc_attr_value(attr: &Attribute) -> Option<DocValue> {
+    use syn::Expr;
+    use syn::ExprLit;
+    use syn::Lit::*;
+
+    match attr.meta.require_name_value() {
+        Ok(MetaNameValue {
+            value: Expr::Lit(ExprLit { lit: Str(s), .. }),
+            path,
+            ..
+        }) if path.is_ident("doc") => {
+            let value = s.value();
+            let token = s.token();
+            let source = token.to_string();
+            // escapes shift the offsets of the value in the source
+            let verbatim = source.find('"').is_some_and(|start| {
+                source[start + 1..].trim_end_matches('#').strip_suffix('"') == Some(value.as_str())
+            });
+            Some(DocValue {
+                value,
+                lit: Some(token).filter(|_| verbatim),
+                span: s.span(),
+            })
+        }
+        _ => None,
+    }
+}
 
-    const TICKS: &str = "```";
-    const MERMAID: &str = "mermaid";
+/// Fenced code block delimiter, as defined by CommonMark
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct Fence {
+    /// Either '`' or '~'
+    marker: char,
+    /// Number of marker characters in the opening fence
+    len: usize,
+    /// Indentation of the opening fence, stripped from the content lines
+    indent: usize,
+}
 
-    let mut tokens = tokenize_doc_str(input).peekable();
+impl Fence {
+    /// Parse an opening code fence, returning the fence and its info string
+    fn open(line: &str) -> Option<(Fence, &str)> {
+        let indent = indentation(line);
+        if indent > 3 {
+            // indented code block or a paragraph continuation, not a fence
+            return None;
+        }
 
-    // Special case: empty strings outside the diagram span should be still generated
-    if tokens.peek().is_none() && !loc.is_inside() {
-        return vec![Attr::DocComment(ident.clone(), String::new())];
-    };
+        let rest = &line[indent..];
+        let marker = rest.chars().next().filter(|c| *c == '`' || *c == '~')?;
+        let len = rest.chars().take_while(|c| *c == marker).count();
+        if len < 3 {
+            return None;
+        }
 
-    // To aid rustc with type inference in closures
-    #[derive(Default)]
-    struct Ctx<'a> {
-        attrs: Vec<Attr>,
-        buffer: Vec<&'a str>,
-    }
+        let info = rest[len..].trim();
+        if marker == '`' && info.contains('`') {
+            // that's an inline code span
+            return None;
+        }
 
-    let mut ctx: Ctx<'_> = Default::default();
+        Some((
+            Fence {
+                marker,
+                len,
+                indent,
+            },
+            info,
+        ))
+    }
 
-    let flush_buffer_as_doc_comment = |ctx: &mut Ctx| {
-        if !ctx.buffer.is_empty() {
-            ctx.attrs.push(Attr::DocComment(
-                ident.clone(),
-                ctx.buffer.drain(..).join(" "),
-            ));
+    fn is_closed_by(&self, line: &str) -> bool {
+        let indent = indentation(line);
+        if indent > 3 {
+            return false;
         }
-    };
 
-    let flush_buffer_as_diagram_entry = |ctx: &mut Ctx| {
-        let s = ctx.buffer.drain(..).join(" ");
-        if !s.trim().is_empty() {
-            ctx.attrs.push(Attr::DiagramEntry(ident.clone(), s));
-        }
-    };
+        let rest = &line[indent..];
+        let len = rest.chars().take_while(|c| *c == self.marker).count();
+        len >= self.len && rest[len..].trim().is_empty()
+    }
 
-    while let Some(token) = tokens.next() {
-        match (*loc, token, tokens.peek()) {
-            // Detect include anchor
-            (OutsideDiagram, token, _) if token.starts_with("include_mmd!") => {
-                // cleanup
-                let path = token.trim_start_matches("include_mmd!").trim();
-                let path = path.trim_start_matches('(').trim_end_matches(')');
-                let path = path.trim_matches('"');
-                let path = PathBuf::from(path);
-                ctx.attrs
-                    .push(Attr::DiagramIncludeAnchor(ident.clone(), path));
-            }
-            // Flush the buffer, then open the diagram code block
-            (OutsideDiagram, TICKS, Some(&MERMAID)) => {
-                tokens.next();
-                *loc = InsideDiagram;
-                flush_buffer_as_doc_comment(&mut ctx);
-                ctx.attrs.push(Attr::DiagramStart(ident.clone()));
-            }
-            // Flush the buffer, close the code block
-            (InsideDiagram, TICKS, _) => {
-                *loc = OutsideDiagram;
-                flush_buffer_as_diagram_entry(&mut ctx);
-                ctx.attrs.push(Attr::DiagramEnd(ident.clone()))
-            }
-            _ => ctx.buffer.push(token),
-        }
+    /// Strip the fence indentation from a content line
+    fn strip_indent<'a>(&self, line: &'a str) -> &'a str {
+        &line[indentation(line).min(self.indent)..]
     }
+}
 
-    if !ctx.buffer.is_empty() {
-        if loc.is_inside() {
-            flush_buffer_as_diagram_entry(&mut ctx);
-        } else {
-            flush_buffer_as_doc_comment(&mut ctx);
-        };
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Location {
+    OutsideDiagram,
+    /// Inside of a `mermaid` code block
+    InsideDiagram(Fence),
+    /// Inside of any other fenced code block
+    InsideCodeBlock(Fence),
+}
+
+impl Location {
+    fn is_inside(self) -> bool {
+        matches!(self, Location::InsideDiagram(_))
     }
+}
 
-    ctx.attrs
+fn indentation(line: &str) -> usize {
+    line.len() - line.trim_start_matches(' ').len()
 }
 
-fn tokenize_doc_str(input: &str) -> impl Iterator<Item = &str> {
-    const TICKS: &str = "```";
-    split_inclusive(input, TICKS).flat_map(|token| {
-        // not str::split_whitespace because we don't wanna filter-out the whitespace tokens
-        token.split(' ')
-    })
+/// Remove up to `n` leading spaces, the way rustdoc unindents doc comments
+fn unindent(line: &str, n: usize) -> &str {
+    &line[indentation(line).min(n)..]
 }
 
-// TODO: remove once str::split_inclusive is stable
-fn split_inclusive<'a, 'b: 'a>(input: &'a str, delim: &'b str) -> impl Iterator<Item = &'a str> {
-    let mut tokens = vec![];
-    let mut prev = 0;
+fn split_attr_body(doc: &DocValue, loc: &mut Location, base_indent: usize) -> Vec<Attr> {
+    use self::Location::*;
 
-    for (idx, matches) in input.match_indices(delim) {
-        tokens.extend(nonempty(&input[prev..idx]));
+    const MERMAID: &str = "mermaid";
 
-        prev = idx + matches.len();
+    let mut attrs = Vec::new();
+    // lines with their offsets in the value
+    let mut buffer: Vec<(usize, &str)> = Vec::new();
 
-        tokens.push(matches);
-    }
+    let flush_buffer_as_doc_comment = |attrs: &mut Vec<Attr>, buffer: &mut Vec<(usize, &str)>| {
+        if let (Some((start, _)), Some((last, line))) = (buffer.first(), buffer.last()) {
+            let span = doc.span_of(*start..last + line.len());
+            let comment = buffer.drain(..).map(|(_, line)| line).join("\n");
+            attrs.push(Attr::DocComment(span, comment));
+        }
+    };
 
-    if prev < input.len() {
-        tokens.push(&input[prev..]);
+    let mut offset = 0;
+    for line in doc.value.split('\n') {
+        let line_offset = offset;
+        offset += line.len() + 1;
+        let span = doc.span_of(line_offset..line_offset + line.len());
+        let unindented = unindent(line, base_indent);
+        let anchor = unindented.trim_start();
+
+        match *loc {
+            OutsideDiagram => match Fence::open(unindented) {
+                // Flush the buffer, then open the diagram code block
+                Some((fence, info)) if info.split_whitespace().next() == Some(MERMAID) => {
+                    let options = DiagramOptions::parse(&info[MERMAID.len()..])
+                        .unwrap_or_else(|e| abort!(span, "{}", e));
+                    flush_buffer_as_doc_comment(&mut attrs, &mut buffer);
+                    *loc = InsideDiagram(fence);
+                    attrs.push(Attr::DiagramStart(span, options));
+                }
+                Some((fence, _)) => {
+                    *loc = InsideCodeBlock(fence);
+                    buffer.push((line_offset, line));
+                }
+                // Detect include and use anchors
+                None if (Include::is_anchor(anchor) || include::is_use_anchor(anchor))
+                    && indentation(unindented) <= 3 =>
+                {
+                    flush_buffer_as_doc_comment(&mut attrs, &mut buffer);
+                    let (attr, rest) = if Include::is_anchor(anchor) {
+                        let (include, rest) =
+                            Include::parse_anchor(anchor).unwrap_or_else(|e| abort!(span, "{}", e));
+                        (Attr::DiagramIncludeAnchor(span, include), rest)
+                    } else {
+                        let (name, rest) = include::parse_use_anchor(anchor)
+                            .unwrap_or_else(|e| abort!(span, "{}", e));
+                        (Attr::DiagramUseAnchor(span, name), rest)
+                    };
+                    attrs.push(attr);
+                    // Text following the invocation stays in the docs, as a paragraph of its
+                    // own rather than a part of the diagram's HTML block
+                    if !rest.trim().is_empty() {
+                        let rest_offset = line_offset + line.len() - rest.len();
+                        attrs.push(Attr::DocComment(span, String::new()));
+                        buffer.push((rest_offset, rest.trim_start()));
+                    }
+                }
+                None => buffer.push((line_offset, line)),
+            },
+            InsideCodeBlock(fence) => {
+                if fence.is_closed_by(unindented) {
+                    *loc = OutsideDiagram;
+                }
+                buffer.push((line_offset, line));
+            }
+            // Close the diagram code block
+            InsideDiagram(fence) if fence.is_closed_by(unindented) => {
+                *loc = OutsideDiagram;
+                attrs.push(Attr::DiagramEnd(span));
+            }
+            InsideDiagram(fence) => {
+                // Blank lines would terminate the HTML block the diagram is rendered into
+                let entry = fence.strip_indent(unindented);
+                if !entry.trim().is_empty() {
+                    attrs.push(Attr::DiagramEntry(span, entry.to_string()));
+                }
+            }
+        }
     }
 
-    tokens.into_iter()
-}
+    flush_buffer_as_doc_comment(&mut attrs, &mut buffer);
 
-fn nonempty(s: &str) -> Option<&str> {
-    if s.is_empty() {
-        None
-    } else {
-        Some(s)
-    }
+    attrs
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{split_inclusive, Attr};
+    use super::Attr;
     use std::fmt;
 
     #[cfg(test)]
@@ -416,12 +1422,13 @@ mod tests {
             match self {
                 Attr::Forward(..) => f.write_str("Attr::Forward"),
                 Attr::DocComment(_, body) => write!(f, "Attr::DocComment({:?})", body),
-                Attr::DiagramStart(..) => f.write_str("Attr::DiagramStart"),
+                Attr::DiagramStart(_, options) => write!(f, "Attr::DiagramStart({:?})", options),
                 Attr::DiagramEntry(_, body) => write!(f, "Attr::DiagramEntry({:?})", body),
                 Attr::DiagramEnd(..) => f.write_str("Attr::DiagramEnd"),
                 Attr::DiagramIncludeAnchor(_, path) => {
                     write!(f, "Attr::DiagramIncludeAnchor({:?})", path)
                 }
+                Attr::DiagramUseAnchor(_, name) => write!(f, "Attr::DiagramUseAnchor({:?})", name),
             }
         }
     }
@@ -436,41 +1443,31 @@ mod tests {
             use Attr::*;
             match (self, other) {
                 (DocComment(_, a), DocComment(_, b)) => a == b,
+                (DiagramStart(_, a), DiagramStart(_, b)) => a == b,
                 (DiagramEntry(_, a), DiagramEntry(_, b)) => a == b,
+                (DiagramIncludeAnchor(_, a), DiagramIncludeAnchor(_, b)) => a == b,
+                (DiagramUseAnchor(_, a), DiagramUseAnchor(_, b)) => a == b,
                 (a, b) => discriminant(a) == discriminant(b),
             }
         }
     }
 
-    #[test]
-    fn temp_split_inclusive() {
-        let src = "```";
-        let out: Vec<_> = split_inclusive(src, "```").collect();
-        assert_eq!(&out, &["```",]);
-
-        let src = "```abcd```";
-        let out: Vec<_> = split_inclusive(src, "```").collect();
-        assert_eq!(&out, &["```", "abcd", "```"]);
-
-        let src = "left```abcd```right";
-        let out: Vec<_> = split_inclusive(src, "```").collect();
-        assert_eq!(&out, &["left", "```", "abcd", "```", "right",]);
-    }
-
     mod split_attr_body_tests {
         use super::super::*;
 
-        use proc_macro2::Ident;
-        use proc_macro2::Span;
-
         use pretty_assertions::assert_eq;
 
-        fn i() -> Ident {
-            Ident::new("fake", Span::call_site())
+        fn s() -> Span {
+            Span::call_site()
         }
 
+        const BACKTICKS: Fence = Fence {
+            marker: '`',
+            len: 3,
+            indent: 0,
+        };
+
         struct TestCase<'a> {
-            ident: Ident,
             location: Location,
             input: &'a str,
             expect_location: Location,
@@ -479,22 +1476,29 @@ mod tests {
 
         fn check(case: TestCase) {
             let mut loc = case.location;
-            let attrs = split_attr_body(&case.ident, case.input, &mut loc);
+            let doc = DocValue {
+                value: case.input.to_string(),
+                lit: None,
+                span: s(),
+            };
+            let attrs = split_attr_body(&doc, &mut loc, 1);
             assert_eq!(loc, case.expect_location);
             assert_eq!(attrs, case.expect_attrs);
         }
 
         #[test]
-        fn one_line_one_diagram() {
+        fn multiline_attr_one_diagram() {
             let case = TestCase {
-                ident: i(),
                 location: Location::OutsideDiagram,
-                input: "```mermaid abcd```",
+                input: " left\n ```mermaid\n graph LR\n\n     a --> b\n ```\n right",
                 expect_location: Location::OutsideDiagram,
                 expect_attrs: vec![
-                    Attr::DiagramStart(i()),
-                    Attr::DiagramEntry(i(), "abcd".into()),
-                    Attr::DiagramEnd(i()),
+                    Attr::DocComment(s(), " left".into()),
+                    Attr::DiagramStart(s(), Default::default()),
+                    Attr::DiagramEntry(s(), "graph LR".into()),
+                    Attr::DiagramEntry(s(), "    a --> b".into()),
+                    Attr::DiagramEnd(s()),
+                    Attr::DocComment(s(), " right".into()),
                 ],
             };
 
@@ -502,24 +1506,47 @@ mod tests {
         }
 
         #[test]
-        fn one_line_multiple_diagrams() {
+        fn opening_fence() {
+            let case = TestCase {
+                location: Location::OutsideDiagram,
+                input: " ```mermaid",
+                expect_location: Location::InsideDiagram(BACKTICKS),
+                expect_attrs: vec![Attr::DiagramStart(s(), Default::default())],
+            };
+
+            check(case)
+        }
+
+        #[test]
+        fn opening_fence_with_options() {
+            let case = TestCase {
+                location: Location::OutsideDiagram,
+                input: r#" ```mermaid caption="Request lifecycle" id=lifecycle"#,
+                expect_location: Location::InsideDiagram(BACKTICKS),
+                expect_attrs: vec![Attr::DiagramStart(
+                    s(),
+                    DiagramOptions {
+                        caption: Some("Request lifecycle".into()),
+                        id: Some("lifecycle".into()),
+                        ..Default::default()
+                    },
+                )],
+            };
+
+            check(case)
+        }
+
+        #[test]
+        fn tilde_fence() {
             let case = TestCase {
-                ident: i(),
                 location: Location::OutsideDiagram,
-                input: "```mermaid abcd``` ```mermaid efgh``` ```mermaid ijkl```",
+                input: " ~~~~ mermaid\n abcd\n ```\n ~~~~",
                 expect_location: Location::OutsideDiagram,
                 expect_attrs: vec![
-                    Attr::DiagramStart(i()),
-                    Attr::DiagramEntry(i(), "abcd".into()),
-                    Attr::DiagramEnd(i()),
-                    Attr::DocComment(i(), " ".into()),
-                    Attr::DiagramStart(i()),
-                    Attr::DiagramEntry(i(), "efgh".into()),
-                    Attr::DiagramEnd(i()),
-                    Attr::DocComment(i(), " ".into()),
-                    Attr::DiagramStart(i()),
-                    Attr::DiagramEntry(i(), "ijkl".into()),
-                    Attr::DiagramEnd(i()),
+                    Attr::DiagramStart(s(), Default::default()),
+                    Attr::DiagramEntry(s(), "abcd".into()),
+                    Attr::DiagramEntry(s(), "```".into()),
+                    Attr::DiagramEnd(s()),
                 ],
             };
 
@@ -527,81 +1554,490 @@ mod tests {
         }
 
         #[test]
-        fn other_snippet() {
+        fn mermaid_inside_other_snippet() {
+            let case = TestCase {
+                location: Location::OutsideDiagram,
+                input: " ````rust\n /// ```mermaid\n include_mmd!(\"a.mmd\")\n ````",
+                expect_location: Location::OutsideDiagram,
+                expect_attrs: vec![Attr::DocComment(
+                    s(),
+                    " ````rust\n /// ```mermaid\n include_mmd!(\"a.mmd\")\n ````".into(),
+                )],
+            };
+
+            check(case)
+        }
+
+        #[test]
+        fn inline_code_span_is_not_a_fence() {
             let case = TestCase {
-                ident: i(),
                 location: Location::OutsideDiagram,
-                input: "```rust panic!()```",
+                input: " ```mermaid abcd```",
                 expect_location: Location::OutsideDiagram,
-                expect_attrs: vec![Attr::DocComment(i(), "``` rust panic!() ```".into())],
+                expect_attrs: vec![Attr::DocComment(s(), " ```mermaid abcd```".into())],
             };
 
             check(case)
         }
 
         #[test]
-        fn carry_over() {
+        fn indented_code_block_is_not_a_fence() {
             let case = TestCase {
-                ident: i(),
                 location: Location::OutsideDiagram,
-                input: "left```mermaid abcd```right",
+                input: "     ```mermaid",
+                expect_location: Location::OutsideDiagram,
+                expect_attrs: vec![Attr::DocComment(s(), "     ```mermaid".into())],
+            };
+
+            check(case)
+        }
+
+        #[test]
+        fn indented_fence() {
+            let case = TestCase {
+                location: Location::OutsideDiagram,
+                input: "   ```mermaid\n     abcd\n  efgh\n    ```",
                 expect_location: Location::OutsideDiagram,
                 expect_attrs: vec![
-                    Attr::DocComment(i(), "left".into()),
-                    Attr::DiagramStart(i()),
-                    Attr::DiagramEntry(i(), "abcd".into()),
-                    Attr::DiagramEnd(i()),
-                    Attr::DocComment(i(), "right".into()),
+                    Attr::DiagramStart(s(), Default::default()),
+                    Attr::DiagramEntry(s(), "  abcd".into()),
+                    Attr::DiagramEntry(s(), "efgh".into()),
+                    Attr::DiagramEnd(s()),
                 ],
             };
 
             check(case)
         }
 
+        #[test]
+        fn closing_fence_must_be_long_enough() {
+            let case = TestCase {
+                location: Location::InsideDiagram(Fence {
+                    len: 4,
+                    ..BACKTICKS
+                }),
+                input: " ```\n `````",
+                expect_location: Location::OutsideDiagram,
+                expect_attrs: vec![Attr::DiagramEntry(s(), "```".into()), Attr::DiagramEnd(s())],
+            };
+
+            check(case)
+        }
+
         #[test]
         fn multiline_termination() {
             let case = TestCase {
-                ident: i(),
-                location: Location::InsideDiagram,
-                input: "abcd```",
+                location: Location::InsideDiagram(BACKTICKS),
+                input: " abcd",
+                expect_location: Location::InsideDiagram(BACKTICKS),
+                expect_attrs: vec![Attr::DiagramEntry(s(), "abcd".into())],
+            };
+
+            check(case);
+
+            let case = TestCase {
+                location: Location::InsideDiagram(BACKTICKS),
+                input: " ```",
                 expect_location: Location::OutsideDiagram,
-                expect_attrs: vec![
-                    Attr::DiagramEntry(i(), "abcd".into()),
-                    Attr::DiagramEnd(i()),
-                ],
+                expect_attrs: vec![Attr::DiagramEnd(s())],
+            };
+
+            check(case)
+        }
+
+        #[test]
+        fn empty_line() {
+            let case = TestCase {
+                location: Location::OutsideDiagram,
+                input: "",
+                expect_location: Location::OutsideDiagram,
+                expect_attrs: vec![Attr::DocComment(s(), "".into())],
+            };
+
+            check(case);
+
+            let case = TestCase {
+                location: Location::InsideDiagram(BACKTICKS),
+                input: "",
+                expect_location: Location::InsideDiagram(BACKTICKS),
+                expect_attrs: vec![],
             };
 
             check(case)
         }
 
         #[test]
-        fn multiline_termination_single_token() {
+        fn include_anchor() {
             let case = TestCase {
-                ident: i(),
-                location: Location::InsideDiagram,
-                input: "```",
+                location: Location::OutsideDiagram,
+                input: r#" include_mmd!("diagram.mmd")"#,
                 expect_location: Location::OutsideDiagram,
-                expect_attrs: vec![Attr::DiagramEnd(i())],
+                expect_attrs: vec![Attr::DiagramIncludeAnchor(
+                    s(),
+                    Include {
+                        path: "diagram.mmd".into(),
+                        ..Default::default()
+                    },
+                )],
             };
 
             check(case)
         }
 
         #[test]
-        fn multiline_termination_carry() {
+        fn use_anchor() {
             let case = TestCase {
-                ident: i(),
-                location: Location::InsideDiagram,
-                input: "abcd```right",
+                location: Location::OutsideDiagram,
+                input: " use_mmd!(pipeline)",
+                expect_location: Location::OutsideDiagram,
+                expect_attrs: vec![Attr::DiagramUseAnchor(s(), "pipeline".into())],
+            };
+
+            check(case)
+        }
+
+        #[test]
+        fn include_anchor_with_text() {
+            let case = TestCase {
+                location: Location::OutsideDiagram,
+                input: r#" include_mmd!("arch.mmd", section = "storage") (simplified)"#,
                 expect_location: Location::OutsideDiagram,
                 expect_attrs: vec![
-                    Attr::DiagramEntry(i(), "abcd".into()),
-                    Attr::DiagramEnd(i()),
-                    Attr::DocComment(i(), "right".into()),
+                    Attr::DiagramIncludeAnchor(
+                        s(),
+                        Include {
+                            path: "arch.mmd".into(),
+                            section: Some("storage".into()),
+                            ..Default::default()
+                        },
+                    ),
+                    Attr::DocComment(s(), "".into()),
+                    Attr::DocComment(s(), "(simplified)".into()),
                 ],
             };
 
             check(case)
         }
     }
+
+    mod doc_value_tests {
+        use super::super::*;
+
+        fn doc_value(attr: Attribute) -> DocValue {
+            doc_attr_value(&attr).unwrap()
+        }
+
+        #[test]
+        fn maps_into_the_literal() {
+            assert!(doc_value(syn::parse_quote!(#[doc = " plain"]))
+                .lit
+                .is_some());
+            assert!(doc_value(syn::parse_quote!(#[doc = r#" "raw" "#]))
+                .lit
+                .is_some());
+            assert!(doc_value(syn::parse_quote!(#[doc = " a\n b"]))
+                .lit
+                .is_none());
+            assert!(doc_value(syn::parse_quote!(#[doc = " \"quoted\""]))
+                .lit
+                .is_none());
+        }
+
+        #[test]
+        fn spans_fall_back_to_the_literal() {
+            let doc = doc_value(syn::parse_quote!(#[doc = "```mermaid\ngraph LR\n```"]));
+            let span = doc.span_of(11..19);
+            assert_eq!(format!("{:?}", span), format!("{:?}", doc.span));
+        }
+    }
+
+    mod forwarding_tests {
+        use super::super::*;
+
+        #[test]
+        fn doc_comments_without_diagrams_are_forwarded() {
+            let attrs: Vec<Attribute> = syn::parse_quote! {
+                /// See [`Missing`] and <b>bold
+                /// ```mermaid
+                /// graph LR
+                /// ```
+                #[doc = " text\n```mermaid\ngraph TD\n```"]
+            };
+            let attrs = Attrs::new(Config::default(), attrs);
+            assert!(matches!(attrs.attrs[0], Attr::Forward(_)));
+            assert!(matches!(attrs.attrs[1], Attr::DiagramStart(..)));
+            assert!(matches!(attrs.attrs.last(), Some(Attr::DiagramEnd(_))));
+            assert!(attrs
+                .attrs
+                .iter()
+                .any(|attr| matches!(attr, Attr::DocComment(_, text) if text == " text")));
+
+            let tokens = quote!(#attrs).to_string();
+            assert!(tokens.starts_with(r#"# [doc = r" See [`Missing`] and <b>bold"]"#));
+        }
+    }
+
+    mod generate_diagram_rustdoc_tests {
+        use super::super::*;
+
+        #[test]
+        fn one_bootstrap_per_item() {
+            let attrs: Vec<Attribute> = syn::parse_quote! {
+                /// ```mermaid
+                /// graph LR
+                /// ```
+                /// ```mermaid
+                /// graph TD
+                /// ```
+            };
+            let attrs = Attrs::new(Config::default(), attrs);
+            let tokens = quote!(#attrs).to_string();
+            assert_eq!(tokens.matches("<script").count(), 1);
+            assert_eq!(tokens.matches(r#"<div class=\"mermaid\">"#).count(), 2);
+        }
+
+        #[test]
+        fn title_frontmatter() {
+            assert_eq!(
+                with_title("graph LR", r#"My "title""#),
+                "---\ntitle: \"My \\&quot;title\\&quot;\"\n---\ngraph LR"
+            );
+            assert_eq!(
+                with_title("---\nconfig:\n  look: handDrawn\n---\ngraph LR", "Title"),
+                "---\ntitle: \"Title\"\nconfig:\n  look: handDrawn\n---\ngraph LR"
+            );
+        }
+
+        #[test]
+        fn figure() {
+            let options = DiagramOptions::parse(
+                r#"id=lifecycle caption="Request <lifecycle>" width=80% align=center"#,
+            )
+            .unwrap();
+            let tokens =
+                generate_diagram_rustdoc(iter::once("graph LR"), &Config::default(), &options, &[])
+                    .to_string();
+
+            assert!(tokens.contains(
+                r#"<figure id=\"lifecycle\" style=\"margin-left:auto;margin-right:auto;text-align:center;width:80%\">"#
+            ));
+            assert!(
+                tokens.contains("<figcaption>Request &lt;lifecycle&gt;</figcaption>\\n</figure>")
+            );
+        }
+
+        #[test]
+        fn integrity() {
+            assert_eq!(
+                sha384_integrity(b""),
+                "sha384-OLBgp1GsljhM2TJ+sbHjaiH9txEUvgdDTAzHv2P24donTt6/529l+9Ua0vFImLlb"
+            );
+
+            let config = Config {
+                offline: Some(false),
+                integrity: Some("sha384-abcd".into()),
+                ..Default::default()
+            };
+            let tokens = generate_bootstrap_rustdoc(&config, &[]).to_string();
+            assert!(tokens.contains(r#"const fallbackIntegrity = \"sha384-abcd\""#));
+            assert!(tokens.contains("dist/mermaid.min.js"));
+        }
+
+        #[test]
+        fn icon_packs() {
+            let path = std::env::temp_dir()
+                .join(format!("aquamarine-icon-pack-{}.json", std::process::id()));
+            fs::write(&path, r#"{"prefix":"logos","icons":{}}"#).unwrap();
+            let pack = IconPack::load(path.to_str().unwrap()).unwrap();
+            fs::remove_file(&path).unwrap();
+
+            let tokens = generate_diagram_rustdoc(
+                iter::once("architecture-beta"),
+                &Config::default(),
+                &Default::default(),
+                &[pack],
+            )
+            .to_string();
+            assert!(tokens.contains(
+                r#"data-icon-packs=\"[{&quot;name&quot;:&quot;logos&quot;,&quot;file&quot;:&quot;static.files.mermaid-icons/logos-"#
+            ));
+        }
+
+        #[test]
+        fn extensions() {
+            assert_eq!(extensions_json(&[]), r#"{"layouts":[],"diagrams":[]}"#);
+            let extensions = [
+                Extension {
+                    name: "elk",
+                    kind: ExtensionKind::Layout,
+                    url: "https://cdn.local/elk.mjs",
+                },
+                Extension {
+                    name: "zenuml",
+                    kind: ExtensionKind::Diagram,
+                    url: "https://cdn.local/zenuml.mjs",
+                },
+            ];
+            assert_eq!(
+                extensions_json(&extensions),
+                r#"{"layouts":["https://cdn.local/elk.mjs"],"diagrams":["https://cdn.local/zenuml.mjs"]}"#
+            );
+            let script = init_script(&Config::default(), &Source::Bundled, "");
+            assert!(script.contains(&format!(
+                "const extensions = {};",
+                extensions_json(&config::extensions())
+            )));
+        }
+
+        #[test]
+        fn rustdoc_theme() {
+            let config = Config {
+                light_theme: Some("rustdoc".into()),
+                dark_theme: Some("rustdoc".into()),
+                ..Default::default()
+            };
+            // the diagrams carry the theme map, the bootstrap may come from another item
+            let tokens =
+                generate_diagram_rustdoc(iter::once("graph LR"), &config, &Default::default(), &[])
+                    .to_string();
+            assert!(tokens.contains(
+                r#"data-theme-map=\"{&quot;light&quot;:&quot;rustdoc&quot;,&quot;dark&quot;:&quot;rustdoc&quot;,&quot;ayu&quot;:&quot;dark&quot;}\""#
+            ));
+            let script = init_script(&config, &Source::Bundled, "");
+            assert!(script.contains(
+                r#"const defaultThemeMap = {"light":"default","dark":"dark","ayu":"dark"};"#
+            ));
+            // replaced by the `base` theme with the variables of the rustdoc theme at render time
+            assert!(script.contains(r#"if (config.theme === "rustdoc") {"#));
+
+            let options = DiagramOptions::parse("theme=rustdoc").unwrap();
+            let tokens =
+                generate_diagram_rustdoc(iter::once("graph LR"), &Config::default(), &options, &[])
+                    .to_string();
+            assert!(tokens.contains(r#"data-config=\"{&quot;theme&quot;:&quot;rustdoc&quot;}\""#));
+        }
+
+        #[test]
+        fn loader_urls() {
+            let dir = Source::Bundled.dir_name();
+            let config = Config {
+                offline: Some(false),
+                ..Default::default()
+            };
+            let script = init_script(&config, &Source::Bundled, "");
+            assert!(script.contains(&format!(
+                r#"const mermaidModuleFile = "{}/mermaid.esm.min.mjs";"#,
+                dir
+            )));
+            assert!(script.contains(&format!(
+                r#"const mermaidClassicFile = "{}/mermaid.min.js";"#,
+                dir
+            )));
+            assert!(script.contains(
+                r#"const fallbackRemoteUrl = "https://unpkg.com/mermaid@10.1.0/dist/mermaid.esm.min.mjs";"#
+            ));
+
+            // a verified remote copy is the classic build
+            let config = Config {
+                integrity: Some("sha384-abcd".into()),
+                ..config
+            };
+            let script = init_script(&config, &Source::Bundled, "sha384-abcd");
+            assert!(script.contains(
+                r#"const fallbackRemoteUrl = "https://unpkg.com/mermaid@10.1.0/dist/mermaid.min.js";"#
+            ));
+            assert!(script.contains(r#"const fallbackIntegrity = "sha384-abcd";"#));
+        }
+
+        #[test]
+        fn offline() {
+            let config = Config {
+                offline: Some(true),
+                cdn: Some("https://mirror.local/mermaid.mjs".into()),
+                integrity: Some("sha384-abcd".into()),
+                ..Default::default()
+            };
+            let script = init_script(&config, &Source::Bundled, "sha384-abcd");
+            assert!(script.contains(r#"const fallbackRemoteUrl = "";"#));
+            assert!(!script.contains("mirror.local"));
+        }
+
+        #[test]
+        fn docs_dir_can_not_be_placed() {
+            let root =
+                std::env::temp_dir().join(format!("aquamarine-unplaceable-{}", std::process::id()));
+            fs::create_dir_all(&root).unwrap();
+            // a file where the docs directory should go
+            let file = root.join("file");
+            fs::write(&file, "").unwrap();
+            let context = |args: &[&str]| docs_dir::Context {
+                args: args.iter().map(|arg| arg.to_string()).collect(),
+                vars: Default::default(),
+                manifest_dir: file.join("member"),
+                cargo_home: None,
+            };
+
+            let rustdoc = context(&["rustdoc", "-o", file.join("doc").to_str().unwrap()]);
+            for offline in [true, false].iter() {
+                assert!(place_mermaid_js(&rustdoc, &Source::Bundled, &[], *offline).is_err());
+            }
+
+            // no docs are rendered, nothing is placed
+            for args in [
+                &["rustc", "--emit=dep-info,link"][..],
+                &["rustc", "--emit=metadata"],
+            ]
+            .iter()
+            {
+                let build = context(args);
+                assert!(place_mermaid_js(&build, &Source::Bundled, &[], true).is_ok());
+                assert!(place_mermaid_js(&build, &Source::Bundled, &[], false).is_ok());
+            }
+            fs::remove_dir_all(&root).unwrap();
+        }
+
+        #[test]
+        fn script_values_are_json() {
+            let config = Config {
+                offline: Some(false),
+                cdn: Some(r#"https://x/"m.mjs</script>"#.into()),
+                ..Default::default()
+            };
+            let script = init_script(&config, &Source::Bundled, "");
+            assert!(
+                script.contains(r#"const fallbackRemoteUrl = "https://x/\"m.mjs\u003c/script>";"#)
+            );
+            assert!(script.contains(r#"const fallbackIntegrity = "";"#));
+            assert!(!script.contains("</script>"));
+        }
+
+        #[test]
+        fn bundled_integrity_of_the_bundle() {
+            // changes along with doc/mermaid.tar.gz only
+            let expected =
+                "sha384-Ll+TyZ4gsomn6qQlibSsRA3xghMOQhuX8WCC60Tj3cAYE8rmfuZOhy6V3tlf7zAJ";
+            assert_eq!(bundled_integrity().as_deref(), Ok(expected));
+            // cached
+            assert_eq!(bundled_integrity().as_deref(), Ok(expected));
+        }
+
+        #[test]
+        fn versioned_local_copy() {
+            let dir = Source::Bundled.dir_name();
+            assert!(dir.starts_with(&format!(
+                "static.files.mermaid-{}-",
+                MERMAID_BUNDLED_VERSION
+            )));
+
+            let docs = std::env::temp_dir().join(format!("aquamarine-test-{}", std::process::id()));
+            let dest = docs.join(dir);
+            fs::create_dir_all(&docs).unwrap();
+            extract_atomically(&Source::Bundled, &dest).unwrap();
+            // the directory is in place already
+            extract_atomically(&Source::Bundled, &dest).unwrap();
+            assert!(dest.join(Source::Bundled.entry()).exists());
+            assert_eq!(fs::read_dir(&docs).unwrap().count(), 1);
+            fs::remove_dir_all(&docs).unwrap();
+        }
+    }
 }
@@ -0,0 +1,376 @@
+use crate::config::{Config, MERMAID_BUNDLED_VERSION};
+use flate2::read::GzDecoder;
+use itertools::Itertools;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Component, Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::UNIX_EPOCH;
+
+// mermaid release produced by `scripts/package_mermaid_release.sh`:
+// the runtime files and the manifest listing them, as a gzipped tarball
+pub static MERMAID_JS_BUNDLE: &[u8] = include_bytes!("../doc/mermaid.tar.gz");
+const MANIFEST: &str = "MANIFEST";
+
+// ES module loaded by the docs, unless another entry is given with `mermaid_path`
+pub const MERMAID_JS_MODULE_FILE: &str = "mermaid.esm.min.mjs";
+// suffixed with the mermaid version and content hash, see `Source::dir_name`
+const MERMAID_JS_LOCAL_DIR_PREFIX: &str = "static.files.mermaid";
+/// Docs directory with the icon packs given with `icon_packs`
+pub const ICON_PACKS_DIR: &str = "static.files.mermaid-icons";
+
+/// Iconify JSON icon pack given with `icon_packs`
+#[derive(Debug)]
+pub struct IconPack {
+    /// Prefix of the icons, e.g. `logos` for `logos:rust`
+    pub name: String,
+    contents: Vec<u8>,
+}
+
+impl IconPack {
+    /// Read the icon pack, relative to the crate being documented
+    pub fn load(path: &str) -> Result<IconPack, String> {
+        let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or(".".to_string());
+        let path = Path::new(&manifest_dir).join(path);
+        let contents = fs::read(&path)
+            .map_err(|e| format!("failed to read icon pack {}: {}", path.display(), e))?;
+        let name = std::str::from_utf8(&contents)
+            .ok()
+            .and_then(|json| json_string_field(json, "prefix"))
+            .ok_or_else(|| {
+                format!(
+                    "{} is not an iconify icon pack: the `prefix` field is missing",
+                    path.display()
+                )
+            })?;
+        Ok(IconPack { name, contents })
+    }
+
+    /// Name of the placed file, keyed by the contents
+    pub fn file_name(&self) -> String {
+        let hash = Sha256::digest(&self.contents);
+        format!("{}-{}.json", self.name, short_hex(&hash))
+    }
+
+    pub fn contents(&self) -> &[u8] {
+        &self.contents
+    }
+}
+
+/// Mermaid build placed into the docs
+#[derive(Debug, PartialEq)]
+pub enum Source {
+    /// The bundle embedded into aquamarine
+    Bundled,
+    /// A build supplied with the `mermaid_path` option
+    Local { dir: PathBuf, entry: String },
+}
+
+impl Source {
+    /// Locate the build given by `mermaid_path`, relative to the crate being documented
+    pub fn new(config: &Config) -> Result<Source, String> {
+        let path = match &config.mermaid_path {
+            Some(path) => path,
+            None => return Ok(Source::Bundled),
+        };
+        let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or(".".to_string());
+        let path = Path::new(&manifest_dir).join(path);
+
+        if !path.exists() {
+            return Err(format!("`mermaid_path` {} doesn't exist", path.display()));
+        }
+
+        let (dir, entry) = if path.is_dir() {
+            (path, MERMAID_JS_MODULE_FILE.to_string())
+        } else {
+            let entry = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .filter(|name| name.ends_with(".mjs"));
+            match (path.parent(), entry) {
+                (Some(dir), Some(entry)) => (dir.to_path_buf(), entry.to_string()),
+                _ => {
+                    return Err(format!(
+                        "`mermaid_path` {} must be a directory or an `.mjs` file",
+                        path.display()
+                    ))
+                }
+            }
+        };
+
+        if !dir.join(&entry).is_file() {
+            return Err(format!(
+                "`mermaid_path`: the mermaid entry file {} doesn't exist",
+                dir.join(&entry).display()
+            ));
+        }
+        Ok(Source::Local { dir, entry })
+    }
+
+    /// Entry ES module, relative to the placed directory
+    pub fn entry(&self) -> &str {
+        match self {
+            Source::Bundled => MERMAID_JS_MODULE_FILE,
+            Source::Local { entry, .. } => entry,
+        }
+    }
+
+    /// Mermaid version, taken from `package.json` of a local build
+    pub fn version(&self) -> Option<String> {
+        match self {
+            Source::Bundled => Some(MERMAID_BUNDLED_VERSION.to_string()),
+            // either the package itself or its `dist` directory
+            Source::Local { dir, .. } => package_json_candidates(dir)
+                .find_map(|path| fs::read_to_string(path).ok())
+                .and_then(|json| json_string_field(&json, "version")),
+        }
+    }
+
+    /// Name of the docs directory holding the build, e.g. `static.files.mermaid-10.1.0-0123abcd`
+    ///
+    /// Keyed by the contents, so an upgraded or patched build is placed next to the stale one
+    /// instead of being shadowed by it
+    pub fn dir_name(&self) -> String {
+        static BUNDLED: OnceLock<String> = OnceLock::new();
+        match self {
+            Source::Bundled => BUNDLED
+                .get_or_init(|| {
+                    let mut hasher = Sha256::new();
+                    hasher.update(MERMAID_JS_BUNDLE);
+                    dir_name(MERMAID_BUNDLED_VERSION, hasher.finalize())
+                })
+                .clone(),
+            Source::Local { dir, .. } => {
+                // a local build can be big, its listing stands for the contents
+                let mut hasher = Sha256::new();
+                hasher.update(dir.to_string_lossy().as_bytes());
+                for (path, metadata) in list_files(dir).unwrap_or_default() {
+                    let modified = metadata
+                        .modified()
+                        .ok()
+                        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+                        .unwrap_or_default();
+                    hasher.update(path.to_string_lossy().as_bytes());
+                    hasher.update(metadata.len().to_le_bytes());
+                    hasher.update(modified.as_nanos().to_le_bytes());
+                }
+                let version = self.version().unwrap_or_else(|| "local".to_string());
+                dir_name(&version, hasher.finalize())
+            }
+        }
+    }
+
+    /// Write the build into `dir`
+    pub fn extract(&self, dest: &Path) -> io::Result<()> {
+        match self {
+            Source::Bundled => extract(MERMAID_JS_BUNDLE, dest)?,
+            Source::Local { dir, .. } => {
+                for (path, _) in list_files(dir)? {
+                    let target = dest.join(&path);
+                    if let Some(parent) = target.parent() {
+                        fs::create_dir_all(parent)?;
+                    }
+                    fs::copy(dir.join(&path), target)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn dir_name(version: &str, hash: impl AsRef<[u8]>) -> String {
+    format!(
+        "{}-{}-{}",
+        MERMAID_JS_LOCAL_DIR_PREFIX,
+        version,
+        short_hex(hash.as_ref())
+    )
+}
+
+fn short_hex(hash: &[u8]) -> String {
+    hash[..4].iter().map(|b| format!("{:02x}", b)).join("")
+}
+
+fn package_json_candidates(dir: &Path) -> impl Iterator<Item = PathBuf> + '_ {
+    dir.ancestors().take(2).map(|dir| dir.join("package.json"))
+}
+
+/// String field of a JSON object, the first one found is taken:
+/// `version` of a `package.json` or `prefix` of an iconify icon pack are the top-level ones
+fn json_string_field(json: &str, field: &str) -> Option<String> {
+    let key = format!("\"{}\"", field);
+    let rest = &json[json.find(&key)? + key.len()..];
+    let rest = rest
+        .trim_start()
+        .strip_prefix(':')?
+        .trim_start()
+        .strip_prefix('"')?;
+    Some(rest[..rest.find('"')?].to_string())
+}
+
+/// Files of the directory, recursively, with paths relative to it
+fn list_files(dir: &Path) -> io::Result<Vec<(PathBuf, fs::Metadata)>> {
+    let mut files = Vec::new();
+    let mut dirs = vec![PathBuf::new()];
+    while let Some(relative) = dirs.pop() {
+        for entry in fs::read_dir(dir.join(&relative))? {
+            let entry = entry?;
+            let metadata = entry.metadata()?;
+            let path = relative.join(entry.file_name());
+            if metadata.is_dir() {
+                dirs.push(path);
+            } else {
+                files.push((path, metadata));
+            }
+        }
+    }
+    files.sort_by(|(a, _), (b, _)| a.cmp(b));
+    Ok(files)
+}
+
+/// Contents of a bundled file
+pub fn file(name: &str) -> io::Result<Option<Vec<u8>>> {
+    Ok(unpack(MERMAID_JS_BUNDLE)?.remove(name))
+}
+
+/// Write the files listed in the manifest of the archive into `dir`
+fn extract(archive: &[u8], dir: &Path) -> io::Result<()> {
+    let mut files = unpack(archive)?;
+    let manifest = files
+        .remove(MANIFEST)
+        .ok_or_else(|| invalid(format!("{} is missing from the bundle", MANIFEST)))?;
+    let manifest = String::from_utf8(manifest).map_err(|e| invalid(e.to_string()))?;
+
+    for name in manifest
+        .lines()
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+    {
+        let is_relative = Path::new(name)
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)));
+        if !is_relative {
+            return Err(invalid(format!(
+                "unexpected path {:?} in the manifest",
+                name
+            )));
+        }
+
+        let contents = files.get(name).ok_or_else(|| {
+            invalid(format!(
+                "{} is listed in the manifest, but not bundled",
+                name
+            ))
+        })?;
+        let path = dir.join(name);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, contents)?;
+    }
+    Ok(())
+}
+
+fn unpack(archive: &[u8]) -> io::Result<HashMap<String, Vec<u8>>> {
+    let mut archive = tar::Archive::new(GzDecoder::new(archive));
+    let mut files = HashMap::new();
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        let name = entry.path()?.to_string_lossy().into_owned();
+        let mut contents = Vec::with_capacity(entry.size() as usize);
+        entry.read_to_end(&mut contents)?;
+        files.insert(name, contents);
+    }
+    Ok(files)
+}
+
+fn invalid(message: String) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("corrupted mermaid bundle: {}", message),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runtime_files_only() {
+        let files = unpack(MERMAID_JS_BUNDLE).unwrap();
+        let manifest = String::from_utf8(files[MANIFEST].clone()).unwrap();
+        assert!(manifest.lines().any(|name| name == "mermaid.esm.min.mjs"));
+        assert!(manifest.lines().all(|name| files.contains_key(name)));
+        assert!(!files.keys().any(|name| name.ends_with(".d.ts")));
+    }
+
+    // loaded by the docs opened from file://, where ES modules can't be imported
+    #[test]
+    fn classic_build() {
+        let files = unpack(MERMAID_JS_BUNDLE).unwrap();
+        let manifest = String::from_utf8(files[MANIFEST].clone()).unwrap();
+        assert!(manifest.lines().any(|name| name == "mermaid.min.js"));
+        assert!(file("mermaid.min.js").unwrap().is_some());
+    }
+    #[test]
+    fn local_build() {
+        let root =
+            std::env::temp_dir().join(format!("aquamarine-local-build-{}", std::process::id()));
+        let dist = root.join("dist");
+        fs::create_dir_all(dist.join("chunks")).unwrap();
+        fs::write(
+            root.join("package.json"),
+            r#"{ "name": "mermaid", "version": "11.4.1" }"#,
+        )
+        .unwrap();
+        fs::write(dist.join("mermaid.esm.min.mjs"), "export default {}").unwrap();
+        fs::write(dist.join("chunks/flowchart.mjs"), "").unwrap();
+
+        let config = |path: &Path| Config {
+            mermaid_path: Some(path.display().to_string()),
+            ..Default::default()
+        };
+
+        let source = Source::new(&config(&dist)).unwrap();
+        assert_eq!(source.entry(), MERMAID_JS_MODULE_FILE);
+        assert_eq!(source.version().as_deref(), Some("11.4.1"));
+        assert!(source
+            .dir_name()
+            .starts_with("static.files.mermaid-11.4.1-"));
+
+        let source = Source::new(&config(&dist.join("mermaid.esm.min.mjs"))).unwrap();
+        assert_eq!(
+            source,
+            Source::Local {
+                dir: dist.clone(),
+                entry: MERMAID_JS_MODULE_FILE.into()
+            }
+        );
+        let dest = root.join("out");
+        source.extract(&dest).unwrap();
+        assert!(dest.join("chunks/flowchart.mjs").is_file());
+
+        assert!(Source::new(&config(&root)).is_err());
+        assert!(Source::new(&config(&root.join("package.json"))).is_err());
+        assert!(Source::new(&config(&root.join("missing.mjs"))).is_err());
+        fs::remove_dir_all(&root).unwrap();
+    }
+    #[test]
+    fn icon_pack() {
+        let path =
+            std::env::temp_dir().join(format!("aquamarine-icons-{}.json", std::process::id()));
+        fs::write(&path, r#"{"prefix":"logos","icons":{"rust":{"body":""}}}"#).unwrap();
+        let pack = IconPack::load(path.to_str().unwrap()).unwrap();
+        assert_eq!(pack.name, "logos");
+        assert!(pack.file_name().starts_with("logos-"));
+
+        fs::write(&path, r#"{"icons":{}}"#).unwrap();
+        assert!(IconPack::load(path.to_str().unwrap()).is_err());
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,1396 @@
+use crate::{lint, template};
+use base64::Engine;
+use itertools::Itertools;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+const MERMAID_JS_CDN_TEMPLATE: &str =
+    "https://unpkg.com/mermaid@{version}/dist/mermaid.esm.min.mjs";
+// IIFE build, the only one that can be verified with a single integrity hash
+const MERMAID_JS_CDN_CLASSIC_TEMPLATE: &str =
+    "https://unpkg.com/mermaid@{version}/dist/mermaid.min.js";
+
+/// Version of the mermaid bundled in `doc/mermaid.tar.gz`, keep in sync when updating the bundle
+pub const MERMAID_BUNDLED_VERSION: &str = "10.1.0";
+// the remote fallback renders like the local copy
+const MERMAID_DEFAULT_VERSION: &str = MERMAID_BUNDLED_VERSION;
+
+/// `integrity` value requesting the hash of the bundled mermaid build
+pub const BUNDLED_INTEGRITY: &str = "bundled";
+/// How an extension is registered in mermaid
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ExtensionKind {
+    /// `registerLayoutLoaders`, which appeared in mermaid 11
+    Layout,
+    /// `registerExternalDiagrams`
+    Diagram,
+}
+
+/// Mermaid extension enabled with the cargo feature of the same name
+///
+/// Extensions are loaded from the CDN: their builds aren't bundled
+#[derive(Debug, PartialEq)]
+pub struct Extension {
+    pub name: &'static str,
+    pub kind: ExtensionKind,
+    /// ES module exporting the extension by default
+    pub url: &'static str,
+}
+
+/// Extensions enabled with cargo features
+pub fn extensions() -> Vec<Extension> {
+    vec![
+        #[cfg(feature = "elk")]
+        Extension {
+            name: "elk",
+            kind: ExtensionKind::Layout,
+            url: "https://cdn.jsdelivr.net/npm/@mermaid-js/layout-elk@0/dist/mermaid-layout-elk.esm.min.mjs",
+        },
+        #[cfg(feature = "zenuml")]
+        Extension {
+            name: "zenuml",
+            kind: ExtensionKind::Diagram,
+            url: "https://cdn.jsdelivr.net/npm/@mermaid-js/mermaid-zenuml@0.1.0/dist/mermaid-zenuml.esm.min.mjs",
+        },
+    ]
+}
+
+/// Prefixes of the Subresource Integrity hashes, with the length of the hash in bytes
+const INTEGRITY_ALGORITHMS: &[(&str, usize)] = &[("sha256-", 32), ("sha384-", 48), ("sha512-", 64)];
+
+const MERMAID_SECURITY_LEVELS: &[&str] = &["strict", "loose", "antiscript", "sandbox"];
+
+const ENV_PREFIX: &str = "AQUAMARINE_";
+
+/// Type of value expected by an option
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Kind {
+    Str,
+    Bool,
+    List,
+    Map,
+}
+
+const KNOWN_OPTIONS: &[(&str, Kind)] = &[
+    ("theme", Kind::Str),
+    ("cdn", Kind::Str),
+    ("mermaid_version", Kind::Str),
+    ("lazy", Kind::Bool),
+    ("collapsible", Kind::Bool),
+    ("font_family", Kind::Str),
+    ("security_level", Kind::Str),
+    ("light_theme", Kind::Str),
+    ("dark_theme", Kind::Str),
+    ("ayu_theme", Kind::Str),
+    ("offline", Kind::Bool),
+    ("integrity", Kind::Str),
+    ("mermaid_path", Kind::Str),
+    ("icon_packs", Kind::Str),
+    ("validate", Kind::Bool),
+    ("allow", Kind::List),
+    ("vars", Kind::Map),
+];
+
+/// Value of a single configuration option
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Str(String),
+    Bool(bool),
+    List(Vec<String>),
+    Map(BTreeMap<String, String>),
+    /// A value of a type no option accepts, e.g. a TOML array
+    Unsupported(&'static str),
+}
+
+impl Value {
+    fn kind(&self) -> &'static str {
+        match self {
+            Value::Str(_) => "a string",
+            Value::Bool(_) => "a boolean",
+            Value::List(_) => "a list",
+            Value::Map(_) => "a map",
+            Value::Unsupported(kind) => kind,
+        }
+    }
+}
+
+/// Diagram rendering options
+///
+/// Every option is optional, unset options fall back to the aquamarine defaults
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Config {
+    /// Mermaid theme, overrides the automatic light/dark selection
+    pub theme: Option<String>,
+    /// URL of the mermaid to load when the local copy is not available,
+    /// `{version}` is replaced with the mermaid version
+    pub cdn: Option<String>,
+    /// Mermaid version to request from the default CDN
+    pub mermaid_version: Option<String>,
+    /// Render diagrams only once they are scrolled into view
+    pub lazy: Option<bool>,
+    /// Wrap diagrams into a collapsed `<details>` element
+    pub collapsible: Option<bool>,
+    /// Font used for the diagram text
+    pub font_family: Option<String>,
+    /// Mermaid `securityLevel`
+    pub security_level: Option<String>,
+    /// Mermaid theme used with the `light` rustdoc theme
+    pub light_theme: Option<String>,
+    /// Mermaid theme used with the `dark` rustdoc theme
+    pub dark_theme: Option<String>,
+    /// Mermaid theme used with the `ayu` rustdoc theme
+    pub ayu_theme: Option<String>,
+    /// Never reference the remote mermaid module, the `offline` feature by default
+    pub offline: Option<bool>,
+    /// Subresource Integrity hash of the remote mermaid, or `bundled`
+    pub integrity: Option<String>,
+    /// Mermaid build to place into the docs instead of the bundled one:
+    /// a directory or its entry `.mjs` file
+    pub mermaid_path: Option<String>,
+    /// Comma-separated iconify JSON files registered as mermaid icon packs
+    pub icon_packs: Option<String>,
+    /// Check the diagram syntax at compile time
+    pub validate: Option<bool>,
+    /// Diagram lints that aren't reported
+    pub allow: Option<Vec<String>>,
+    /// Values of the `{{name}}` placeholders of diagrams
+    pub vars: Option<BTreeMap<String, String>>,
+}
+
+impl Config {
+    /// Set an option by its name, validating the value type
+    pub fn set(&mut self, key: &str, value: Value) -> Result<(), String> {
+        match key {
+            "theme" => self.theme = Some(expect_str(key, value)?),
+            "cdn" => {
+                let cdn = expect_str(key, value)?;
+                if !is_url(&cdn) {
+                    return Err(format!(
+                        "invalid `cdn` value {:?}, expected an `https://` or `http://` URL",
+                        cdn
+                    ));
+                }
+                self.cdn = Some(cdn)
+            }
+            "mermaid_version" => {
+                let version = expect_str(key, value)?;
+                if !is_version(&version) {
+                    return Err(format!(
+                        "invalid `mermaid_version` value {:?}, expected a version, e.g. `11`, `11.4` or `11.4.1`",
+                        version
+                    ));
+                }
+                self.mermaid_version = Some(version)
+            }
+            "lazy" => self.lazy = Some(expect_bool(key, value)?),
+            "collapsible" => self.collapsible = Some(expect_bool(key, value)?),
+            "font_family" => self.font_family = Some(expect_str(key, value)?),
+            "security_level" => {
+                let level = expect_str(key, value)?;
+                if !MERMAID_SECURITY_LEVELS.contains(&level.as_str()) {
+                    return Err(format!(
+                        "invalid `security_level` value {:?}, expected one of: {}",
+                        level,
+                        quoted_list(MERMAID_SECURITY_LEVELS.iter().copied())
+                    ));
+                }
+                self.security_level = Some(level)
+            }
+            "light_theme" => self.light_theme = Some(expect_str(key, value)?),
+            "dark_theme" => self.dark_theme = Some(expect_str(key, value)?),
+            "ayu_theme" => self.ayu_theme = Some(expect_str(key, value)?),
+            "offline" => self.offline = Some(expect_bool(key, value)?),
+            "integrity" => {
+                let integrity = expect_str(key, value)?;
+                if integrity != BUNDLED_INTEGRITY && !is_integrity_hash(&integrity) {
+                    return Err(format!(
+                        "invalid `integrity` value {:?}, expected `{}` or a base64 hash prefixed with one of: {}",
+                        integrity,
+                        BUNDLED_INTEGRITY,
+                        quoted_list(INTEGRITY_ALGORITHMS.iter().map(|(prefix, _)| *prefix))
+                    ));
+                }
+                self.integrity = Some(integrity)
+            }
+            "mermaid_path" => self.mermaid_path = Some(expect_str(key, value)?),
+            "icon_packs" => self.icon_packs = Some(expect_str(key, value)?),
+            "validate" => self.validate = Some(expect_bool(key, value)?),
+            "allow" => {
+                let lints = expect_list(key, value)?;
+                if let Some(unknown) = lints
+                    .iter()
+                    .find(|lint| !lint::LINTS.contains(&lint.as_str()))
+                {
+                    return Err(format!(
+                        "unknown lint `{}`, expected one of: {}",
+                        unknown,
+                        quoted_list(lint::LINTS.iter().copied())
+                    ));
+                }
+                self.allow = Some(lints)
+            }
+            "vars" => {
+                let vars = expect_map(key, value)?;
+                if let Some(name) = vars.keys().find(|name| !template::is_name(name)) {
+                    return Err(format!(
+                        "invalid variable name `{}`: expected letters, digits and underscores",
+                        name
+                    ));
+                }
+                self.vars = Some(vars)
+            }
+            _ => {
+                return Err(format!(
+                    "unknown option `{}`, expected one of: {}",
+                    key,
+                    quoted_list(KNOWN_OPTIONS.iter().map(|(opt, _)| *opt))
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    /// Fill the options unset in `self` from `defaults`
+    pub fn or(self, defaults: Config) -> Config {
+        Config {
+            theme: self.theme.or(defaults.theme),
+            cdn: self.cdn.or(defaults.cdn),
+            mermaid_version: self.mermaid_version.or(defaults.mermaid_version),
+            lazy: self.lazy.or(defaults.lazy),
+            collapsible: self.collapsible.or(defaults.collapsible),
+            font_family: self.font_family.or(defaults.font_family),
+            security_level: self.security_level.or(defaults.security_level),
+            light_theme: self.light_theme.or(defaults.light_theme),
+            dark_theme: self.dark_theme.or(defaults.dark_theme),
+            ayu_theme: self.ayu_theme.or(defaults.ayu_theme),
+            offline: self.offline.or(defaults.offline),
+            integrity: self.integrity.or(defaults.integrity),
+            mermaid_path: self.mermaid_path.or(defaults.mermaid_path),
+            icon_packs: self.icon_packs.or(defaults.icon_packs),
+            validate: self.validate.or(defaults.validate),
+            // allowed in addition to the crate-wide ones
+            allow: match (self.allow, defaults.allow) {
+                (Some(allow), Some(defaults)) => Some(defaults.into_iter().chain(allow).collect()),
+                (allow, defaults) => allow.or(defaults),
+            },
+            // defined in addition to the crate-wide ones, overriding them
+            vars: match (self.vars, defaults.vars) {
+                (Some(vars), Some(defaults)) => Some(defaults.into_iter().chain(vars).collect()),
+                (vars, defaults) => vars.or(defaults),
+            },
+        }
+    }
+
+    /// Crate-wide defaults
+    ///
+    /// In the order of priority:
+    /// 1. `AQUAMARINE_*` environment variables
+    /// 2. `[package.metadata.aquamarine]` table of the crate manifest
+    /// 3. `[workspace.metadata.aquamarine]` table of the workspace manifest
+    pub fn crate_defaults() -> Result<Config, String> {
+        let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or(".".to_string());
+        let env = Config::from_env(std::env::vars())?;
+        let manifest = Config::from_manifests(Path::new(&manifest_dir))?;
+        Ok(env.or(manifest))
+    }
+
+    fn from_env(vars: impl Iterator<Item = (String, String)>) -> Result<Config, String> {
+        let mut config = Config::default();
+        for (name, value) in vars {
+            let key = match name.strip_prefix(ENV_PREFIX) {
+                Some(key) => key.to_ascii_lowercase(),
+                None => continue,
+            };
+            // unrelated variables may share the prefix, only known options are picked up
+            let kind = match KNOWN_OPTIONS.iter().find(|(opt, _)| *opt == key) {
+                Some((_, kind)) => *kind,
+                None => continue,
+            };
+            let value = match kind {
+                Kind::Str => Value::Str(value),
+                Kind::List => Value::List(
+                    value
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|item| !item.is_empty())
+                        .map(String::from)
+                        .collect(),
+                ),
+                Kind::Map => Value::Map(
+                    value
+                        .split(',')
+                        .filter(|entry| !entry.trim().is_empty())
+                        .map(|entry| match entry.split_once('=') {
+                            Some((name, value)) => {
+                                Ok((name.trim().to_string(), value.trim().to_string()))
+                            }
+                            None => Err(format!(
+                                "environment variable {} expects `name=value` pairs, found {:?}",
+                                name, entry
+                            )),
+                        })
+                        .collect::<Result<_, _>>()?,
+                ),
+                Kind::Bool => match value.as_str() {
+                    "1" | "true" | "yes" | "on" => Value::Bool(true),
+                    "0" | "false" | "no" | "off" | "" => Value::Bool(false),
+                    _ => {
+                        return Err(format!(
+                            "environment variable {} expects a boolean, found {:?}",
+                            name, value
+                        ))
+                    }
+                },
+            };
+            config
+                .set(&key, value)
+                .map_err(|e| format!("environment variable {}: {}", name, e))?;
+        }
+        Ok(config)
+    }
+
+    /// Environment variables the crate-wide defaults are read from
+    pub fn env_vars() -> impl Iterator<Item = String> {
+        KNOWN_OPTIONS
+            .iter()
+            .map(|(opt, _)| format!("{}{}", ENV_PREFIX, opt.to_ascii_uppercase()))
+    }
+
+    /// Manifests the crate-wide defaults are read from
+    pub fn manifests() -> Vec<PathBuf> {
+        let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or(".".to_string());
+        read_manifests(Path::new(&manifest_dir))
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(path, _)| path)
+            .collect()
+    }
+
+    fn from_manifests(manifest_dir: &Path) -> Result<Config, String> {
+        let manifests = read_manifests(manifest_dir)?;
+
+        let package = match manifests.first() {
+            Some((path, manifest)) => Config::from_metadata(manifest, "package", path)?,
+            None => return Ok(Config::default()),
+        };
+        let workspace = match manifests.last() {
+            Some((path, manifest)) if manifest.contains_key("workspace") => {
+                Config::from_metadata(manifest, "workspace", path)?
+            }
+            _ => Config::default(),
+        };
+
+        Ok(package.or(workspace))
+    }
+
+    /// Read the `[<section>.metadata.aquamarine]` table
+    fn from_metadata(manifest: &toml::Table, section: &str, path: &Path) -> Result<Config, String> {
+        let table = manifest
+            .get(section)
+            .and_then(|section| section.get("metadata"))
+            .and_then(|metadata| metadata.get("aquamarine"));
+
+        let table = match table {
+            Some(toml::Value::Table(table)) => table,
+            Some(_) => {
+                return Err(format!(
+                    "{}: `{}.metadata.aquamarine` must be a table",
+                    path.display(),
+                    section
+                ))
+            }
+            None => return Ok(Config::default()),
+        };
+
+        let mut config = Config::default();
+        for (key, value) in table {
+            let value = match value {
+                toml::Value::String(s) => Value::Str(s.clone()),
+                toml::Value::Boolean(b) => Value::Bool(*b),
+                toml::Value::Array(items) => items
+                    .iter()
+                    .map(|item| item.as_str().map(String::from))
+                    .collect::<Option<_>>()
+                    .map_or(Value::Unsupported("an array of non-strings"), Value::List),
+                toml::Value::Table(entries) => entries
+                    .iter()
+                    .map(|(name, value)| match value {
+                        toml::Value::String(s) => Some((name.clone(), s.clone())),
+                        toml::Value::Integer(i) => Some((name.clone(), i.to_string())),
+                        toml::Value::Float(f) => Some((name.clone(), f.to_string())),
+                        toml::Value::Boolean(b) => Some((name.clone(), b.to_string())),
+                        _ => None,
+                    })
+                    .collect::<Option<_>>()
+                    .map_or(Value::Unsupported("a table of non-scalars"), Value::Map),
+                other => Value::Unsupported(other.type_str()),
+            };
+            config.set(&key.replace('-', "_"), value).map_err(|e| {
+                format!(
+                    "{}: [{}.metadata.aquamarine]: {}",
+                    path.display(),
+                    section,
+                    e
+                )
+            })?;
+        }
+
+        // relative to the manifest declaring them, like the paths of cargo itself
+        if let Some(manifest_dir) = path.parent() {
+            let resolve = |path: &str| manifest_dir.join(path).display().to_string();
+            config.mermaid_path = config.mermaid_path.as_deref().map(resolve);
+            config.icon_packs = config
+                .icon_packs
+                .as_ref()
+                .map(|_| config.icon_pack_paths().map(resolve).join(","));
+        }
+        Ok(config)
+    }
+
+    /// URL of the remote mermaid
+    ///
+    /// It's an ES module, unless verified with an integrity hash:
+    /// then it's the classic build, loaded with a `<script>` element
+    pub fn cdn_url(&self) -> String {
+        let template = match (&self.cdn, &self.integrity) {
+            (Some(cdn), _) => cdn.as_str(),
+            (None, Some(_)) => MERMAID_JS_CDN_CLASSIC_TEMPLATE,
+            (None, None) => MERMAID_JS_CDN_TEMPLATE,
+        };
+        template.replace("{version}", self.version())
+    }
+
+    /// Version of the remote mermaid, the bundled one is the only choice
+    /// when verified with its integrity hash
+    fn version(&self) -> &str {
+        if self.integrity.as_deref() == Some(BUNDLED_INTEGRITY) {
+            MERMAID_BUNDLED_VERSION
+        } else {
+            self.mermaid_version
+                .as_deref()
+                .unwrap_or(MERMAID_DEFAULT_VERSION)
+        }
+    }
+
+    /// Paths listed in `icon_packs`
+    pub fn icon_pack_paths(&self) -> impl Iterator<Item = &str> {
+        self.icon_packs
+            .as_deref()
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|path| !path.is_empty())
+    }
+
+    /// Check the options that can't be validated one by one
+    pub fn validate(&self) -> Result<(), String> {
+        self.validate_extensions(&extensions())?;
+        self.validate_integrity()
+    }
+
+    fn validate_integrity(&self) -> Result<(), String> {
+        match (self.integrity.as_deref(), self.mermaid_version.as_deref()) {
+            (Some(BUNDLED_INTEGRITY), Some(version)) if version != MERMAID_BUNDLED_VERSION => {
+                Err(format!(
+                    "`integrity = \"{}\"` pins the remote mermaid to the bundled version {}, \
+                     `mermaid_version` {:?} can't be used with it",
+                    BUNDLED_INTEGRITY, MERMAID_BUNDLED_VERSION, version
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// The registration APIs of icon packs and layouts appeared in mermaid 11,
+    /// the remote extensions can't be loaded in offline mode
+    fn validate_extensions(&self, extensions: &[Extension]) -> Result<(), String> {
+        match extensions.first() {
+            Some(extension) if self.is_offline() => {
+                return Err(format!(
+                    "the `{}` feature loads the extension from the CDN, which the offline mode disables",
+                    extension.name
+                ))
+            }
+            _ => {}
+        }
+        if self.mermaid_path.is_some() {
+            return Ok(());
+        }
+        let needs_mermaid_11 = extensions
+            .iter()
+            .filter(|extension| extension.kind == ExtensionKind::Layout)
+            .map(|extension| format!("the `{}` feature", extension.name))
+            .chain(
+                self.icon_pack_paths()
+                    .next()
+                    .map(|_| "`icon_packs`".to_string()),
+            )
+            .next();
+        match needs_mermaid_11 {
+            Some(what) => Err(format!(
+                "{} needs mermaid 11, the bundled mermaid is {}: \
+                 set `mermaid_path` to a mermaid 11 build",
+                what, MERMAID_BUNDLED_VERSION
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// URL of the remote mermaid module, unless in offline mode
+    pub fn remote_url(&self) -> Option<String> {
+        if self.is_offline() {
+            None
+        } else {
+            Some(self.cdn_url())
+        }
+    }
+
+    pub fn is_offline(&self) -> bool {
+        self.offline.unwrap_or(cfg!(feature = "offline"))
+    }
+
+    pub fn is_lazy(&self) -> bool {
+        self.lazy.unwrap_or(false)
+    }
+
+    pub fn is_collapsible(&self) -> bool {
+        self.collapsible.unwrap_or(false)
+    }
+
+    pub fn is_validating(&self) -> bool {
+        self.validate.unwrap_or(true)
+    }
+
+    /// Mapping of the rustdoc themes to the mermaid themes, as a JSON object
+    pub fn theme_map_json(&self) -> String {
+        let themes = [
+            ("light", &self.light_theme, "default"),
+            ("dark", &self.dark_theme, "dark"),
+            ("ayu", &self.ayu_theme, "dark"),
+        ];
+
+        let entries = themes
+            .iter()
+            .map(|(rustdoc, mermaid, default)| {
+                let mermaid = mermaid.as_deref().unwrap_or(default);
+                format!("{}:{}", json_str(rustdoc), json_str(mermaid))
+            })
+            .join(",");
+
+        format!("{{{}}}", entries)
+    }
+
+    /// Per-diagram options passed to `mermaid.initialize`, as a JSON object
+    pub fn mermaid_config_json(&self) -> Option<String> {
+        let options = [
+            ("theme", &self.theme),
+            ("fontFamily", &self.font_family),
+            ("securityLevel", &self.security_level),
+        ];
+
+        let entries = options
+            .iter()
+            .filter_map(|(key, value)| value.as_ref().map(|value| (key, value)))
+            .map(|(key, value)| format!("{}:{}", json_str(key), json_str(value)))
+            .collect::<Vec<_>>();
+
+        if entries.is_empty() {
+            None
+        } else {
+            Some(format!("{{{}}}", entries.join(",")))
+        }
+    }
+}
+
+const DIAGRAM_ALIGNMENTS: &[&str] = &["left", "center", "right"];
+
+const KNOWN_DIAGRAM_OPTIONS: &[&str] = &[
+    "title",
+    "caption",
+    "id",
+    "theme",
+    "width",
+    "align",
+    "highlight",
+    "define",
+];
+
+/// Per-diagram options, set in the info string of the code block
+///
+/// ````text
+/// ```mermaid title="Request lifecycle" id=lifecycle width=80% align=center
+/// ````
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DiagramOptions {
+    /// Diagram title, rendered by mermaid
+    pub title: Option<String>,
+    /// Caption below the diagram
+    pub caption: Option<String>,
+    /// Anchor id of the diagram
+    pub id: Option<String>,
+    /// Mermaid theme of this diagram only
+    pub theme: Option<String>,
+    /// CSS width of the diagram, e.g. `80%` or `400px`
+    pub width: Option<String>,
+    /// Horizontal alignment: `left`, `center` or `right`
+    pub align: Option<String>,
+    /// Flowchart nodes to emphasise, dimming the rest
+    pub highlight: Vec<String>,
+    /// Name the diagram is reused by with `use_mmd!(name)`
+    pub define: Option<String>,
+}
+
+impl DiagramOptions {
+    /// Parse the attributes following the `mermaid` language tag in the info string
+    pub fn parse(info: &str) -> Result<DiagramOptions, String> {
+        let mut options = DiagramOptions::default();
+        for (key, value) in split_info_string(info)? {
+            let value =
+                value.ok_or_else(|| format!("diagram attribute `{}` expects a value", key))?;
+            options.set(key, value)?;
+        }
+        Ok(options)
+    }
+
+    fn set(&mut self, key: &str, value: String) -> Result<(), String> {
+        match key {
+            "title" => self.title = Some(value),
+            "caption" => self.caption = Some(value),
+            "id" => {
+                if value.is_empty() || value.contains(char::is_whitespace) {
+                    return Err(format!(
+                        "invalid diagram `id` {:?}: must be a single word",
+                        value
+                    ));
+                }
+                self.id = Some(value)
+            }
+            "theme" => self.theme = Some(value),
+            "width" => {
+                if !is_css_length(&value) {
+                    return Err(format!(
+                        "invalid diagram `width` {:?}: expected a number with an optional unit, e.g. `80%` or `400px`",
+                        value
+                    ));
+                }
+                self.width = Some(value)
+            }
+            "align" => {
+                if !DIAGRAM_ALIGNMENTS.contains(&value.as_str()) {
+                    return Err(format!(
+                        "invalid diagram `align` {:?}, expected one of: {}",
+                        value,
+                        quoted_list(DIAGRAM_ALIGNMENTS.iter().copied())
+                    ));
+                }
+                self.align = Some(value)
+            }
+            "highlight" => {
+                self.highlight = value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|node| !node.is_empty())
+                    .map(str::to_string)
+                    .collect();
+                if self.highlight.is_empty() {
+                    return Err("diagram `highlight` expects a list of nodes, e.g. `highlight=\"Parser,Lexer\"`".to_string());
+                }
+            }
+            "define" => {
+                if !template::is_name(&value) {
+                    return Err(format!(
+                        "invalid diagram `define` {:?}: expected letters, digits and underscores",
+                        value
+                    ));
+                }
+                self.define = Some(value)
+            }
+            _ => {
+                return Err(format!(
+                    "unknown diagram attribute `{}`, expected one of: {}",
+                    key,
+                    quoted_list(KNOWN_DIAGRAM_OPTIONS.iter().copied())
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether the diagram needs to be wrapped into a `<figure>`
+    pub fn is_figure(&self) -> bool {
+        self.caption.is_some() || self.id.is_some() || self.width.is_some() || self.align.is_some()
+    }
+
+    /// Apply the per-diagram overrides on top of the item configuration
+    pub fn apply(&self, config: &Config) -> Config {
+        Config {
+            theme: self.theme.clone(),
+            ..Default::default()
+        }
+        .or(config.clone())
+    }
+}
+
+/// Split an info string into `key=value`, `key="quoted value"` and `flag` entries
+fn split_info_string(info: &str) -> Result<Vec<(&str, Option<String>)>, String> {
+    let mut entries = Vec::new();
+    let mut rest = info.trim_start();
+
+    while !rest.is_empty() {
+        let key_len = rest
+            .find(|c: char| c == '=' || c.is_whitespace())
+            .unwrap_or(rest.len());
+        let (key, tail) = rest.split_at(key_len);
+
+        let (value, tail) = match tail.strip_prefix('=') {
+            Some(tail) => match tail.chars().next() {
+                Some(quote @ '"') | Some(quote @ '\'') => {
+                    let end = tail[1..]
+                        .find(quote)
+                        .ok_or_else(|| format!("unterminated quoted value of `{}`", key))?;
+                    (Some(tail[1..end + 1].to_string()), &tail[end + 2..])
+                }
+                _ => {
+                    let end = tail.find(char::is_whitespace).unwrap_or(tail.len());
+                    (Some(tail[..end].to_string()), &tail[end..])
+                }
+            },
+            None => (None, tail),
+        };
+
+        entries.push((key, value));
+        rest = tail.trim_start();
+    }
+
+    Ok(entries)
+}
+
+fn is_css_length(value: &str) -> bool {
+    const UNITS: &[&str] = &["px", "%", "em", "rem", "vw", "ch"];
+
+    let number = UNITS
+        .iter()
+        .find_map(|unit| value.strip_suffix(unit))
+        .unwrap_or(value);
+
+    !number.is_empty()
+        && number.chars().all(|c| c.is_ascii_digit() || c == '.')
+        && number.chars().filter(|c| *c == '.').count() <= 1
+}
+
+/// Absolute URL without the characters that can't appear in one unencoded
+fn is_url(value: &str) -> bool {
+    (value.starts_with("https://") || value.starts_with("http://"))
+        && !value
+            .chars()
+            .any(|c| c.is_whitespace() || c.is_control() || "\"'<>\\`".contains(c))
+}
+
+/// `sha384-<base64 hash>`, or a hash of the other supported algorithms
+fn is_integrity_hash(value: &str) -> bool {
+    INTEGRITY_ALGORITHMS.iter().any(|(prefix, len)| {
+        value
+            .strip_prefix(prefix)
+            .and_then(|hash| base64::engine::general_purpose::STANDARD.decode(hash).ok())
+            .is_some_and(|hash| hash.len() == *len)
+    })
+}
+
+/// Version of an npm package: `11`, `11.4`, `11.4.1`, `11.0.0-rc.1`
+fn is_version(value: &str) -> bool {
+    let (release, pre) = match value.split_once(['-', '+']) {
+        Some((_, "")) => return false,
+        Some((release, pre)) => (release, pre),
+        None => (value, ""),
+    };
+    let numbers = release.split('.').collect::<Vec<_>>();
+    numbers.len() <= 3
+        && numbers
+            .iter()
+            .all(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+        && pre
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-' || c == '+')
+}
+
+fn expect_str(key: &str, value: Value) -> Result<String, String> {
+    match value {
+        Value::Str(s) => Ok(s),
+        other => Err(format!(
+            "option `{}` expects a string, found {}",
+            key,
+            other.kind()
+        )),
+    }
+}
+
+fn expect_bool(key: &str, value: Value) -> Result<bool, String> {
+    match value {
+        Value::Bool(b) => Ok(b),
+        other => Err(format!(
+            "option `{}` expects a boolean, found {}",
+            key,
+            other.kind()
+        )),
+    }
+}
+
+fn expect_list(key: &str, value: Value) -> Result<Vec<String>, String> {
+    match value {
+        Value::List(items) => Ok(items),
+        other => Err(format!(
+            "option `{}` expects a list, found {}",
+            key,
+            other.kind()
+        )),
+    }
+}
+
+fn expect_map(key: &str, value: Value) -> Result<BTreeMap<String, String>, String> {
+    match value {
+        Value::Map(map) => Ok(map),
+        other => Err(format!(
+            "option `{}` expects a map, found {}",
+            key,
+            other.kind()
+        )),
+    }
+}
+
+/// The crate manifest, followed by the workspace manifest unless it's the same file
+fn read_manifests(manifest_dir: &Path) -> Result<Vec<(PathBuf, toml::Table)>, String> {
+    let manifest_path = manifest_dir.join("Cargo.toml");
+    let manifest = match read_toml(&manifest_path)? {
+        Some(manifest) => manifest,
+        None => return Ok(Vec::new()),
+    };
+
+    let is_workspace = manifest.contains_key("workspace");
+    let mut manifests = vec![(manifest_path, manifest)];
+    if !is_workspace {
+        for dir in manifest_dir.ancestors().skip(1) {
+            let path = dir.join("Cargo.toml");
+            match read_toml(&path)? {
+                Some(manifest) if manifest.contains_key("workspace") => {
+                    manifests.push((path, manifest));
+                    break;
+                }
+                _ => continue,
+            }
+        }
+    }
+    Ok(manifests)
+}
+
+/// Parsed TOML file, `None` if it can't be read
+pub fn read_toml(path: &Path) -> Result<Option<toml::Table>, String> {
+    match std::fs::read_to_string(path) {
+        Ok(data) => data
+            .parse()
+            .map(Some)
+            .map_err(|e| format!("failed to parse {}: {}", path.display(), e)),
+        Err(_) => Ok(None),
+    }
+}
+
+fn quoted_list<'a>(items: impl Iterator<Item = &'a str>) -> String {
+    items.map(|item| format!("`{}`", item)).join(", ")
+}
+
+pub fn json_str(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            // a `</script>` in a string would end the script it's embedded into
+            '<' => out.push_str("\\u003c"),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Escape a string to be used as an HTML attribute value
+pub fn html_attr_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_validates_types() {
+        let mut config = Config::default();
+        assert!(config.set("theme", Value::Str("forest".into())).is_ok());
+        assert!(config.set("lazy", Value::Bool(true)).is_ok());
+        assert!(config.set("theme", Value::Bool(true)).is_err());
+        assert!(config.set("collapsible", Value::Str("yes".into())).is_err());
+        assert!(config.set("colour", Value::Str("red".into())).is_err());
+
+        assert_eq!(config.theme.as_deref(), Some("forest"));
+        assert!(config.is_lazy());
+        assert!(!config.is_collapsible());
+    }
+
+    #[test]
+    fn cdn_url() {
+        let mut config = Config::default();
+        assert_eq!(
+            config.cdn_url(),
+            "https://unpkg.com/mermaid@10.1.0/dist/mermaid.esm.min.mjs"
+        );
+
+        config.mermaid_version = Some("11.4.1".into());
+        assert_eq!(
+            config.cdn_url(),
+            "https://unpkg.com/mermaid@11.4.1/dist/mermaid.esm.min.mjs"
+        );
+
+        config.cdn = Some("https://mirror.local/mermaid.mjs".into());
+        assert_eq!(config.cdn_url(), "https://mirror.local/mermaid.mjs");
+
+        config.offline = Some(false);
+        assert_eq!(config.remote_url(), Some(config.cdn_url()));
+
+        config.offline = Some(true);
+        assert_eq!(config.remote_url(), None);
+
+        let config = Config {
+            cdn: Some("https://mirror.local/mermaid@{version}/mermaid.min.js".into()),
+            mermaid_version: Some("11.4.1".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            config.cdn_url(),
+            "https://mirror.local/mermaid@11.4.1/mermaid.min.js"
+        );
+    }
+
+    #[test]
+    fn cdn_and_version_are_validated() {
+        let mut config = Config::default();
+        let mut set = |key, value: &str| config.set(key, Value::Str(value.into()));
+        assert!(set("cdn", "https://mirror.local/mermaid@{version}/mermaid.mjs").is_ok());
+        assert!(set("cdn", r#"https://x/"m.mjs"#).is_err());
+        assert!(set("cdn", "https://x/m.mjs</script>").is_err());
+        assert!(set("cdn", "javascript:alert(1)").is_err());
+
+        for version in ["11", "11.4", "11.4.1", "11.0.0-rc.1", "10.9.1+build.3"].iter() {
+            assert!(set("mermaid_version", version).is_ok(), "{}", version);
+        }
+        for version in ["", "latest", "11.", "1.2.3.4", "11\"; alert(1)", "11-"].iter() {
+            assert!(set("mermaid_version", version).is_err(), "{}", version);
+        }
+    }
+
+    #[test]
+    fn integrity() {
+        let mut config = Config::default();
+        assert!(config
+            .set("integrity", Value::Str("md5-abcd".into()))
+            .is_err());
+        for integrity in [
+            "sha384-abcd",
+            "sha384-not base64",
+            // a sha256 hash
+            "sha384-47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU=",
+            r#"sha384-"; alert(1); ""#,
+        ]
+        .iter()
+        {
+            assert!(config
+                .set("integrity", Value::Str(integrity.to_string()))
+                .is_err());
+        }
+        assert!(config
+            .set(
+                "integrity",
+                Value::Str("sha256-47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU=".into())
+            )
+            .is_ok());
+        assert!(config
+            .set(
+                "integrity",
+                Value::Str(
+                    "sha384-OLBgp1GsljhM2TJ+sbHjaiH9txEUvgdDTAzHv2P24donTt6/529l+9Ua0vFImLlb"
+                        .into()
+                )
+            )
+            .is_ok());
+        assert_eq!(
+            config.cdn_url(),
+            "https://unpkg.com/mermaid@10.1.0/dist/mermaid.min.js"
+        );
+
+        config.integrity = Some(BUNDLED_INTEGRITY.into());
+        assert_eq!(
+            config.cdn_url(),
+            format!(
+                "https://unpkg.com/mermaid@{}/dist/mermaid.min.js",
+                MERMAID_BUNDLED_VERSION
+            )
+        );
+        assert!(config.validate_integrity().is_ok());
+
+        config.mermaid_version = Some("11.4.1".into());
+        assert!(config.validate_integrity().is_err());
+    }
+
+    #[test]
+    fn extensions_need_mermaid_11() {
+        let elk = Extension {
+            name: "elk",
+            kind: ExtensionKind::Layout,
+            url: "https://cdn.local/elk.mjs",
+        };
+        let zenuml = Extension {
+            name: "zenuml",
+            kind: ExtensionKind::Diagram,
+            url: "https://cdn.local/zenuml.mjs",
+        };
+        let mut config = Config {
+            offline: Some(false),
+            ..Config::default()
+        };
+        assert!(config.validate_extensions(&[]).is_ok());
+        // external diagrams are supported by the bundled mermaid
+        assert!(config.validate_extensions(&[zenuml]).is_ok());
+        assert_eq!(
+            config.validate_extensions(&[elk]),
+            Err(format!(
+                "the `elk` feature needs mermaid 11, the bundled mermaid is {}: \
+                 set `mermaid_path` to a mermaid 11 build",
+                MERMAID_BUNDLED_VERSION
+            ))
+        );
+
+        config.icon_packs = Some("icons/logos.json".into());
+        assert!(config
+            .validate_extensions(&[])
+            .unwrap_err()
+            .starts_with("`icon_packs` needs mermaid 11"));
+        config.mermaid_path = Some("node_modules/mermaid/dist".into());
+        assert!(config.validate_extensions(&[]).is_ok());
+
+        config.offline = Some(true);
+        assert!(config.validate_extensions(&[]).is_ok());
+        assert_eq!(
+            config.validate_extensions(&[Extension {
+                name: "zenuml",
+                kind: ExtensionKind::Diagram,
+                url: "https://cdn.local/zenuml.mjs",
+            }]),
+            Err("the `zenuml` feature loads the extension from the CDN, which the offline mode disables".into())
+        );
+    }
+
+    #[test]
+    fn security_level_is_validated() {
+        let mut config = Config::default();
+        assert!(config
+            .set("security_level", Value::Str("loose".into()))
+            .is_ok());
+        assert!(config
+            .set("security_level", Value::Str("none".into()))
+            .is_err());
+        assert_eq!(config.security_level.as_deref(), Some("loose"));
+    }
+
+    #[test]
+    fn layering() {
+        let attrs = Config {
+            theme: Some("forest".into()),
+            ..Default::default()
+        };
+        let defaults = Config {
+            theme: Some("dark".into()),
+            font_family: Some("monospace".into()),
+            ..Default::default()
+        };
+        let config = attrs.or(defaults);
+        assert_eq!(config.theme.as_deref(), Some("forest"));
+        assert_eq!(config.font_family.as_deref(), Some("monospace"));
+    }
+
+    #[test]
+    fn allowed_lints_add_up() {
+        let attrs = Config {
+            allow: Some(vec!["unquoted_label".into()]),
+            ..Default::default()
+        };
+        let defaults = Config {
+            allow: Some(vec!["undeclared_node".into()]),
+            ..Default::default()
+        };
+        assert_eq!(
+            attrs.or(defaults).allow,
+            Some(vec!["undeclared_node".into(), "unquoted_label".into()])
+        );
+    }
+
+    #[test]
+    fn vars_are_overridden() {
+        let vars = |entries: &[(&str, &str)]| {
+            Some(
+                entries
+                    .iter()
+                    .map(|(name, value)| (name.to_string(), value.to_string()))
+                    .collect(),
+            )
+        };
+        let attrs = Config {
+            vars: vars(&[("service", "auth")]),
+            ..Default::default()
+        };
+        let defaults = Config {
+            vars: vars(&[("service", "gateway"), ("region", "eu")]),
+            ..Default::default()
+        };
+        assert_eq!(
+            attrs.or(defaults).vars,
+            vars(&[("region", "eu"), ("service", "auth")])
+        );
+    }
+
+    #[test]
+    fn from_env() {
+        let vars = vec![
+            ("AQUAMARINE_THEME", "neutral"),
+            ("AQUAMARINE_LAZY", "1"),
+            ("AQUAMARINE_ALLOW", "reserved_end, duplicate_node"),
+            ("AQUAMARINE_VARS", "region=eu, tier = 2"),
+            ("AQUAMARINE_SOMETHING_ELSE", "ignored"),
+            ("PATH", "/usr/bin"),
+        ];
+        let config = Config::from_env(
+            vars.into_iter()
+                .map(|(name, value)| (name.to_string(), value.to_string())),
+        )
+        .unwrap();
+        assert_eq!(config.theme.as_deref(), Some("neutral"));
+        assert_eq!(config.lazy, Some(true));
+        assert_eq!(
+            config.allow,
+            Some(vec!["reserved_end".into(), "duplicate_node".into()])
+        );
+        let vars = config.vars.unwrap();
+        assert_eq!(
+            (vars["region"].as_str(), vars["tier"].as_str()),
+            ("eu", "2")
+        );
+
+        let vars = vec![("AQUAMARINE_COLLAPSIBLE".to_string(), "maybe".to_string())];
+        assert!(Config::from_env(vars.into_iter()).is_err());
+        let vars = vec![("AQUAMARINE_VARS".to_string(), "region".to_string())];
+        assert!(Config::from_env(vars.into_iter()).is_err());
+    }
+
+    #[test]
+    fn tracked_env_vars() {
+        let names = Config::env_vars().collect::<Vec<_>>();
+        assert_eq!(names.len(), KNOWN_OPTIONS.len());
+        assert!(names.contains(&"AQUAMARINE_THEME".to_string()));
+        assert!(names.contains(&"AQUAMARINE_OFFLINE".to_string()));
+    }
+
+    #[test]
+    fn workspace_manifest() {
+        let root =
+            std::env::temp_dir().join(format!("aquamarine-manifests-{}", std::process::id()));
+        let member = root.join("crates/member");
+        std::fs::create_dir_all(&member).unwrap();
+        std::fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\n[workspace.metadata.aquamarine]\ntheme = \"forest\"\nlazy = true\n",
+        )
+        .unwrap();
+        std::fs::write(
+            member.join("Cargo.toml"),
+            "[package]\n[package.metadata.aquamarine]\ntheme = \"dark\"\n",
+        )
+        .unwrap();
+
+        let paths = read_manifests(&member)
+            .unwrap()
+            .into_iter()
+            .map(|(path, _)| path)
+            .collect::<Vec<_>>();
+        assert_eq!(paths, [member.join("Cargo.toml"), root.join("Cargo.toml")]);
+        let config = Config::from_manifests(&member).unwrap();
+        assert_eq!(config.theme.as_deref(), Some("dark"));
+        assert_eq!(config.lazy, Some(true));
+
+        assert_eq!(read_manifests(&root).unwrap().len(), 1);
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn from_metadata() {
+        let manifest: toml::Table = r#"
+            [package.metadata.aquamarine]
+            theme = "forest"
+            font-family = "Fira Sans"
+            security_level = "strict"
+            collapsible = true
+            allow = ["unbalanced_activation"]
+
+            [package.metadata.aquamarine.vars]
+            service = "auth"
+            replicas = 3
+        "#
+        .parse()
+        .unwrap();
+        let config = Config::from_metadata(&manifest, "package", Path::new("Cargo.toml")).unwrap();
+        assert_eq!(config.theme.as_deref(), Some("forest"));
+        assert_eq!(config.font_family.as_deref(), Some("Fira Sans"));
+        assert_eq!(config.security_level.as_deref(), Some("strict"));
+        assert_eq!(config.collapsible, Some(true));
+        assert_eq!(config.allow, Some(vec!["unbalanced_activation".into()]));
+        let vars = config.vars.unwrap();
+        assert_eq!(
+            (vars["service"].as_str(), vars["replicas"].as_str()),
+            ("auth", "3")
+        );
+
+        let config =
+            Config::from_metadata(&manifest, "workspace", Path::new("Cargo.toml")).unwrap();
+        assert_eq!(config, Config::default());
+
+        let manifest: toml::Table = r#"
+            [workspace.metadata.aquamarine]
+            lazy = ["yes"]
+        "#
+        .parse()
+        .unwrap();
+        assert!(Config::from_metadata(&manifest, "workspace", Path::new("Cargo.toml")).is_err());
+
+        let manifest: toml::Table = r#"
+            [workspace.metadata.aquamarine]
+            allow = ["no_such_lint"]
+        "#
+        .parse()
+        .unwrap();
+        assert!(Config::from_metadata(&manifest, "workspace", Path::new("Cargo.toml")).is_err());
+
+        let manifest: toml::Table = r#"
+            [workspace.metadata.aquamarine.vars]
+            "not a name" = "x"
+            nested = { a = "b" }
+        "#
+        .parse()
+        .unwrap();
+        assert!(Config::from_metadata(&manifest, "workspace", Path::new("Cargo.toml")).is_err());
+
+        let manifest: toml::Table = r#"
+            [workspace.metadata.aquamarine]
+            mermaid-path = "vendor/mermaid"
+        "#
+        .parse()
+        .unwrap();
+        let config =
+            Config::from_metadata(&manifest, "workspace", Path::new("/ws/Cargo.toml")).unwrap();
+        assert_eq!(config.mermaid_path.as_deref(), Some("/ws/vendor/mermaid"));
+
+        let manifest: toml::Table = r#"
+            [package.metadata.aquamarine]
+            icon-packs = "icons/logos.json, /abs/mdi.json"
+        "#
+        .parse()
+        .unwrap();
+        let config =
+            Config::from_metadata(&manifest, "package", Path::new("/ws/member/Cargo.toml"))
+                .unwrap();
+        assert_eq!(
+            config.icon_pack_paths().collect::<Vec<_>>(),
+            ["/ws/member/icons/logos.json", "/abs/mdi.json"]
+        );
+    }
+
+    #[test]
+    fn diagram_options() {
+        let options = DiagramOptions::parse(
+            r#"title="Request lifecycle" id=lifecycle theme=dark width=80% align=center caption='A "quoted" caption'"#,
+        )
+        .unwrap();
+        assert_eq!(
+            options,
+            DiagramOptions {
+                title: Some("Request lifecycle".into()),
+                caption: Some("A \"quoted\" caption".into()),
+                id: Some("lifecycle".into()),
+                theme: Some("dark".into()),
+                width: Some("80%".into()),
+                align: Some("center".into()),
+                ..Default::default()
+            }
+        );
+        assert!(options.is_figure());
+
+        assert_eq!(
+            DiagramOptions::parse(r#"highlight="Parser, Lexer""#)
+                .unwrap()
+                .highlight,
+            ["Parser", "Lexer"]
+        );
+        assert!(DiagramOptions::parse("highlight=,").is_err());
+        assert_eq!(
+            DiagramOptions::parse("define=pipeline").unwrap().define,
+            Some("pipeline".into())
+        );
+        assert!(DiagramOptions::parse("define=data-flow").is_err());
+
+        assert_eq!(
+            DiagramOptions::parse("").unwrap(),
+            DiagramOptions::default()
+        );
+        assert!(DiagramOptions::parse("colour=red").is_err());
+        assert!(DiagramOptions::parse("title").is_err());
+        assert!(DiagramOptions::parse(r#"title="unterminated"#).is_err());
+        assert!(DiagramOptions::parse("width=100;color:red").is_err());
+        assert!(DiagramOptions::parse("align=top").is_err());
+    }
+
+    #[test]
+    fn theme_map_json() {
+        let mut config = Config::default();
+        assert_eq!(
+            config.theme_map_json(),
+            r#"{"light":"default","dark":"dark","ayu":"dark"}"#
+        );
+
+        config.ayu_theme = Some("forest".into());
+        assert_eq!(
+            config.theme_map_json(),
+            r#"{"light":"default","dark":"dark","ayu":"forest"}"#
+        );
+    }
+
+    #[test]
+    fn mermaid_config_json() {
+        let mut config = Config::default();
+        assert_eq!(config.mermaid_config_json(), None);
+
+        config.theme = Some("for\"est".into());
+        assert_eq!(
+            config.mermaid_config_json().as_deref(),
+            Some(r#"{"theme":"for\"est"}"#)
+        );
+
+        config.security_level = Some("loose".into());
+        assert_eq!(
+            config.mermaid_config_json().as_deref(),
+            Some(r#"{"theme":"for\"est","securityLevel":"loose"}"#)
+        );
+    }
+}
@@ -0,0 +1,323 @@
+use crate::config::read_toml;
+use std::collections::HashMap;
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+
+/// Explicit location of the rustdoc output, takes priority over any detection
+pub const DOC_DIR_ENV: &str = "AQUAMARINE_DOC_DIR";
+
+/// Where the rustdoc output is
+#[derive(Debug, PartialEq)]
+pub enum DocsDir {
+    /// Docs are being rendered into the directory by this process, or it's given explicitly
+    Rendering(PathBuf),
+    /// Docs rendered by another process, found in one of the known locations
+    Existing(PathBuf),
+    /// None of the candidate locations exists
+    Missing(Vec<PathBuf>),
+}
+
+/// What the location is derived from: the command line of the compiler running the macro,
+/// the environment set by cargo and cargo configuration files
+pub struct Context {
+    pub args: Vec<String>,
+    pub vars: HashMap<String, String>,
+    pub manifest_dir: PathBuf,
+    pub cargo_home: Option<PathBuf>,
+}
+
+impl Context {
+    pub fn current() -> Context {
+        let vars: HashMap<String, String> = std::env::vars().collect();
+        let manifest_dir =
+            PathBuf::from(vars.get("CARGO_MANIFEST_DIR").map_or(".", String::as_str));
+        let cargo_home = vars
+            .get("CARGO_HOME")
+            .map(PathBuf::from)
+            .or_else(|| vars.get("HOME").map(|home| Path::new(home).join(".cargo")));
+        Context {
+            args: std::env::args().collect(),
+            vars,
+            manifest_dir,
+            cargo_home,
+        }
+    }
+
+    pub fn resolve(&self) -> DocsDir {
+        if let Some(dir) = self.vars.get(DOC_DIR_ENV).filter(|dir| !dir.is_empty()) {
+            return DocsDir::Rendering(PathBuf::from(dir));
+        }
+
+        // proc-macros run inside of the compiler, so its arguments are the most reliable source:
+        // cargo passes the docs location to rustdoc, `--target-dir` and `--target` included
+        if self.is_rustdoc() {
+            let out_dir = self.arg("-o").or_else(|| self.arg("--out-dir"));
+            return DocsDir::Rendering(PathBuf::from(out_dir.unwrap_or("doc")));
+        }
+
+        let candidates = self.candidates();
+        match candidates.iter().find(|dir| dir.is_dir()) {
+            Some(dir) => DocsDir::Existing(dir.clone()),
+            None => DocsDir::Missing(candidates),
+        }
+    }
+
+    /// Docs are being rendered, as opposed to builds, checks and the dependencies of `cargo doc`
+    pub fn is_rustdoc(&self) -> bool {
+        self.args
+            .first()
+            .and_then(|program| Path::new(program).file_stem())
+            .and_then(OsStr::to_str)
+            .is_some_and(|name| name.starts_with("rustdoc"))
+    }
+
+    /// Lint warnings are printed while rendering the docs of the crate itself:
+    /// builds and checks of the same crate would repeat them, dependencies cap their lints
+    pub fn reports_lints(&self) -> bool {
+        self.is_rustdoc() && self.arg("--cap-lints") != Some("allow")
+    }
+
+    /// Value of a `--flag value`, `--flag=value` or `-fvalue` argument
+    fn arg(&self, flag: &str) -> Option<&str> {
+        let mut args = self.args.iter().skip(1);
+        while let Some(arg) = args.next() {
+            if arg == flag {
+                return args.next().map(String::as_str);
+            }
+            let value = if flag.starts_with("--") {
+                arg.strip_prefix(flag)
+                    .and_then(|rest| rest.strip_prefix('='))
+            } else {
+                arg.strip_prefix(flag)
+            };
+            if value.is_some() {
+                return value;
+            }
+        }
+        None
+    }
+
+    fn candidates(&self) -> Vec<PathBuf> {
+        let mut candidates = Vec::new();
+
+        // rustc writes into `<target-dir>[/<triple>]/<profile>/deps`,
+        // rustdoc into `<target-dir>[/<triple>]/doc`
+        if let Some(out_dir) = self.arg("--out-dir").map(Path::new) {
+            if out_dir.ends_with("deps") {
+                if let Some(base) = out_dir.parent().and_then(Path::parent) {
+                    candidates.push(base.join("doc"));
+                }
+            }
+        }
+
+        let config = self.cargo_config();
+        let triple = self
+            .arg("--target")
+            .or_else(|| self.vars.get("CARGO_BUILD_TARGET").map(String::as_str))
+            .or(config.target.as_deref())
+            .map(target_name);
+
+        let target_dirs = [
+            self.vars.get("CARGO_TARGET_DIR").map(PathBuf::from),
+            self.vars.get("CARGO_BUILD_TARGET_DIR").map(PathBuf::from),
+            config.target_dir,
+            Some(self.workspace_root().join("target")),
+        ];
+        for target_dir in target_dirs.iter().flatten() {
+            if let Some(triple) = triple {
+                candidates.push(target_dir.join(triple).join("doc"));
+            }
+            candidates.push(target_dir.join("doc"));
+        }
+
+        let mut unique = Vec::with_capacity(candidates.len());
+        for candidate in candidates {
+            if !unique.contains(&candidate) {
+                unique.push(candidate);
+            }
+        }
+        unique
+    }
+
+    /// `build.target-dir` and `build.target` of the nearest cargo configuration defining them
+    fn cargo_config(&self) -> CargoConfig {
+        let dirs = self
+            .manifest_dir
+            .ancestors()
+            .map(|dir| dir.join(".cargo"))
+            .chain(self.cargo_home.clone());
+
+        let mut config = CargoConfig::default();
+        for dir in dirs {
+            for file in ["config.toml", "config"].iter() {
+                let build = match read_toml(&dir.join(file)) {
+                    Ok(Some(table)) => match table.get("build") {
+                        Some(toml::Value::Table(build)) => build.clone(),
+                        _ => continue,
+                    },
+                    _ => continue,
+                };
+                // relative paths are relative to the parent of the `.cargo` directory
+                let root = dir.parent().unwrap_or(&dir);
+                if config.target_dir.is_none() {
+                    config.target_dir = build
+                        .get("target-dir")
+                        .and_then(toml::Value::as_str)
+                        .map(|target_dir| root.join(target_dir));
+                }
+                if config.target.is_none() {
+                    config.target = build
+                        .get("target")
+                        .and_then(toml::Value::as_str)
+                        .map(String::from);
+                }
+            }
+        }
+        config
+    }
+
+    fn workspace_root(&self) -> &Path {
+        self.manifest_dir
+            .ancestors()
+            .find(|dir| {
+                matches!(
+                    read_toml(&dir.join("Cargo.toml")),
+                    Ok(Some(manifest)) if manifest.contains_key("workspace")
+                )
+            })
+            .unwrap_or(&self.manifest_dir)
+    }
+}
+
+#[derive(Default)]
+struct CargoConfig {
+    target_dir: Option<PathBuf>,
+    target: Option<String>,
+}
+
+/// Custom targets are given as a path to the target spec, its output is named after the file
+fn target_name(target: &str) -> &str {
+    match target.strip_suffix(".json") {
+        Some(spec) => Path::new(spec)
+            .file_name()
+            .and_then(OsStr::to_str)
+            .unwrap_or(spec),
+        None => target,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn context(root: &Path, args: &[&str], vars: &[(&str, &str)]) -> Context {
+        Context {
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+            vars: vars
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+            manifest_dir: root.join("member"),
+            cargo_home: None,
+        }
+    }
+
+    fn workspace(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!(
+            "aquamarine-docs-dir-{}-{}",
+            name,
+            std::process::id()
+        ));
+        fs::create_dir_all(root.join("member")).unwrap();
+        fs::write(root.join("Cargo.toml"), "[workspace]\n").unwrap();
+        fs::write(root.join("member/Cargo.toml"), "[package]\n").unwrap();
+        root
+    }
+
+    #[test]
+    fn rustdoc_output() {
+        let root = workspace("rustdoc");
+        let ctx = context(
+            &root,
+            &[
+                "/toolchain/bin/rustdoc",
+                "src/lib.rs",
+                "-o",
+                "/custom/x86_64-unknown-linux-gnu/doc",
+            ],
+            &[],
+        );
+        assert!(ctx.is_rustdoc());
+        assert!(ctx.reports_lints());
+        assert_eq!(
+            ctx.resolve(),
+            DocsDir::Rendering("/custom/x86_64-unknown-linux-gnu/doc".into())
+        );
+
+        let dependency = context(&root, &["rustdoc", "--cap-lints", "allow"], &[]);
+        assert!(!dependency.reports_lints());
+
+        let ctx = context(
+            &root,
+            &["rustdoc", "src/lib.rs"],
+            &[(DOC_DIR_ENV, "/explicit")],
+        );
+        assert_eq!(ctx.resolve(), DocsDir::Rendering("/explicit".into()));
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn rustc_output() {
+        let root = workspace("rustc");
+        let out_dir = root.join("custom/wasm32-unknown-unknown/debug/deps");
+        let ctx = context(
+            &root,
+            &[
+                "rustc",
+                "--emit=dep-info,metadata",
+                "--out-dir",
+                out_dir.to_str().unwrap(),
+                "--target",
+                "wasm32-unknown-unknown",
+            ],
+            &[],
+        );
+        assert!(!ctx.is_rustdoc());
+        assert!(!ctx.reports_lints());
+        let expected = root.join("custom/wasm32-unknown-unknown/doc");
+        match ctx.resolve() {
+            DocsDir::Missing(candidates) => {
+                assert_eq!(candidates[0], expected);
+                // the workspace target dir, not the member's one
+                assert!(candidates.contains(&root.join("target/wasm32-unknown-unknown/doc")));
+                assert!(!candidates.contains(&root.join("member/target/doc")));
+            }
+            dir => panic!("unexpected {:?}", dir),
+        }
+
+        fs::create_dir_all(&expected).unwrap();
+        assert_eq!(ctx.resolve(), DocsDir::Existing(expected));
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn cargo_config_target_dir() {
+        let root = workspace("config");
+        fs::create_dir_all(root.join(".cargo")).unwrap();
+        fs::write(
+            root.join(".cargo/config.toml"),
+            "[build]\ntarget-dir = \"out\"\ntarget = \"specs/custom.json\"\n",
+        )
+        .unwrap();
+        fs::create_dir_all(root.join("out/custom/doc")).unwrap();
+
+        let ctx = context(&root, &["rustc", "--emit=link"], &[]);
+        assert!(!ctx.is_rustdoc());
+        assert_eq!(
+            ctx.resolve(),
+            DocsDir::Existing(root.join("out/custom/doc"))
+        );
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,467 @@
+use crate::attrs::Attrs;
+use crate::config::Config;
+use crate::docs_dir;
+use itertools::Itertools;
+use proc_macro2::{TokenStream, TokenTree};
+use proc_macro_error2::abort;
+use quote::{quote, ToTokens};
+use std::path::PathBuf;
+use syn::parse::Parser;
+use syn::visit_mut::{self, VisitMut};
+use syn::{
+    parse_quote, AttrStyle, Attribute, Block, Field, ForeignItem, ImplItem, Item, ItemConst, Meta,
+    Stmt, TraitItem, Variant,
+};
+
+/// Apply the doc comments transformation to everything nested in the item:
+/// fields, variants, associated items, items of inline modules and their inner docs
+///
+/// Items that can't be parsed are forwarded untouched
+///
+/// `inputs` are the ones read for the item's own docs, tracked along with the nested ones
+pub fn nested(config: &Config, item: TokenStream, inputs: Inputs) -> TokenStream {
+    match syn::parse2::<Item>(item.clone()) {
+        Ok(mut item) => {
+            let mut expand = Expand { config, inputs };
+            expand.visit_item_mut(&mut item);
+            track(item, expand.inputs)
+        }
+        Err(_) => item,
+    }
+}
+
+/// What the docs of an item and its nested entities are generated from
+#[derive(Default)]
+pub struct Inputs {
+    /// Files loaded with `include_mmd!`
+    pub includes: Vec<PathBuf>,
+    /// Environment variables read by the diagrams
+    pub env: Vec<String>,
+    /// Whether any diagrams are rendered, they depend on the crate-wide defaults then
+    pub diagrams: bool,
+}
+
+impl Inputs {
+    pub fn add(&mut self, attrs: &Attrs) {
+        self.includes.extend(attrs.includes());
+        self.env.extend(attrs.env_vars());
+        self.diagrams |= attrs.has_diagrams();
+    }
+}
+
+struct Expand<'a> {
+    config: &'a Config,
+    inputs: Inputs,
+}
+
+impl Expand<'_> {
+    fn expand(&mut self, attrs: &mut Vec<Attribute>) {
+        // entities with their own `#[aquamarine]` are left for it to process
+        if attrs.iter().any(is_aquamarine_attr) {
+            return;
+        }
+
+        let (outer, inner): (Vec<_>, Vec<_>) = attrs
+            .drain(..)
+            .partition(|attr| matches!(attr.style, AttrStyle::Outer));
+
+        attrs.extend(self.transform(outer));
+        attrs.extend(self.transform(inner).into_iter().map(|mut attr| {
+            attr.style = AttrStyle::Inner(Default::default());
+            attr
+        }));
+    }
+
+    fn transform(&mut self, attrs: Vec<Attribute>) -> Vec<Attribute> {
+        if attrs.is_empty() {
+            return attrs;
+        }
+
+        let attrs = Attrs::new(self.config.clone(), attrs);
+        self.inputs.add(&attrs);
+        Attribute::parse_outer
+            .parse2(quote!(#attrs))
+            .unwrap_or_else(|e| abort!(e.span(), "failed to re-parse doc attributes: {}", e))
+    }
+}
+
+impl VisitMut for Expand<'_> {
+    fn visit_item_mut(&mut self, item: &mut Item) {
+        if let Some(attrs) = item_attrs(item) {
+            self.expand(attrs);
+        }
+        visit_mut::visit_item_mut(self, item);
+    }
+
+    fn visit_impl_item_mut(&mut self, item: &mut ImplItem) {
+        if let Some(attrs) = impl_item_attrs(item) {
+            self.expand(attrs);
+        }
+        visit_mut::visit_impl_item_mut(self, item);
+    }
+
+    fn visit_trait_item_mut(&mut self, item: &mut TraitItem) {
+        if let Some(attrs) = trait_item_attrs(item) {
+            self.expand(attrs);
+        }
+        visit_mut::visit_trait_item_mut(self, item);
+    }
+
+    fn visit_foreign_item_mut(&mut self, item: &mut ForeignItem) {
+        if let Some(attrs) = foreign_item_attrs(item) {
+            self.expand(attrs);
+        }
+        visit_mut::visit_foreign_item_mut(self, item);
+    }
+
+    fn visit_field_mut(&mut self, field: &mut Field) {
+        self.expand(&mut field.attrs);
+        visit_mut::visit_field_mut(self, field);
+    }
+
+    fn visit_variant_mut(&mut self, variant: &mut Variant) {
+        self.expand(&mut variant.attrs);
+        visit_mut::visit_variant_mut(self, variant);
+    }
+
+    // items declared inside of function bodies are not documented
+    fn visit_block_mut(&mut self, _: &mut Block) {}
+}
+
+/// Make the files loaded with `include_mmd!` inputs of the build with a hidden `include_bytes!`,
+/// and the environment variables with a hidden `option_env!`, so that editing a diagram
+/// or changing the configuration rebuilds the docs. Items rendering diagrams also track
+/// the manifests and variables the crate-wide defaults are read from
+///
+/// The tracking const goes where an item is valid whether or not the item is an associated one:
+/// into function bodies and constant initializers, otherwise after the item.
+/// Associated types and such are not tracked
+fn track(mut item: Item, mut inputs: Inputs) -> TokenStream {
+    if inputs.diagrams {
+        inputs.includes.extend(Config::manifests());
+        inputs.env.extend(Config::env_vars());
+        inputs.env.push(docs_dir::DOC_DIR_ENV.to_string());
+    }
+    let paths = inputs
+        .includes
+        .iter()
+        .filter(|path| path.is_file())
+        .map(|path| path.to_string_lossy())
+        .unique()
+        .collect::<Vec<_>>();
+    let env = inputs.env.iter().unique().collect::<Vec<_>>();
+    if paths.is_empty() && env.is_empty() {
+        return item.into_token_stream();
+    }
+
+    let tracker: ItemConst = parse_quote! {
+        const _: () = {
+            #(let _ = include_bytes!(#paths);)*
+            #(let _ = option_env!(#env);)*
+        };
+    };
+    match &mut item {
+        Item::Fn(item) => {
+            item.block.stmts.insert(0, Stmt::Item(tracker.into()));
+            item.into_token_stream()
+        }
+        Item::Const(item) => {
+            let expr = &item.expr;
+            *item.expr = parse_quote!({ #tracker #expr });
+            item.into_token_stream()
+        }
+        Item::Enum(_)
+        | Item::ExternCrate(_)
+        | Item::ForeignMod(_)
+        | Item::Impl(_)
+        | Item::Mod(_)
+        | Item::Static(_)
+        | Item::Struct(_)
+        | Item::Trait(_)
+        | Item::TraitAlias(_)
+        | Item::Union(_)
+        | Item::Use(_) => quote! {
+            #item
+            #tracker
+        },
+        _ => item.into_token_stream(),
+    }
+}
+
+fn is_aquamarine_attr(attr: &Attribute) -> bool {
+    match &attr.meta {
+        Meta::List(list) if list.path.is_ident("cfg_attr") => list
+            .tokens
+            .clone()
+            .into_iter()
+            .any(|token| matches!(token, TokenTree::Ident(ident) if ident == "aquamarine")),
+        meta => meta
+            .path()
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "aquamarine"),
+    }
+}
+
+fn item_attrs(item: &mut Item) -> Option<&mut Vec<Attribute>> {
+    match item {
+        Item::Const(item) => Some(&mut item.attrs),
+        Item::Enum(item) => Some(&mut item.attrs),
+        Item::ExternCrate(item) => Some(&mut item.attrs),
+        Item::Fn(item) => Some(&mut item.attrs),
+        Item::ForeignMod(item) => Some(&mut item.attrs),
+        Item::Impl(item) => Some(&mut item.attrs),
+        Item::Macro(item) => Some(&mut item.attrs),
+        Item::Mod(item) => Some(&mut item.attrs),
+        Item::Static(item) => Some(&mut item.attrs),
+        Item::Struct(item) => Some(&mut item.attrs),
+        Item::Trait(item) => Some(&mut item.attrs),
+        Item::TraitAlias(item) => Some(&mut item.attrs),
+        Item::Type(item) => Some(&mut item.attrs),
+        Item::Union(item) => Some(&mut item.attrs),
+        Item::Use(item) => Some(&mut item.attrs),
+        _ => None,
+    }
+}
+
+fn impl_item_attrs(item: &mut ImplItem) -> Option<&mut Vec<Attribute>> {
+    match item {
+        ImplItem::Const(item) => Some(&mut item.attrs),
+        ImplItem::Fn(item) => Some(&mut item.attrs),
+        ImplItem::Type(item) => Some(&mut item.attrs),
+        ImplItem::Macro(item) => Some(&mut item.attrs),
+        _ => None,
+    }
+}
+
+fn trait_item_attrs(item: &mut TraitItem) -> Option<&mut Vec<Attribute>> {
+    match item {
+        TraitItem::Const(item) => Some(&mut item.attrs),
+        TraitItem::Fn(item) => Some(&mut item.attrs),
+        TraitItem::Type(item) => Some(&mut item.attrs),
+        TraitItem::Macro(item) => Some(&mut item.attrs),
+        _ => None,
+    }
+}
+
+fn foreign_item_attrs(item: &mut ForeignItem) -> Option<&mut Vec<Attribute>> {
+    match item {
+        ForeignItem::Fn(item) => Some(&mut item.attrs),
+        ForeignItem::Static(item) => Some(&mut item.attrs),
+        ForeignItem::Type(item) => Some(&mut item.attrs),
+        ForeignItem::Macro(item) => Some(&mut item.attrs),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{nested, Inputs};
+    use crate::config::Config;
+    use proc_macro2::TokenStream;
+    use quote::quote;
+    use std::path::PathBuf;
+
+    fn expand(item: TokenStream, includes: Vec<PathBuf>, env: Vec<String>) -> String {
+        let inputs = Inputs {
+            includes,
+            env,
+            diagrams: false,
+        };
+        nested(&Config::default(), item, inputs).to_string()
+    }
+
+    fn diagrams(tokens: &str) -> usize {
+        tokens.matches(r#"<div class=\"mermaid\">"#).count()
+    }
+
+    #[test]
+    fn nested_docs() {
+        let item = quote! {
+            mod inner {
+                //! ```mermaid
+                //! graph LR
+                //! ```
+
+                struct Foo {
+                    /// ```mermaid
+                    /// graph LR
+                    /// ```
+                    field: u32,
+                }
+
+                enum Bar {
+                    /// ```mermaid
+                    /// graph LR
+                    /// ```
+                    Variant,
+                }
+
+                impl Foo {
+                    /// ```mermaid
+                    /// graph LR
+                    /// ```
+                    fn method(&self) {
+                        /// ```mermaid
+                        /// not documented
+                        /// ```
+                        struct Local;
+                    }
+                }
+
+                trait Baz {
+                    /// ```mermaid
+                    /// graph LR
+                    /// ```
+                    fn required(&self);
+                }
+            }
+        };
+
+        let out = expand(item, Vec::new(), Vec::new());
+        assert_eq!(diagrams(&out), 5);
+        assert!(out.contains("# ! [doc ="));
+        assert!(out.contains("not documented"));
+    }
+
+    #[test]
+    fn bootstrap_per_docs_block() {
+        // rustdoc shows the docs of fields, methods and such on their own,
+        // e.g. the methods of a trait on the pages of its implementors
+        let item = quote! {
+            trait Foo {
+                /// ```mermaid
+                /// graph LR
+                /// ```
+                /// ```mermaid
+                /// graph TD
+                /// ```
+                fn first(&self);
+                /// ```mermaid
+                /// graph LR
+                /// ```
+                fn second(&self);
+                /// No diagrams here
+                fn third(&self);
+            }
+        };
+        let out = expand(item, Vec::new(), Vec::new());
+        assert_eq!(diagrams(&out), 3);
+        assert_eq!(out.matches("<script").count(), 2);
+    }
+
+    #[test]
+    fn skips_items_with_own_attribute() {
+        let item = quote! {
+            impl Foo {
+                #[aquamarine]
+                /// ```mermaid
+                /// graph LR
+                /// ```
+                fn method(&self) {}
+
+                #[cfg_attr(doc, aquamarine::aquamarine)]
+                /// ```mermaid
+                /// graph LR
+                /// ```
+                fn other(&self) {}
+            }
+        };
+
+        let out = expand(item, Vec::new(), Vec::new());
+        assert_eq!(diagrams(&out), 0);
+    }
+
+    #[test]
+    fn tracks_includes() {
+        let manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+        let missing = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("missing.mmd");
+        let includes = vec![manifest.clone(), missing, manifest];
+        let track = |item| expand(item, includes.clone(), Vec::new());
+
+        let out = track(quote! { fn function() { body() } });
+        assert_eq!(out.matches("include_bytes !").count(), 1);
+        assert!(out.starts_with("fn function () { const _ : () ="));
+        assert!(!out.contains("missing.mmd"));
+
+        let out = track(quote! { const VALUE: u32 = 1; });
+        assert!(out.starts_with("const VALUE : u32 = { const _ : () ="));
+
+        let out = track(quote! { struct Foo; });
+        assert!(out.starts_with("struct Foo ; const _ : () ="));
+
+        let out = track(quote! { type Foo = u32; });
+        assert!(!out.contains("include_bytes"));
+
+        let out = expand(quote! { struct Foo; }, Vec::new(), Vec::new());
+        assert!(!out.contains("include_bytes"));
+    }
+
+    #[test]
+    fn tracks_env() {
+        let env = vec![
+            "AQUAMARINE_THEME".to_string(),
+            "AQUAMARINE_THEME".to_string(),
+        ];
+        let out = expand(quote! { struct Foo; }, Vec::new(), env);
+        assert_eq!(out.matches("option_env !").count(), 1);
+        assert!(out.contains(r#"option_env ! ("AQUAMARINE_THEME")"#));
+    }
+
+    #[test]
+    fn tracks_defaults_of_diagrams() {
+        let out = expand(
+            quote! {
+                struct Foo {
+                    /// ```mermaid
+                    /// graph LR
+                    ///     a --> b
+                    /// ```
+                    field: u32,
+                }
+            },
+            Vec::new(),
+            Vec::new(),
+        );
+        assert!(out.contains(r#"option_env ! ("AQUAMARINE_THEME")"#));
+        assert!(out.contains("Cargo.toml"));
+
+        let out = expand(
+            quote! {
+                /// No diagrams here
+                struct Foo {
+                    /// Nor here
+                    field: u32,
+                }
+            },
+            Vec::new(),
+            Vec::new(),
+        );
+        assert!(!out.contains("const _"));
+    }
+
+    #[test]
+    fn tracks_env_of_included_lines() {
+        let path =
+            std::env::temp_dir().join(format!("aquamarine-tracked-env-{}.mmd", std::process::id()));
+        std::fs::write(
+            &path,
+            "graph LR\n  A[{{env.AQ_SKIPPED}}]\n  B[{{CARGO_PKG_NAME}}]\n",
+        )
+        .unwrap();
+        let doc = format!(r#"include_mmd!({:?}, lines = 3..)"#, path);
+        let out = expand(
+            quote! {
+                struct Foo {
+                    #[doc = #doc]
+                    field: u32,
+                }
+            },
+            Vec::new(),
+            Vec::new(),
+        );
+        std::fs::remove_file(&path).unwrap();
+        assert!(out.contains(r#"option_env ! ("CARGO_PKG_NAME")"#));
+        assert!(!out.contains("AQ_SKIPPED"));
+    }
+}
@@ -0,0 +1,107 @@
+use crate::lint;
+use crate::validate::{Diagram, DiagramType};
+use itertools::Itertools;
+
+/// Class of the highlighted nodes
+const HIGHLIGHT_CLASS: &str = "aquamarineHighlight";
+/// Class of the other nodes, the `default` class is left to the diagram
+const DIM_CLASS: &str = "aquamarineDim";
+
+/// Statements emphasising `nodes` of a flowchart and dimming the rest,
+/// to be appended to the diagram
+pub fn statements<S: AsRef<str>>(lines: &[S], nodes: &[String]) -> Result<Vec<String>, String> {
+    if nodes.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let diagram = Diagram::parse(lines, &mut Vec::new())
+        .filter(|diagram| diagram.kind == DiagramType::Flowchart)
+        .ok_or_else(|| "`highlight` is supported in flowcharts only".to_string())?;
+
+    let ids = lint::flowchart_ids(&diagram.statements);
+    if let Some(node) = nodes.iter().find(|node| !ids.contains(&node.as_str())) {
+        return Err(match lint::similar(node, ids.iter().copied()) {
+            Some(similar) => format!(
+                "cannot highlight `{}`, there's no such node, did you mean `{}`?",
+                node, similar
+            ),
+            None => format!("cannot highlight `{}`, there's no such node", node),
+        });
+    }
+
+    let mut statements = vec![
+        format!("classDef {} stroke-width:3px", HIGHLIGHT_CLASS),
+        format!("class {} {}", nodes.join(","), HIGHLIGHT_CLASS),
+    ];
+    let dimmed = ids
+        .iter()
+        .filter(|id| !nodes.iter().any(|node| node == *id))
+        .join(",");
+    if !dimmed.is_empty() {
+        statements.push(format!("classDef {} opacity:0.4", DIM_CLASS));
+        statements.push(format!("class {} {}", dimmed, DIM_CLASS));
+    }
+    Ok(statements)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::statements;
+
+    fn highlight(diagram: &str, nodes: &[&str]) -> Result<Vec<String>, String> {
+        let lines = diagram.lines().collect::<Vec<_>>();
+        let nodes = nodes
+            .iter()
+            .map(|node| node.to_string())
+            .collect::<Vec<_>>();
+        statements(&lines, &nodes)
+    }
+
+    #[test]
+    fn flowchart() {
+        let diagram = "graph LR\n  Lexer[Lexer] --> Parser\n  subgraph Backend\n    Codegen\n  end";
+        assert_eq!(
+            highlight(diagram, &["Parser", "Lexer"]).unwrap(),
+            [
+                "classDef aquamarineHighlight stroke-width:3px",
+                "class Parser,Lexer aquamarineHighlight",
+                "classDef aquamarineDim opacity:0.4",
+                "class Backend,Codegen aquamarineDim",
+            ]
+        );
+        assert_eq!(
+            highlight(diagram, &["Lexer", "Parser", "Backend", "Codegen"])
+                .unwrap()
+                .len(),
+            2
+        );
+        assert!(highlight(diagram, &["Backend"]).is_ok());
+        assert!(highlight(diagram, &[]).unwrap().is_empty());
+        assert_eq!(
+            highlight(diagram, &["Parsr"]).unwrap_err(),
+            "cannot highlight `Parsr`, there's no such node, did you mean `Parser`?"
+        );
+        assert!(highlight(diagram, &["Optimizer"]).is_err());
+    }
+
+    #[test]
+    fn comments_before_the_header() {
+        // as at the top of a `.mmd` file
+        let diagram = "%% Pipeline\naccTitle: Pipeline\ngraph LR\n  Lexer --> Parser";
+        assert_eq!(
+            highlight(diagram, &["Parser"]).unwrap(),
+            [
+                "classDef aquamarineHighlight stroke-width:3px",
+                "class Parser aquamarineHighlight",
+                "classDef aquamarineDim opacity:0.4",
+                "class Lexer aquamarineDim",
+            ]
+        );
+    }
+
+    #[test]
+    fn other_diagrams() {
+        assert!(highlight("sequenceDiagram\n  A->>B: hi", &["A"]).is_err());
+        assert!(highlight("", &["A"]).is_err());
+    }
+}
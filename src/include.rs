@@ -0,0 +1,490 @@
+use crate::parse::Vars;
+use proc_macro2::TokenTree;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use syn::parse::{Parse, ParseStream};
+use syn::{parenthesized, Expr, ExprLit, ExprRange, Ident, Lit, LitStr, RangeLimits, Token};
+
+const INCLUDE_MACRO: &str = "include_mmd!";
+const USE_MACRO: &str = "use_mmd!";
+
+/// Arguments of an `include_mmd!(...)` anchor
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Include {
+    /// Path of the file, relative to the crate manifest
+    pub path: PathBuf,
+    /// Name of the `%% region: <name>` to include
+    pub section: Option<String>,
+    /// Lines to include
+    pub lines: Option<Lines>,
+    /// Flowchart nodes to emphasise, dimming the rest
+    pub highlight: Vec<String>,
+    /// Values of the placeholders, in addition to the crate-wide ones
+    pub vars: BTreeMap<String, String>,
+}
+
+/// Range of 1-based line numbers, the last one included
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Lines {
+    pub first: usize,
+    /// `None` up to the end of the file
+    pub last: Option<usize>,
+}
+
+/// Region marker of a diagram file, a mermaid comment
+enum Marker<'a> {
+    /// `%% region: <name>`
+    Start(&'a str),
+    /// `%% endregion`
+    End,
+}
+
+impl<'a> Marker<'a> {
+    fn parse(line: &'a str) -> Option<Marker<'a>> {
+        let comment = line.trim().strip_prefix("%%")?.trim();
+        if comment == "endregion" {
+            Some(Marker::End)
+        } else {
+            comment
+                .strip_prefix("region:")
+                .map(|name| Marker::Start(name.trim()))
+        }
+    }
+}
+
+impl Include {
+    pub fn is_anchor(line: &str) -> bool {
+        line.starts_with(INCLUDE_MACRO)
+    }
+
+    /// Path of the file in the crate manifest directory
+    pub fn resolve(&self) -> PathBuf {
+        let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or(".".to_string());
+        PathBuf::from(manifest_dir).join(&self.path)
+    }
+
+    /// Parse an anchor line, `include_mmd!(...)` followed by an optional text
+    pub fn parse_anchor(line: &str) -> Result<(Include, &str), String> {
+        let (args, rest) = invocation(line, INCLUDE_MACRO)?;
+        let include = syn::parse_str(args)
+            .map_err(|e| format!("invalid `{}` arguments: {}", INCLUDE_MACRO, e))?;
+        Ok((include, rest))
+    }
+
+    /// Lines of the file to include, with their 1-based numbers
+    pub fn select<'a>(&self, data: &'a str) -> Result<Vec<(usize, &'a str)>, String> {
+        let lines: Vec<(usize, &str)> = data.lines().enumerate().map(|(i, l)| (i + 1, l)).collect();
+
+        if let Some(section) = &self.section {
+            return select_section(&lines, section);
+        }
+
+        match self.lines {
+            Some(Lines { first, last }) => {
+                let last = last.unwrap_or(lines.len());
+                if let Some(line) = [first, last].iter().find(|&&line| line > lines.len()) {
+                    return Err(format!(
+                        "line {} is past the end of the file, it has {} lines",
+                        line,
+                        lines.len()
+                    ));
+                }
+                Ok(lines[first - 1..last].to_vec())
+            }
+            None => Ok(lines),
+        }
+    }
+}
+
+pub fn is_use_anchor(line: &str) -> bool {
+    line.starts_with(USE_MACRO)
+}
+
+/// Parse a `use_mmd!(name)` line of a diagram defined with `define=name`,
+/// followed by an optional text
+pub fn parse_use_anchor(line: &str) -> Result<(String, &str), String> {
+    let (args, rest) = invocation(line, USE_MACRO)?;
+    let name = syn::parse_str::<Ident>(args).map_err(|_| {
+        format!(
+            "expected the name of a diagram defined with `define=<name>`, found `{}`",
+            args.trim()
+        )
+    })?;
+    Ok((name.to_string(), rest))
+}
+
+/// Arguments of a `name!(...)` invocation at the start of `line`, and the text following it
+fn invocation<'a>(line: &'a str, name: &str) -> Result<(&'a str, &'a str), String> {
+    let invocation = line
+        .strip_prefix(name)
+        .map(str::trim_start)
+        .filter(|args| args.starts_with('('))
+        .ok_or_else(|| format!("expected `(` after `{}`", name))?;
+    let len =
+        invocation_len(invocation).ok_or_else(|| format!("`{}(` is not closed with `)`", name))?;
+    Ok((&invocation[1..len - 1], &invocation[len..]))
+}
+
+/// Lines of a region, after the lines preceding the first region,
+/// shared by all of them: the diagram type, class definitions and so on
+fn select_section<'a>(
+    lines: &[(usize, &'a str)],
+    section: &str,
+) -> Result<Vec<(usize, &'a str)>, String> {
+    let preamble = lines
+        .iter()
+        .take_while(|(_, line)| !matches!(Marker::parse(line), Some(Marker::Start(_))));
+
+    let start = lines
+        .iter()
+        .position(
+            |(_, line)| matches!(Marker::parse(line), Some(Marker::Start(name)) if name == section),
+        )
+        .ok_or_else(|| {
+            let regions = lines
+                .iter()
+                .filter_map(|(_, line)| match Marker::parse(line) {
+                    Some(Marker::Start(name)) => Some(format!("`{}`", name)),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            match regions.is_empty() {
+                true => format!("no `%% region: {}` in the file, it has no regions", section),
+                false => format!(
+                    "no `%% region: {}` in the file, expected one of: {}",
+                    section,
+                    regions.join(", ")
+                ),
+            }
+        })?;
+
+    let mut depth = 0;
+    let mut region = Vec::new();
+    for &(number, line) in &lines[start + 1..] {
+        match Marker::parse(line) {
+            Some(Marker::End) if depth == 0 => {
+                return Ok(preamble.copied().chain(region).collect());
+            }
+            Some(Marker::End) => depth -= 1,
+            Some(Marker::Start(_)) => depth += 1,
+            None => region.push((number, line)),
+        }
+    }
+    Err(format!(
+        "`%% region: {}` is not closed with `%% endregion`",
+        section
+    ))
+}
+
+/// Length of the parenthesized arguments at the start of `s`, string literals skipped
+fn invocation_len(s: &str) -> Option<usize> {
+    let mut depth = 0;
+    let mut chars = s.char_indices().peekable();
+    while let Some((at, c)) = chars.next() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(at + 1);
+                }
+            }
+            '"' => {
+                let mut escaped = false;
+                chars.by_ref().find(|(_, c)| {
+                    let closes = *c == '"' && !escaped;
+                    escaped = *c == '\\' && !escaped;
+                    closes
+                })?;
+            }
+            // raw strings, `r"..."` and `r#"..."#`
+            'r' if matches!(chars.peek(), Some((_, '"' | '#'))) => {
+                let hashes = s[at + 1..].chars().take_while(|c| *c == '#').count();
+                let rest = &s[at + 1 + hashes..];
+                if !rest.starts_with('"') {
+                    continue;
+                }
+                let terminator = format!("\"{}", "#".repeat(hashes));
+                let end = at + 1 + hashes + 1 + rest[1..].find(&terminator)? + terminator.len();
+                while chars.peek().is_some_and(|(next, _)| *next < end) {
+                    chars.next();
+                }
+            }
+            _ => (),
+        }
+    }
+    None
+}
+
+impl Parse for Include {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let path = if input.peek(LitStr) {
+            input.parse::<LitStr>()?.value()
+        } else {
+            // unquoted paths, `include_mmd!(diagrams/flow.mmd)`
+            let mut path = String::new();
+            while !input.is_empty() && !input.peek(Token![,]) {
+                path.push_str(&input.parse::<TokenTree>()?.to_string());
+            }
+            path
+        };
+        if path.is_empty() {
+            return Err(input.error("expected the path of a mermaid file"));
+        }
+
+        let mut include = Include {
+            path: PathBuf::from(path),
+            ..Default::default()
+        };
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+            let key: Ident = input.parse()?;
+            if key == "vars" && input.peek(syn::token::Paren) {
+                let content;
+                parenthesized!(content in input);
+                include.vars = content.parse::<Vars>()?.0;
+                continue;
+            }
+            input.parse::<Token![=]>()?;
+            match key.to_string().as_str() {
+                "section" => include.section = Some(input.parse::<LitStr>()?.value()),
+                "lines" => include.lines = Some(parse_lines(input.parse()?)?),
+                "highlight" => include.highlight = parse_nodes(input.parse()?)?,
+                _ => {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        format!(
+                            "unknown argument `{}`, expected `section`, `lines`, `highlight` or `vars(...)`",
+                            key
+                        ),
+                    ))
+                }
+            }
+        }
+
+        if include.section.is_some() && include.lines.is_some() {
+            return Err(input.error("`section` and `lines` can't be combined"));
+        }
+        Ok(include)
+    }
+}
+
+/// `["Parser", "Lexer"]` or a single `"Parser"`
+fn parse_nodes(expr: Expr) -> syn::Result<Vec<String>> {
+    let node = |expr: &Expr| match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(node),
+            ..
+        }) => Ok(node.value()),
+        _ => Err(syn::Error::new_spanned(expr, "expected the id of a node")),
+    };
+
+    match &expr {
+        Expr::Array(array) if !array.elems.is_empty() => array.elems.iter().map(node).collect(),
+        Expr::Array(_) => Err(syn::Error::new_spanned(
+            expr,
+            "expected a list of nodes, e.g. `[\"Parser\", \"Lexer\"]`",
+        )),
+        expr => node(expr).map(|node| vec![node]),
+    }
+}
+
+/// `first..end` excluding `end`, `first..=last` or `first..`
+fn parse_lines(expr: Expr) -> syn::Result<Lines> {
+    let number = |expr: &Option<Box<Expr>>| match expr.as_deref() {
+        None => Ok(None),
+        Some(Expr::Lit(ExprLit {
+            lit: Lit::Int(int), ..
+        })) => int.base10_parse::<usize>().map(Some),
+        Some(expr) => Err(syn::Error::new_spanned(expr, "expected a line number")),
+    };
+
+    let range = match &expr {
+        Expr::Range(range) => range,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                expr,
+                "expected a range of lines, e.g. `10..=40`, or `10..41` excluding line 41",
+            ))
+        }
+    };
+    let ExprRange {
+        start, limits, end, ..
+    } = range;
+    let first = number(start)?.unwrap_or(1);
+    let last = match (number(end)?, limits) {
+        (Some(end), RangeLimits::HalfOpen(_)) => Some(end.saturating_sub(1)),
+        (last, _) => last,
+    };
+
+    if first == 0 {
+        return Err(syn::Error::new_spanned(range, "line numbers start at 1"));
+    }
+    if last.is_some_and(|last| last < first) {
+        return Err(syn::Error::new_spanned(
+            range,
+            "the range of lines is empty",
+        ));
+    }
+    Ok(Lines { first, last })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_use_anchor, Include, Lines};
+
+    fn anchor(line: &str) -> Result<(Include, &str), String> {
+        Include::parse_anchor(line)
+    }
+
+    fn path(line: &str) -> String {
+        anchor(line).unwrap().0.path.display().to_string()
+    }
+
+    #[test]
+    fn paths() {
+        assert_eq!(path(r#"include_mmd!("diagram.mmd")"#), "diagram.mmd");
+        assert_eq!(
+            path(r#"include_mmd!("my diagrams/a (1).mmd")"#),
+            "my diagrams/a (1).mmd"
+        );
+        assert_eq!(path(r#"include_mmd!("a\"b.mmd")"#), r#"a"b.mmd"#);
+        assert_eq!(path(r##"include_mmd!(r#"a")".mmd"#)"##), r#"a")".mmd"#);
+        assert_eq!(path("include_mmd!(diagram_1.mmd)"), "diagram_1.mmd");
+        assert_eq!(
+            path("include_mmd! (../docs/flow-1.mmd)"),
+            "../docs/flow-1.mmd"
+        );
+    }
+
+    #[test]
+    fn trailing_text() {
+        let (include, rest) = anchor(r#"include_mmd!("a.mmd") shows the flow (roughly)"#).unwrap();
+        assert_eq!(include.path.to_str(), Some("a.mmd"));
+        assert_eq!(rest, " shows the flow (roughly)");
+    }
+
+    #[test]
+    fn arguments() {
+        let (include, _) = anchor(r#"include_mmd!("arch.mmd", section = "storage")"#).unwrap();
+        assert_eq!(include.section.as_deref(), Some("storage"));
+
+        let lines = |args: &str| {
+            anchor(&format!(r#"include_mmd!("a.mmd", lines = {})"#, args))
+                .map(|(include, _)| include.lines.unwrap())
+        };
+        let range = |first, last| Lines { first, last };
+        assert_eq!(lines("10..40"), Ok(range(10, Some(39))));
+        assert_eq!(lines("10..=40"), Ok(range(10, Some(40))));
+        assert_eq!(lines("10.."), Ok(range(10, None)));
+        assert_eq!(lines("..5"), Ok(range(1, Some(4))));
+        assert!(lines("0..5").is_err());
+        assert!(lines("5..5").is_err());
+        assert!(lines("5").is_err());
+
+        let highlight = |args: &str| {
+            anchor(&format!(r#"include_mmd!("a.mmd", highlight = {})"#, args))
+                .map(|(include, _)| include.highlight)
+        };
+        assert_eq!(
+            highlight(r#"["Parser", "Lexer"]"#).unwrap(),
+            ["Parser", "Lexer"]
+        );
+        assert_eq!(highlight(r#""Parser""#).unwrap(), ["Parser"]);
+        assert!(highlight("[]").is_err());
+        assert!(highlight("[Parser]").is_err());
+
+        let (include, _) =
+            anchor(r#"include_mmd!("svc.mmd", vars(service = "auth"), lines = 2..)"#).unwrap();
+        assert_eq!(include.vars["service"], "auth");
+        assert!(anchor(r#"include_mmd!("svc.mmd", vars = "auth")"#).is_err());
+
+        assert!(anchor(r#"include_mmd!("a.mmd", colour = "red")"#).is_err());
+        assert!(anchor(r#"include_mmd!("a.mmd", section = "a", lines = 1..2)"#).is_err());
+        assert!(anchor(r#"include_mmd!("a.mmd""#).is_err());
+        assert!(anchor("include_mmd!()").is_err());
+        assert!(anchor(r#"include_mmd!"a.mmd""#).is_err());
+    }
+
+    #[test]
+    fn use_anchors() {
+        assert_eq!(
+            parse_use_anchor("use_mmd!(pipeline) (simplified)"),
+            Ok(("pipeline".to_string(), " (simplified)"))
+        );
+        assert_eq!(
+            parse_use_anchor("use_mmd!( pipeline )").map(|(name, _)| name),
+            Ok("pipeline".to_string())
+        );
+        assert!(parse_use_anchor(r#"use_mmd!("pipeline")"#).is_err());
+        assert!(parse_use_anchor("use_mmd!(a, b)").is_err());
+        assert!(parse_use_anchor("use_mmd!(pipeline").is_err());
+    }
+
+    #[test]
+    fn sections() {
+        let file = "graph LR\n  classDef hot fill:#f96\n%% region: storage\n  db --> disk\n  %% region: cache\n  db --> cache\n  %% endregion\n%% endregion\n%% region: api\n  api --> db\n%% endregion";
+        let select = |section: &str| {
+            let include = Include {
+                section: Some(section.into()),
+                ..Default::default()
+            };
+            include.select(file)
+        };
+        assert_eq!(
+            select("storage").unwrap(),
+            [
+                (1, "graph LR"),
+                (2, "  classDef hot fill:#f96"),
+                (4, "  db --> disk"),
+                (6, "  db --> cache"),
+            ]
+        );
+        assert_eq!(select("api").unwrap().last(), Some(&(10, "  api --> db")));
+        assert_eq!(select("cache").unwrap().len(), 3);
+        assert!(select("network")
+            .unwrap_err()
+            .contains("`storage`, `cache`, `api`"));
+        assert!(Include {
+            section: Some("open".into()),
+            ..Default::default()
+        }
+        .select("%% region: open\na --> b")
+        .is_err());
+    }
+
+    #[test]
+    fn line_ranges() {
+        let file = "graph LR\na --> b\nb --> c\nc --> d";
+        let select = |first, last| {
+            let include = Include {
+                lines: Some(Lines { first, last }),
+                ..Default::default()
+            };
+            include.select(file)
+        };
+        assert_eq!(
+            select(2, Some(3)).unwrap(),
+            [(2, "a --> b"), (3, "b --> c")]
+        );
+        assert_eq!(select(4, None).unwrap(), [(4, "c --> d")]);
+        assert!(select(2, Some(5)).is_err());
+        assert!(select(6, None).is_err());
+
+        let numbers = |range: &str| {
+            let (include, _) =
+                anchor(&format!(r#"include_mmd!("a.mmd", lines = {})"#, range)).unwrap();
+            include
+                .select(file)
+                .map(|lines| lines.iter().map(|(number, _)| *number).collect::<Vec<_>>())
+        };
+        assert_eq!(numbers("2..4"), Ok(vec![2, 3]));
+        assert_eq!(numbers("2..=4"), Ok(vec![2, 3, 4]));
+        assert_eq!(numbers("2..5"), Ok(vec![2, 3, 4]));
+        assert!(numbers("2..=5").is_err());
+        assert_eq!(numbers("4..=4"), Ok(vec![4]));
+    }
+}
@@ -28,7 +28,19 @@
 //!
 //! Aquamarine will automatically select the `dark` theme as a default, if the current `rustdoc` theme is either `ayu` or `dark`.
 //!
-//! You might need to reload the page to redraw the diagrams after changing the theme.
+//! Diagrams are redrawn automatically when the theme is changed in the rustdoc settings.
+//! The mermaid theme used for each of the rustdoc themes can be changed with the `light_theme`, `dark_theme` and `ayu_theme` [options](#configuration).
+//! Custom rustdoc themes get the light or the dark mermaid theme, depending on the page background.
+//!
+//! Alternatively, the `rustdoc` theme paints diagrams in the colors of the active rustdoc theme, custom `--extend-css` themes included.
+//! It's the mermaid `base` theme, with the `themeVariables` taken from the rustdoc CSS variables at render time:
+//!
+//! ```toml
+//! [package.metadata.aquamarine]
+//! light-theme = "rustdoc"
+//! dark-theme = "rustdoc"
+//! ayu-theme = "rustdoc"
+//! ```
 //!
 //! ### Custom themes
 //!
@@ -55,6 +67,112 @@
 //!
 //! To learn more, see the [Theming Section](https://mermaid-js.github.io/mermaid/#/theming) of the mermaid.js book
 //!
+//! ### Diagram attributes
+//!
+//! Individual diagrams can be captioned, sized and linked to with attributes in the code block info string:
+//!
+//! ```no_run
+//! /// ```mermaid title="Request lifecycle" caption="Figure 1: a request" id=lifecycle width=80% align=center
+//! /// sequenceDiagram
+//! ///     Client->>Server: request
+//! ///     Server-->>Client: response
+//! /// ```
+//! # fn example() {}
+//! ```
+//!
+//! | Attribute | Description                                                            |
+//! |-----------|------------------------------------------------------------------------|
+//! | `title`   | diagram title, rendered by mermaid                                     |
+//! | `caption` | caption below the diagram                                              |
+//! | `id`      | anchor id, the diagram can be linked to with `#lifecycle`              |
+//! | `theme`   | mermaid theme of this diagram only                                     |
+//! | `width`   | diagram width, a number with an optional `px`, `%`, `em`, `rem`, `vw` or `ch` unit |
+//! | `align`   | `left`, `center` or `right`                                            |
+//! | `highlight` | flowchart nodes to emphasise, comma-separated, the other nodes are dimmed |
+//! | `define`    | name the diagram is reused by, `use_mmd!(name)`, see [Reusing diagrams](#reusing-diagrams) |
+//!
+//! Values containing spaces must be quoted.
+//!
+//! ### Nested items
+//!
+//! A single `#[aquamarine]` on a struct, an enum, an `impl` block, a trait or an inline module also processes the docs of its fields, variants, associated items and nested items, including the inner `//!` docs of the modules:
+//!
+//! ```no_run
+//! # use aquamarine::aquamarine;
+//! #[aquamarine]
+//! impl Example {
+//!     /// ```mermaid
+//!     /// graph LR
+//!     ///     a --> b
+//!     /// ```
+//!     pub fn method(&self) {}
+//! }
+//! # struct Example;
+//! ```
+//!
+//! Entities with their own `#[aquamarine(...)]` attribute are left to it, so their arguments take effect.
+//!
+//! ### Offline mode
+//!
+//! By default, the docs fall back to loading mermaid from a CDN when the local copy can't be loaded.
+//! Docs that are built and served in an isolated network can opt out of that with the `offline` cargo feature:
+//!
+//! ```toml
+//! [dependencies]
+//! aquamarine = { version = "0.6", features = ["offline"] }
+//! ```
+//!
+//! or with the `offline` option, e.g. `AQUAMARINE_OFFLINE=1 cargo doc`.
+//!
+//! In offline mode, the generated pages never reference the remote location, and the build fails if the local copy of mermaid can't be placed into the docs directory.
+//!
+//! ### Verifying the CDN
+//!
+//! The `cdn` option can point to a mirror, with `{version}` standing for `mermaid_version`:
+//! `cdn = "https://mirror.example.com/mermaid@{version}/dist/mermaid.min.js"`.
+//!
+//! With the `integrity` option, the browser refuses a remote copy that doesn't match the given
+//! [Subresource Integrity](https://developer.mozilla.org/en-US/docs/Web/Security/Subresource_Integrity) hash.
+//! Verified copies are loaded as a classic script, so the default CDN location switches to the `mermaid.min.js` build.
+//! The value is either a `sha256-`, `sha384-` or `sha512-` hash, or `"bundled"`:
+//! the hash of the mermaid build shipped with aquamarine, which pins the remote version to the bundled one.
+//! Its classic build is converted from the module files of the release, so it differs from the `mermaid.min.js` of the CDN:
+//! `"bundled"` verifies a mirror, set with `cdn`, serving the copy placed next to the docs.
+//!
+//! ### Docs directory
+//!
+//! The local copy of mermaid is placed next to the generated docs.
+//! Their location is taken from the `rustdoc` command line, so `--target-dir` and `--target` are respected.
+//! Docs of dependencies are looked up in `CARGO_TARGET_DIR`, `build.target-dir` of `.cargo/config.toml`
+//! and the `target` directory of the workspace.
+//! When none of these fits, point `AQUAMARINE_DOC_DIR` to the docs directory, e.g. `AQUAMARINE_DOC_DIR=/srv/docs cargo doc`.
+//!
+//! ### Custom mermaid build
+//!
+//! A patched or newer mermaid can be placed into the docs instead of the bundled one with the `mermaid_path` option,
+//! e.g. `AQUAMARINE_MERMAID_PATH=node_modules/mermaid/dist cargo doc`.
+//! It's either a directory with `mermaid.esm.min.mjs` in it, or the entry `.mjs` file itself, next to the chunks it imports.
+//! Relative paths are resolved against the crate being documented, or the manifest declaring them in `[*.metadata.aquamarine]`.
+//! A build of another version than the bundled one is reported with a warning.
+//!
+//! ### Extensions
+//!
+//! Mermaid extensions are enabled with cargo features, loaded from the CDN and registered by the docs:
+//!
+//! | Feature  | Extension                                                             |
+//! |----------|-----------------------------------------------------------------------|
+//! | `elk`    | [ELK layout](https://www.npmjs.com/package/@mermaid-js/layout-elk), `layout: elk` in the diagram config |
+//! | `zenuml` | [ZenUML](https://www.npmjs.com/package/@mermaid-js/mermaid-zenuml) diagrams, `zenuml` code blocks |
+//!
+//! They can't be used in [offline mode](#offline-mode).
+//!
+//! Icon packs of architecture diagrams are registered from [iconify](https://iconify.design/) JSON files listed in the `icon_packs` option,
+//! e.g. `icon-packs = "icons/logos.json, icons/mdi.json"` in `[package.metadata.aquamarine]`.
+//! A pack is named after the `prefix` of its icons, as in `logos:rust`.
+//!
+//! The registration APIs of layouts and icon packs appeared in mermaid 11, so `elk` and `icon_packs` are rejected
+//! with the bundled mermaid 10.1.0: they take a [custom mermaid build](#custom-mermaid-build).
+//!
 //! ### Loading from a file
 //!
 //! When describing complex logic, a diagram can get quite big.
@@ -70,17 +188,205 @@
 //! # fn example() {}
 //! ```
 //! [Demo on docs.rs](https://docs.rs/aquamarine-demo-crate/0.6.0/aquamarine_demo_crate/fn.example_load_from_file.html)
+//!
+//! A part of a file can be included with the `section` or `lines` argument:
+//!
+//! ```text
+//! /// include_mmd!("architecture.mmd", section = "storage")
+//! /// include_mmd!("architecture.mmd", lines = 10..=40)
+//! ```
+//!
+//! Line ranges follow Rust: `10..=40` includes line 40, `10..40` stops at line 39 and `10..` runs to the end of the file.
+//!
+//! A section is the text between `%% region: <name>` and `%% endregion` comments, mermaid ignores both.
+//! The lines preceding the first region, such as the diagram type and class definitions, are shared by all sections:
+//!
+//! ```text
+//! flowchart LR
+//!     classDef db fill:#f96
+//! %% region: storage
+//!     cache --> db:::db
+//! %% endregion
+//! %% region: api
+//!     api --> cache
+//! %% endregion
+//! ```
+//!
+//! `lines` takes a range of 1-based line numbers, `10..40`, `10..=40` or `10..`.
+//! The path can be a string literal, raw strings included, and any text after the closing parenthesis stays in the docs.
+//! The included files are tracked like the ones of `include_bytes!`, so editing a diagram rebuilds the docs.
+//!
+//! Flowchart nodes can be highlighted, e.g. to show where an item sits in a diagram shared by several items:
+//! `include_mmd!("architecture.mmd", highlight = ["Parser", "Lexer"])`.
+//! The given nodes are emphasised and the rest are dimmed, like with the `highlight` diagram attribute.
+//! The classes are `aquamarineHighlight` and `aquamarineDim`, the diagram's own `default` class is left as it is.
+//!
+//! ### Reusing diagrams
+//!
+//! A diagram defined with the `define` attribute can be shown again in the docs of other items with `use_mmd!`:
+//!
+//! ```no_run
+//! #[cfg_attr(doc, aquamarine::aquamarine)]
+//! /// ```mermaid define=pipeline
+//! /// graph LR
+//! ///     lexer --> parser --> codegen
+//! /// ```
+//! pub struct Pipeline;
+//!
+//! #[cfg_attr(doc, aquamarine::aquamarine)]
+//! /// The stages of the [`Pipeline`]:
+//! ///
+//! /// use_mmd!(pipeline)
+//! pub fn compile() {}
+//! ```
+//!
+//! Definitions are shared by the items of a crate expanded after them: earlier in the same file,
+//! in modules declared before, or nested in the item defining the diagram.
+//! Using an unknown name and defining the same name twice in a crate are errors, other crates of the workspace can reuse the name.
+//! A diagram can't be used from another crate, define it again there instead.
+//!
+//! ### Template variables
+//!
+//! Diagrams, inline and included, can contain `{{name}}` placeholders, replaced when the docs are built:
+//!
+//! ```text
+//! /// ```mermaid
+//! /// graph LR
+//! ///     client --> {{service}}[{{service}} v{{CARGO_PKG_VERSION}}]
+//! /// ```
+//! ```
+//!
+//! | Placeholder            | Value                                                                         |
+//! |------------------------|-------------------------------------------------------------------------------|
+//! | `{{CARGO_*}}`          | the variables cargo sets for the crate, e.g. `{{CARGO_PKG_VERSION}}` and `{{CARGO_PKG_NAME}}` |
+//! | `{{env.NAME}}`         | an environment variable of the build                                          |
+//! | `{{name}}`             | the `vars` option, e.g. `[package.metadata.aquamarine.vars]`, and the values passed to `include_mmd!` |
+//!
+//! Values passed to `include_mmd!` let one file be rendered for several items: `include_mmd!("service.mmd", vars(service = "auth"))`.
+//!
+//! An undefined variable fails the build, pointing at the line it's used on.
+//! A `{{...}}` right after a node id, outside of labels, is a hexagon node: `A{{Decision}}` is left as is, and `A{{service}}` becomes `A{{auth}}`.
+//! The environment variables read by the diagrams are tracked, changing them rebuilds the crate.
+//!
+//! ### Syntax checking
+//!
+//! Flowchart, sequence, class, state and entity relationship diagrams are checked when the docs are built,
+//! so a typo fails the build instead of showing up as a blank box:
+//!
+//! ```text
+//! error: mermaid syntax error: missing closing `]`
+//!   --> src/lib.rs:12:1
+//!    |
+//! 12 | ///     a[Start --> b
+//!    | ^^^^^^^^^^^^^^^^^^^^^
+//! ```
+//!
+//! Errors point at the offending line of the doc comment, or name the file and the line for `include_mmd!`.
+//! The check only reports what mermaid is known to reject, other diagram types are left to mermaid.
+//! It can be turned off with the `validate` option.
+//!
+//! #### Lints
+//!
+//! On top of the syntax, diagrams are checked for common pitfalls, reported as warnings with a suggested fix:
+//!
+//! | Lint                    | Pitfall                                                                   |
+//! |-------------------------|---------------------------------------------------------------------------|
+//! | `reserved_end`          | `end` used as a flowchart node id                                          |
+//! | `undeclared_node`       | link to a node that is never declared with a label, often a typo in the id |
+//! | `duplicate_node`        | node declared twice with different labels                                  |
+//! | `unbalanced_subgraph`   | `subgraph` without `end`, or `end` without `subgraph`                      |
+//! | `unbalanced_activation` | sequence diagram participant deactivated while inactive, or never deactivated |
+//! | `unquoted_label`        | flowchart label with parentheses, which must be quoted                     |
+//!
+//! Lints are turned off with the `allow` option, e.g. `#[aquamarine(allow(undeclared_node, unquoted_label))]`,
+//! or `allow = ["undeclared_node"]` in `[package.metadata.aquamarine]`.
+//! Lints allowed by the macro arguments add up to the crate-wide ones.
+//! The warnings are printed by `cargo doc`, for the documented crate only, and point at the diagram on rustc 1.88 and newer.
+//!
+//! ### Configuration
+//!
+//! The rendering of all diagrams of a documented entity can be tuned with the macro arguments:
+//!
+//! ```no_run
+//! # use aquamarine::aquamarine;
+//! #[aquamarine(theme = "forest", mermaid_version = "11.4", lazy, collapsible)]
+//! /// ```mermaid
+//! /// graph LR
+//! ///     a --> b
+//! /// ```
+//! # fn example() {}
+//! ```
+//!
+//! | Option            | Value   | Description                                                          |
+//! |-------------------|---------|----------------------------------------------------------------------|
+//! | `theme`           | string  | mermaid theme, overrides the automatic light/dark theme selection    |
+//! | `cdn`             | string  | URL of mermaid used when the local copy can't be loaded, `{version}` is substituted |
+//! | `mermaid_version` | string  | mermaid version to load from the default CDN                         |
+//! | `lazy`            | boolean | render diagrams only when they are scrolled into view                |
+//! | `collapsible`     | boolean | hide diagrams in a collapsed `<details>` block                       |
+//! | `font_family`     | string  | font of the diagram text                                             |
+//! | `security_level`  | string  | mermaid `securityLevel`: `strict`, `loose`, `antiscript` or `sandbox` |
+//! | `light_theme`     | string  | mermaid theme for the `light` rustdoc theme, `default` by default      |
+//! | `dark_theme`      | string  | mermaid theme for the `dark` rustdoc theme, `dark` by default          |
+//! | `ayu_theme`       | string  | mermaid theme for the `ayu` rustdoc theme, `dark` by default           |
+//! | `offline`         | boolean | never load mermaid from the CDN, see [Offline mode](#offline-mode)      |
+//! | `integrity`       | string  | SRI hash of the CDN copy or `bundled`, see [Verifying the CDN](#verifying-the-cdn) |
+//! | `mermaid_path`    | string  | mermaid build to use instead of the bundled one, see [Custom mermaid build](#custom-mermaid-build) |
+//! | `icon_packs`      | string  | comma-separated iconify JSON files registered as icon packs, see [Extensions](#extensions) |
+//! | `validate`        | boolean | check the diagram syntax at compile time, `true` by default, see [Syntax checking](#syntax-checking) |
+//! | `allow`           | list    | lints not to report, e.g. `allow(undeclared_node)`, see [Lints](#lints) |
+//! | `vars`            | map     | values of the diagram placeholders, e.g. `vars(service = "auth")`, see [Template variables](#template-variables) |
+//!
+//! Boolean options can be written as a bare flag (`lazy`) or explicitly (`lazy = false`).
+//! List options are written as `allow(a, b)`, as an array in the manifest and comma-separated in the environment.
+//! Map options are written as `vars(a = "b")`, as a table in the manifest and as `a=b,c=d` in the environment.
+//! A diagram's own `%%init%%` annotation still takes priority over these options.
+//!
+//! #### Crate-wide defaults
+//!
+//! Defaults for every `#[aquamarine]` in a crate can be set in the `Cargo.toml`.
+//! In a workspace, the `[workspace.metadata.aquamarine]` table of the workspace root manifest is used for all member crates.
+//!
+//! ```toml
+//! [package.metadata.aquamarine]
+//! theme = "neutral"
+//! font-family = "Fira Sans, sans-serif"
+//! security-level = "strict"
+//! ```
+//!
+//! Option names in the manifest can be spelled with either dashes or underscores.
+//!
+//! On top of the manifest, every option can be overridden with an `AQUAMARINE_<OPTION>` environment variable, e.g. `AQUAMARINE_THEME=dark cargo doc`.
+//! The variables and the manifests are build inputs of the items rendering diagrams, so changing them rebuilds the crate.
+//! Stable `cargo doc` only tracks the files of the crate itself though: after changing the variables or the workspace manifest, run `cargo clean --doc`, or use `cargo +nightly doc -Zrustdoc-depinfo`.
+//!
+//! The options are resolved in the following order, from the highest to the lowest priority:
+//! 1. diagram's own `%%init%%` annotation or frontmatter
+//! 2. `#[aquamarine(...)]` arguments
+//! 3. `AQUAMARINE_*` environment variables
+//! 4. `[package.metadata.aquamarine]`
+//! 5. `[workspace.metadata.aquamarine]`
 
 extern crate proc_macro;
 
 use proc_macro::TokenStream;
-use proc_macro_error2::{abort, proc_macro_error};
+use proc_macro_error2::{abort, abort_call_site, proc_macro_error};
 
-use quote::quote;
+use quote::{quote, ToTokens};
 use syn::{parse_macro_input, Attribute};
 
 mod attrs;
+mod bundle;
+mod config;
+mod docs_dir;
+mod expand;
+mod highlight;
+mod include;
+mod lint;
 mod parse;
+mod registry;
+mod template;
+mod validate;
 
 /// Aquamarine is a proc-macro that adds [Mermaid](https://mermaid-js.github.io/mermaid/#/) diagrams to rustdoc
 ///
@@ -94,15 +400,34 @@ mod parse;
 /// /// ```
 /// struct Foo;
 /// ```
+///
+/// The rendering can be tuned with arguments, see the [crate-level docs](crate#configuration):
+///
+/// ```rust
+/// # use aquamarine::aquamarine;
+/// #[aquamarine(theme = "forest", lazy, collapsible)]
+/// /// ```mermaid
+/// ///   --- here goes your mermaid diagram ---
+/// /// ```
+/// struct Foo;
+/// ```
 #[proc_macro_attribute]
 #[proc_macro_error]
-pub fn aquamarine(_args: TokenStream, input: TokenStream) -> TokenStream {
+pub fn aquamarine(args: TokenStream, input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(args as parse::Args);
     let input = parse_macro_input!(input as parse::Input);
 
     check_input_attrs(&input.attrs);
 
-    let attrs = attrs::Attrs::from(input.attrs);
-    let forward = input.rest;
+    let defaults = config::Config::crate_defaults().unwrap_or_else(|e| abort_call_site!(e));
+    let config = args.config.or(defaults);
+    config.validate().unwrap_or_else(|e| abort_call_site!(e));
+    let attrs = attrs::Attrs::new(config.clone(), input.attrs);
+    let mut inputs = expand::Inputs::default();
+    inputs.add(&attrs);
+    // the item's own docs go first, the diagrams they define are used by the nested docs
+    let attrs = attrs.into_token_stream();
+    let forward = expand::nested(&config, input.rest, inputs);
 
     let tokens = quote! {
         #attrs
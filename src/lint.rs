@@ -0,0 +1,516 @@
+use crate::validate::{first_word, is_sequence_keyword, Diagram, DiagramType, Message};
+use std::collections::{BTreeMap, HashMap};
+
+/// Names of the lints, as accepted by the `allow` option
+pub const LINTS: &[&str] = &[
+    "reserved_end",
+    "undeclared_node",
+    "duplicate_node",
+    "unbalanced_subgraph",
+    "unbalanced_activation",
+    "unquoted_label",
+];
+
+/// Likely mistake in a diagram, one that mermaid accepts or reports unhelpfully
+#[derive(Debug, PartialEq)]
+pub struct Warning {
+    /// Index of the offending line
+    pub line: usize,
+    pub lint: &'static str,
+    pub message: String,
+    /// Suggested fix
+    pub help: String,
+}
+
+impl Warning {
+    fn new(line: usize, lint: &'static str, message: String, help: String) -> Warning {
+        Warning {
+            line,
+            lint,
+            message,
+            help,
+        }
+    }
+
+    /// The warning in the format of the compiler diagnostics
+    pub fn render(&self, location: &str) -> String {
+        format!(
+            "warning: {}\n  --> {}\n   = help: {}\n   = note: `#[aquamarine(allow({}))]` to allow it",
+            self.message, location, self.help, self.lint
+        )
+    }
+}
+
+/// Run the lints on a diagram, except for the `allowed` ones
+pub fn check<S: AsRef<str>>(lines: &[S], allowed: &[String]) -> Vec<Warning> {
+    let diagram = match Diagram::parse(lines, &mut Vec::new()) {
+        Some(diagram) => diagram,
+        None => return Vec::new(),
+    };
+
+    let mut warnings = Vec::new();
+    match diagram.kind {
+        DiagramType::Flowchart => flowchart(&diagram.statements, &mut warnings),
+        DiagramType::Sequence => sequence(&diagram.statements, &mut warnings),
+        _ => (),
+    }
+    warnings.retain(|warning| !allowed.iter().any(|lint| lint == warning.lint));
+    warnings.sort_by_key(|warning| warning.line);
+    warnings
+}
+
+/// Node of a flowchart statement, with the shape and label it's declared with, if any
+struct Node<'a> {
+    id: &'a str,
+    label: Option<&'a str>,
+}
+
+pub fn is_id_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn is_link_char(c: char) -> bool {
+    matches!(c, '-' | '=' | '.' | '<' | '>' | '~')
+}
+
+/// Length of the node id at the start of `s`, dashes are allowed in between
+fn id_len(s: &str) -> usize {
+    let mut chars = s.char_indices().peekable();
+    while let Some((at, c)) = chars.next() {
+        let next_is_id = chars.peek().is_some_and(|(_, next)| is_id_char(*next));
+        if !(is_id_char(c) || c == '-' && next_is_id) {
+            return at;
+        }
+    }
+    s.len()
+}
+
+/// Length of the bracketed shape at the start of `s`, `None` if it isn't closed
+fn shape_len(s: &str) -> Option<usize> {
+    let mut closers = Vec::new();
+    let mut in_string = false;
+    for (at, c) in s.char_indices() {
+        if in_string {
+            in_string = c != '"';
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '[' => closers.push(']'),
+            '(' => closers.push(')'),
+            '{' => closers.push('}'),
+            '>' if at == 0 => closers.push(']'),
+            ']' | ')' | '}' if closers.pop() != Some(c) => return None,
+            _ => (),
+        }
+        if closers.is_empty() {
+            return Some(at + c.len_utf8());
+        }
+    }
+    None
+}
+
+/// Nodes of a flowchart statement, in the order of appearance
+fn nodes(statement: &str) -> Vec<Node<'_>> {
+    let mut nodes = Vec::new();
+    let mut rest = statement;
+    while let Some(c) = rest.chars().next() {
+        if is_id_char(c) {
+            let (id, after) = rest.split_at(id_len(rest));
+            rest = after;
+            if let Some(after) = rest.strip_prefix(":::") {
+                rest = &after[id_len(after)..];
+            }
+            // the id of a link, `e1@-->`
+            if rest.starts_with('@') && !rest.starts_with("@{") {
+                rest = &rest[1..];
+                continue;
+            }
+            let shape = rest.trim_start_matches('@');
+            let label = if shape.starts_with(['[', '(', '{', '>'].as_ref()) {
+                let (label, after) = shape.split_at(shape_len(shape).unwrap_or(shape.len()));
+                rest = after;
+                Some(label)
+            } else {
+                None
+            };
+            if let Some(after) = rest.strip_prefix(":::") {
+                rest = &after[id_len(after)..];
+            }
+            nodes.push(Node { id, label });
+        } else if is_link_char(c) {
+            let len = rest.find(|c| !is_link_char(c)).unwrap_or(rest.len());
+            let (link, after) = rest.split_at(len);
+            rest = after;
+            // `A --o B` and `A --x B`
+            let head = rest.strip_prefix(['o', 'x'].as_ref()).filter(|after| {
+                link.ends_with(['-', '='].as_ref()) && !after.starts_with(is_id_char)
+            });
+            if let Some(after) = head {
+                rest = after;
+            } else if matches!(link, "--" | "==" | "-.") {
+                // the text of a link, `A -- text --> B`
+                let end = rest
+                    .char_indices()
+                    .zip(rest.chars().skip(1))
+                    .find(|((_, c), next)| is_link_char(*c) && is_link_char(*next))
+                    .map(|((at, _), _)| at);
+                if let Some(end) = end {
+                    let after = &rest[end..];
+                    rest = &after[after.find(|c| !is_link_char(c)).unwrap_or(after.len())..];
+                }
+            }
+        } else if c == '|' || c == '"' {
+            // the text of a link, `A -->|text| B` or `A -. "text" .-> B`
+            let after = &rest[1..];
+            rest = after.find(c).map_or("", |end| &after[end + 1..]);
+        } else {
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    nodes
+}
+
+/// Text of a node label without the shape delimiters, `Start` of `[(Start)]`
+fn label_text(label: &str) -> (&str, &str, &str) {
+    let len = label
+        .chars()
+        .take(3)
+        .take_while(|c| "[({/\\>".contains(*c))
+        .count()
+        .min(label.len() / 2);
+    let (opening, rest) = label.split_at(len);
+    let (text, closing) = rest.split_at(rest.len() - len.min(rest.len()));
+    (opening, text, closing)
+}
+
+/// Number of single character edits turning `a` into `b`
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut previous = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous + usize::from(ca != *cb);
+            previous = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(previous + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// The closest of `candidates` to a misspelled `id`
+pub fn similar<'a>(id: &str, candidates: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    candidates
+        .map(|candidate| (edit_distance(id, candidate), candidate))
+        .filter(|(distance, _)| *distance <= 2)
+        .min()
+        .map(|(_, candidate)| candidate)
+}
+
+/// Ids of the nodes and subgraphs of a flowchart
+pub fn flowchart_ids<'a>(statements: &[(usize, &'a str)]) -> Vec<&'a str> {
+    let mut ids = Vec::new();
+    for &(_, line) in statements {
+        match first_word(line) {
+            "subgraph" => {
+                let id = line["subgraph".len()..].trim_start();
+                ids.push(&id[..id_len(id)]);
+            }
+            "end" if line.trim_end_matches(';') == "end" => (),
+            "direction" | "classDef" | "class" | "style" | "linkStyle" | "click" => (),
+            _ => ids.extend(nodes(line).into_iter().map(|node| node.id)),
+        }
+    }
+    ids
+}
+
+fn flowchart(statements: &[(usize, &str)], warnings: &mut Vec<Warning>) {
+    let mut subgraphs = Vec::new();
+    let mut subgraph_ids = Vec::new();
+    let mut declared: HashMap<&str, &str> = HashMap::new();
+    let mut referenced: Vec<(usize, &str)> = Vec::new();
+
+    for &(index, line) in statements {
+        match first_word(line) {
+            "subgraph" => {
+                subgraphs.push(index);
+                let id = line["subgraph".len()..].trim_start();
+                subgraph_ids.push(&id[..id_len(id)]);
+                continue;
+            }
+            "end" if line.trim_end_matches(';') == "end" => {
+                if subgraphs.pop().is_none() {
+                    warnings.push(Warning::new(
+                        index,
+                        "unbalanced_subgraph",
+                        "`end` without an open `subgraph`".to_string(),
+                        "remove it, or open a `subgraph` before".to_string(),
+                    ));
+                }
+                continue;
+            }
+            "direction" | "classDef" | "class" | "style" | "linkStyle" | "click" => continue,
+            _ => (),
+        }
+
+        for node in nodes(line) {
+            if node.id == "end" {
+                warnings.push(Warning::new(
+                    index,
+                    "reserved_end",
+                    "`end` is a reserved word, it breaks the flowchart when used as a node id"
+                        .to_string(),
+                    format!(
+                        "capitalize it, `End{}`, or give the node another id, `end_node{}`",
+                        node.label.unwrap_or_default(),
+                        node.label.unwrap_or("[end]")
+                    ),
+                ));
+            }
+
+            let label = match node.label {
+                Some(label) => label,
+                // `end` is reported as reserved already
+                None if node.id == "end" => continue,
+                None => {
+                    referenced.push((index, node.id));
+                    continue;
+                }
+            };
+
+            let (opening, text, closing) = label_text(label);
+            let text = text.trim();
+            if !text.starts_with('"')
+                && !label.starts_with('{')
+                && text.contains(['(', ')'].as_ref())
+            {
+                warnings.push(Warning::new(
+                    index,
+                    "unquoted_label",
+                    format!("label of `{}` has unquoted parentheses", node.id),
+                    format!(
+                        "quote the label, `{}{}\"{}\"{}`",
+                        node.id, opening, text, closing
+                    ),
+                ));
+            }
+
+            match declared.get(node.id) {
+                Some(first) if first != &label => warnings.push(Warning::new(
+                    index,
+                    "duplicate_node",
+                    format!(
+                        "`{}` is declared again with a different label, it was `{}{}`",
+                        node.id, node.id, first
+                    ),
+                    format!("refer to the declared node by its id alone, `{}`", node.id),
+                )),
+                Some(_) => (),
+                None => {
+                    declared.insert(node.id, label);
+                }
+            }
+        }
+    }
+
+    for index in subgraphs {
+        warnings.push(Warning::new(
+            index,
+            "unbalanced_subgraph",
+            "`subgraph` is never closed".to_string(),
+            "add `end` after its last statement".to_string(),
+        ));
+    }
+
+    // diagrams declaring no labels at all use ids as the labels
+    if declared.is_empty() {
+        return;
+    }
+    let mut reported = Vec::new();
+    for (index, id) in referenced {
+        if declared.contains_key(id) || subgraph_ids.contains(&id) || reported.contains(&id) {
+            continue;
+        }
+        reported.push(id);
+        let help = match similar(id, declared.keys().copied()) {
+            Some(similar) => format!("did you mean `{}`?", similar),
+            None => format!("declare it with a label, e.g. `{}[{}]`", id, id),
+        };
+        warnings.push(Warning::new(
+            index,
+            "undeclared_node",
+            format!("`{}` is never declared, mermaid adds it as a new node", id),
+            help,
+        ));
+    }
+}
+
+/// Lines of the activations of each participant, not deactivated yet
+type Activations<'a> = BTreeMap<&'a str, Vec<usize>>;
+
+fn deactivate(
+    active: &mut Activations,
+    index: usize,
+    participant: &str,
+    warnings: &mut Vec<Warning>,
+) {
+    if active.get_mut(participant).and_then(Vec::pop).is_none() {
+        warnings.push(Warning::new(
+            index,
+            "unbalanced_activation",
+            format!(
+                "`{}` is deactivated while inactive, mermaid fails to render the diagram",
+                participant
+            ),
+            format!(
+                "remove the deactivation, or activate `{}` before with `activate {}`",
+                participant, participant
+            ),
+        ));
+    }
+}
+
+fn sequence(statements: &[(usize, &str)], warnings: &mut Vec<Warning>) {
+    let mut active = Activations::new();
+    for &(index, line) in statements {
+        let keyword = first_word(line);
+        let participant = line[keyword.len()..].trim();
+        match keyword {
+            "activate" => active.entry(participant).or_default().push(index),
+            "deactivate" => deactivate(&mut active, index, participant, warnings),
+            _ if is_sequence_keyword(keyword) => (),
+            _ => match Message::parse(line) {
+                Some(message) if message.activation == Some('+') => {
+                    active.entry(message.to).or_default().push(index)
+                }
+                Some(message) if message.activation == Some('-') => {
+                    deactivate(&mut active, index, message.from, warnings)
+                }
+                _ => (),
+            },
+        }
+    }
+
+    for (participant, lines) in active {
+        for index in lines {
+            warnings.push(Warning::new(
+                index,
+                "unbalanced_activation",
+                format!("`{}` is activated, but never deactivated", participant),
+                format!(
+                    "add `deactivate {}`, or `-` to its reply, e.g. `{}-->>-Other: reply`",
+                    participant, participant
+                ),
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check, label_text, Warning};
+
+    fn warnings(diagram: &str) -> Vec<(usize, &'static str)> {
+        let lines: Vec<&str> = diagram.lines().collect();
+        check(&lines, &[])
+            .into_iter()
+            .map(|Warning { line, lint, .. }| (line, lint))
+            .collect()
+    }
+
+    fn help(diagram: &str) -> String {
+        let lines: Vec<&str> = diagram.lines().collect();
+        check(&lines, &[]).remove(0).help
+    }
+
+    #[test]
+    fn clean_flowchart() {
+        let diagram = r#"
+            graph LR
+                s([Source]) --> a[[aquamarine]]
+                r[[rustdoc]] --> f([Docs w/ Mermaid!])
+                subgraph rustc[Rust Compiler]
+                a -. "inject mermaid.js" .-> r
+                end
+                a -- text --> s & f
+                r -->|"label (quoted)"| q["ok (quoted)"]
+                q --o rustc
+                id1[One]:::green --> q
+                e1@--> f
+        "#;
+        assert_eq!(warnings(diagram), []);
+        assert_eq!(warnings("graph TD\n  A-->B\n  B-->C"), []);
+    }
+
+    #[test]
+    fn flowchart_pitfalls() {
+        let diagram = "graph LR\n  start[Start] --> end\n  start --> stat\n  start(Begin)\n  x[call (f)] --> start\n  end\n  subgraph s";
+        assert_eq!(
+            warnings(diagram),
+            [
+                (1, "reserved_end"),
+                (2, "undeclared_node"),
+                (3, "duplicate_node"),
+                (4, "unquoted_label"),
+                (5, "unbalanced_subgraph"),
+                (6, "unbalanced_subgraph"),
+            ]
+        );
+        assert_eq!(
+            help("graph LR\n  a[call (f)]"),
+            r#"quote the label, `a["call (f)"]`"#
+        );
+        assert_eq!(
+            help("graph LR\n  start[Start]\n  stat --> b[B]"),
+            "did you mean `start`?"
+        );
+    }
+
+    #[test]
+    fn comments_before_the_header() {
+        // as at the top of a `.mmd` file
+        let diagram =
+            "%% Pipeline\n%%{init: {\"theme\": \"forest\"}}%%\ngraph LR\n  start[Start] --> stat";
+        assert_eq!(warnings(diagram), [(3, "undeclared_node")]);
+    }
+
+    #[test]
+    fn sequence_activations() {
+        let balanced = "sequenceDiagram\n  A->>+B: hi\n  B-->>-A: hi\n  activate A\n  deactivate A";
+        assert_eq!(warnings(balanced), []);
+
+        let unbalanced = "sequenceDiagram\n  A->>+B: hi\n  deactivate A\n  activate C";
+        assert_eq!(
+            warnings(unbalanced),
+            [
+                (1, "unbalanced_activation"),
+                (2, "unbalanced_activation"),
+                (3, "unbalanced_activation"),
+            ]
+        );
+    }
+
+    #[test]
+    fn allowed() {
+        let lines = ["graph LR", "a[x] --> b", "end"];
+        let allowed = ["undeclared_node".to_string()];
+        let warnings = check(&lines, &allowed);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].lint, "unbalanced_subgraph");
+        assert_eq!(
+            warnings[0].render("src/lib.rs:3:1"),
+            "warning: `end` without an open `subgraph`\n  --> src/lib.rs:3:1\n   \
+             = help: remove it, or open a `subgraph` before\n   \
+             = note: `#[aquamarine(allow(unbalanced_subgraph))]` to allow it"
+        );
+    }
+
+    #[test]
+    fn labels() {
+        assert_eq!(label_text("[(db)]"), ("[(", "db", ")]"));
+        assert_eq!(label_text(">flag]"), (">", "flag", "]"));
+        assert_eq!(label_text("[x (y)]"), ("[", "x (y)", "]"));
+    }
+}
@@ -1,8 +1,11 @@
+use crate::config::{Config, Value};
 use proc_macro2::TokenStream;
+use std::collections::BTreeMap;
 use syn::{
-    self,
+    self, parenthesized,
     parse::{Parse, ParseStream},
-    Attribute,
+    punctuated::Punctuated,
+    Attribute, Ident, Lit, Token,
 };
 
 pub struct Input {
@@ -17,3 +20,180 @@ impl Parse for Input {
         Ok(Input { attrs, rest })
     }
 }
+
+/// Arguments of the `#[aquamarine(...)]` attribute
+pub struct Args {
+    pub config: Config,
+}
+
+impl Parse for Args {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut config = Config::default();
+        let options = Punctuated::<Opt, Token![,]>::parse_terminated(input)?;
+        let mut seen = Vec::new();
+        for Opt { key, value } in options {
+            if seen.contains(&key) {
+                return Err(syn::Error::new(
+                    key.span(),
+                    format!("`{}` is given twice", key),
+                ));
+            }
+            seen.push(key.clone());
+            config
+                .set(&key.to_string(), value)
+                .map_err(|e| syn::Error::new(key.span(), e))?;
+        }
+        Ok(Args { config })
+    }
+}
+
+/// Entries of `vars(name = "value", ...)`, numbers and booleans are taken as written
+pub struct Vars(pub BTreeMap<String, String>);
+
+impl Parse for Vars {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut vars = BTreeMap::new();
+        while !input.is_empty() {
+            let name: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+            let value = match input.parse()? {
+                Lit::Str(s) => s.value(),
+                Lit::Int(i) => i.base10_digits().to_string(),
+                Lit::Float(f) => f.base10_digits().to_string(),
+                Lit::Bool(b) => b.value.to_string(),
+                lit => {
+                    return Err(syn::Error::new(
+                        lit.span(),
+                        "expected a string, a number or a boolean literal",
+                    ))
+                }
+            };
+            if vars.insert(name.to_string(), value).is_some() {
+                return Err(syn::Error::new(
+                    name.span(),
+                    format!("`{}` is given twice", name),
+                ));
+            }
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+        Ok(Vars(vars))
+    }
+}
+
+/// A single `key = value`, `flag`, `list(item, ...)` or `map(name = value, ...)` argument
+struct Opt {
+    key: Ident,
+    value: Value,
+}
+
+impl Parse for Opt {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let key: Ident = input.parse()?;
+        if input.peek(syn::token::Paren) {
+            let content;
+            parenthesized!(content in input);
+            if content.peek2(Token![=]) {
+                return Ok(Opt {
+                    key,
+                    value: Value::Map(content.parse::<Vars>()?.0),
+                });
+            }
+            let items = Punctuated::<Ident, Token![,]>::parse_terminated(&content)?;
+            return Ok(Opt {
+                key,
+                value: Value::List(items.iter().map(Ident::to_string).collect()),
+            });
+        }
+        if !input.peek(Token![=]) {
+            return Ok(Opt {
+                key,
+                value: Value::Bool(true),
+            });
+        }
+
+        input.parse::<Token![=]>()?;
+        let value = match input.parse()? {
+            Lit::Str(s) => Value::Str(s.value()),
+            Lit::Bool(b) => Value::Bool(b.value),
+            lit => {
+                return Err(syn::Error::new(
+                    lit.span(),
+                    "expected a string or a boolean literal",
+                ))
+            }
+        };
+        Ok(Opt { key, value })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Args;
+
+    fn parse(args: &str) -> syn::Result<Args> {
+        syn::parse_str(args)
+    }
+
+    #[test]
+    fn empty() {
+        let args = parse("").unwrap();
+        assert_eq!(args.config, Default::default());
+    }
+
+    #[test]
+    fn options_and_flags() {
+        let args =
+            parse(r#"theme = "forest", cdn = "https://cdn/m.mjs", lazy, collapsible = false"#)
+                .unwrap();
+        assert_eq!(args.config.theme.as_deref(), Some("forest"));
+        assert_eq!(args.config.cdn.as_deref(), Some("https://cdn/m.mjs"));
+        assert_eq!(args.config.lazy, Some(true));
+        assert_eq!(args.config.collapsible, Some(false));
+    }
+
+    #[test]
+    fn lists() {
+        let args = parse("allow(undeclared_node, unquoted_label), lazy").unwrap();
+        assert_eq!(
+            args.config.allow,
+            Some(vec!["undeclared_node".into(), "unquoted_label".into()])
+        );
+        assert!(parse("allow(no_such_lint)").is_err());
+        assert!(parse(r#"theme("forest")"#).is_err());
+    }
+
+    #[test]
+    fn maps() {
+        let args = parse(r#"vars(service = "auth", port = 8080, tls = true)"#).unwrap();
+        let vars = args.config.vars.unwrap();
+        assert_eq!(vars["service"], "auth");
+        assert_eq!(vars["port"], "8080");
+        assert_eq!(vars["tls"], "true");
+        assert!(parse("vars(service = auth)").is_err());
+        assert!(parse(r#"allow(reserved_end = "yes")"#).is_err());
+    }
+
+    #[test]
+    fn rejects_unknown_and_mistyped() {
+        assert!(parse(r#"colour = "red""#).is_err());
+        assert!(parse("theme").is_err());
+        assert!(parse("lazy = 1").is_err());
+        assert!(parse(r#"mermaid_version = "11" "#).is_ok());
+    }
+
+    #[test]
+    fn rejects_duplicates() {
+        let error = |args: &str| parse(args).err().map(|e| e.to_string());
+        assert_eq!(
+            error(r#"theme = "dark", lazy, theme = "forest""#).as_deref(),
+            Some("`theme` is given twice")
+        );
+        assert_eq!(
+            error(r#"vars(service = "auth", service = "billing")"#).as_deref(),
+            Some("`service` is given twice")
+        );
+        assert!(parse(r#"theme = "dark", vars(theme = "forest")"#).is_ok());
+    }
+}
@@ -0,0 +1,155 @@
+use crate::lint;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+/// Diagrams defined with `define=<name>`, by crate and name
+///
+/// The macro runs inside of the compiler, so the definitions are kept for the items expanded
+/// after them, in the order they appear in the crate. A process may expand several crates,
+/// e.g. an IDE expanding a workspace, each of them has definitions of its own
+static DEFINITIONS: Mutex<BTreeMap<String, BTreeMap<String, Definition>>> =
+    Mutex::new(BTreeMap::new());
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Definition {
+    /// Diagram lines, the placeholders replaced
+    pub lines: Vec<String>,
+    /// `file:line` of the definition
+    pub location: String,
+}
+
+/// Crate being expanded, by its manifest directory and target name
+pub fn current_crate() -> String {
+    let var = |name| std::env::var(name).unwrap_or_default();
+    format!("{}#{}", var("CARGO_MANIFEST_DIR"), var("CARGO_CRATE_NAME"))
+}
+
+/// Register a diagram of `krate` under `name`
+///
+/// The item defining it may be expanded again, e.g. by an IDE,
+/// so a definition at the same location replaces the previous one
+pub fn define(krate: &str, name: &str, definition: Definition) -> Result<(), String> {
+    let mut definitions = DEFINITIONS.lock().unwrap_or_else(|e| e.into_inner());
+    let definitions = definitions.entry(krate.to_string()).or_default();
+    match definitions.get(name) {
+        Some(first) if first.location != definition.location => Err(format!(
+            "diagram `{}` is defined twice, it's defined at {} already",
+            name, first.location
+        )),
+        _ => {
+            definitions.insert(name.to_string(), definition);
+            Ok(())
+        }
+    }
+}
+
+/// Lines of the diagram of `krate` defined under `name`
+pub fn lookup(krate: &str, name: &str) -> Result<Vec<String>, String> {
+    let definitions = DEFINITIONS.lock().unwrap_or_else(|e| e.into_inner());
+    let definitions = definitions.get(krate);
+    let names = definitions
+        .iter()
+        .flat_map(|d| d.keys())
+        .map(String::as_str);
+    match definitions.and_then(|definitions| definitions.get(name)) {
+        Some(definition) => Ok(definition.lines.clone()),
+        None => Err(match lint::similar(name, names) {
+            Some(similar) => format!(
+                "no diagram named `{}` is defined, did you mean `{}`?",
+                name, similar
+            ),
+            None => format!(
+                "no diagram named `{}` is defined, define it with ```` ```mermaid define={} ```` \
+                 before its first use",
+                name, name
+            ),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{define, lookup, Definition};
+
+    const KRATE: &str = "/workspace/core#core";
+
+    fn definition(lines: &[&str], location: &str) -> Definition {
+        Definition {
+            lines: lines.iter().map(|line| line.to_string()).collect(),
+            location: location.to_string(),
+        }
+    }
+
+    // the registry is shared by the tests, each one uses its own names
+    #[test]
+    fn define_and_use() {
+        assert!(lookup(KRATE, "registry_pipeline").is_err());
+        define(
+            KRATE,
+            "registry_pipeline",
+            definition(&["graph LR", "a --> b"], "src/lib.rs:3"),
+        )
+        .unwrap();
+        assert_eq!(
+            lookup(KRATE, "registry_pipeline").unwrap(),
+            ["graph LR", "a --> b"]
+        );
+        assert!(lookup(KRATE, "registry_pipline")
+            .unwrap_err()
+            .contains("did you mean `registry_pipeline`?"));
+    }
+
+    #[test]
+    fn defined_twice() {
+        define(
+            KRATE,
+            "registry_twice",
+            definition(&["graph LR"], "src/lib.rs:3"),
+        )
+        .unwrap();
+        assert_eq!(
+            define(
+                KRATE,
+                "registry_twice",
+                definition(&["graph TD"], "src/a.rs:7")
+            )
+            .unwrap_err(),
+            "diagram `registry_twice` is defined twice, it's defined at src/lib.rs:3 already"
+        );
+        // expanded again
+        define(
+            KRATE,
+            "registry_twice",
+            definition(&["graph RL"], "src/lib.rs:3"),
+        )
+        .unwrap();
+        assert_eq!(lookup(KRATE, "registry_twice").unwrap(), ["graph RL"]);
+    }
+
+    #[test]
+    fn crates_of_a_workspace() {
+        let app = "/workspace/app#app";
+        define(
+            KRATE,
+            "registry_crates",
+            definition(&["graph LR"], "core/src/lib.rs:3"),
+        )
+        .unwrap();
+        define(
+            app,
+            "registry_crates",
+            definition(&["graph TD"], "app/src/lib.rs:3"),
+        )
+        .unwrap();
+        assert_eq!(lookup(KRATE, "registry_crates").unwrap(), ["graph LR"]);
+        assert_eq!(lookup(app, "registry_crates").unwrap(), ["graph TD"]);
+
+        define(
+            KRATE,
+            "registry_core_only",
+            definition(&["graph LR"], "core/src/lib.rs:9"),
+        )
+        .unwrap();
+        assert!(lookup(app, "registry_core_only").is_err());
+    }
+}
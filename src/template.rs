@@ -0,0 +1,194 @@
+use crate::lint;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+const ENV_PREFIX: &str = "env.";
+const CARGO_PREFIX: &str = "CARGO_";
+
+/// Whether `name` can be used as a variable, `service` or `api_v2`
+pub fn is_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Replace the `{{name}}` placeholders of a diagram line
+///
+/// `{{env.NAME}}` and `{{CARGO_*}}` are read from the environment of the compilation,
+/// the rest from `vars`. A `{{...}}` right after a node id, outside of labels, is a hexagon node, `A{{Decision}}`:
+/// it's left as is, or keeps its braces around the value of a defined variable, `A{{auth}}`
+pub fn expand<'a>(line: &'a str, vars: &BTreeMap<String, String>) -> Result<Cow<'a, str>, String> {
+    if !line.contains("{{") {
+        return Ok(Cow::Borrowed(line));
+    }
+
+    let mut out = String::with_capacity(line.len());
+    let mut rest = line;
+    while let Some(start) = rest.find("{{") {
+        let (before, placeholder) = rest.split_at(start);
+        out.push_str(before);
+        let end = match placeholder.find("}}") {
+            Some(end) => end + 2,
+            None => {
+                rest = placeholder;
+                break;
+            }
+        };
+        let name = placeholder[2..end - 2].trim();
+        rest = &placeholder[end..];
+
+        let after_id = out.chars().last().is_some_and(lint::is_id_char) && !in_label(&out);
+        match lookup(name, vars)? {
+            Some(value) if after_id => {
+                out.push_str("{{");
+                out.push_str(&value);
+                out.push_str("}}");
+            }
+            Some(value) => out.push_str(&value),
+            None if after_id || !is_name(name) => out.push_str(&placeholder[..end]),
+            None => return Err(undefined(name, vars)),
+        }
+    }
+    out.push_str(rest);
+    Ok(Cow::Owned(out))
+}
+
+/// Whether the end of `line` is inside of a node label, `A[text` or `A["text`
+fn in_label(line: &str) -> bool {
+    let mut depth = 0usize;
+    let mut quoted = false;
+    for c in line.chars() {
+        match c {
+            '"' => quoted = !quoted,
+            '[' | '(' | '{' if !quoted => depth += 1,
+            ']' | ')' | '}' if !quoted => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+    quoted || depth > 0
+}
+
+/// Environment variables read by the placeholders of a diagram line
+pub fn env_vars(line: &str) -> impl Iterator<Item = &str> {
+    line.split("{{")
+        .skip(1)
+        .filter_map(|rest| rest.find("}}").map(|end| rest[..end].trim()))
+        .filter_map(|name| match name.strip_prefix(ENV_PREFIX) {
+            Some(var) => Some(var),
+            None if name.starts_with(CARGO_PREFIX) && is_name(name) => Some(name),
+            None => None,
+        })
+}
+
+fn lookup(name: &str, vars: &BTreeMap<String, String>) -> Result<Option<String>, String> {
+    if let Some(var) = name.strip_prefix(ENV_PREFIX) {
+        return std::env::var(var).map(Some).map_err(|_| {
+            format!(
+                "`{{{{{}}}}}`: environment variable `{}` is not set",
+                name, var
+            )
+        });
+    }
+    if name.starts_with(CARGO_PREFIX) && is_name(name) {
+        return std::env::var(name)
+            .map(Some)
+            .map_err(|_| format!("`{{{{{}}}}}` is not set by cargo", name));
+    }
+    Ok(vars.get(name).cloned())
+}
+
+fn undefined(name: &str, vars: &BTreeMap<String, String>) -> String {
+    match lint::similar(name, vars.keys().map(String::as_str)) {
+        Some(similar) => format!(
+            "undefined variable `{{{{{}}}}}`, did you mean `{}`?",
+            name, similar
+        ),
+        None => format!(
+            "undefined variable `{{{{{}}}}}`, define it in `[package.metadata.aquamarine.vars]` \
+             or with `vars({} = \"...\")`",
+            name, name
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{env_vars, expand, is_name};
+    use std::collections::BTreeMap;
+
+    fn vars() -> BTreeMap<String, String> {
+        vec![("service", "auth"), ("db", "postgres")]
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn names() {
+        assert!(is_name("service"));
+        assert!(is_name("_api_v2"));
+        assert!(!is_name("2fa"));
+        assert!(!is_name("is it?"));
+        assert!(!is_name(""));
+    }
+
+    #[test]
+    fn placeholders() {
+        let vars = vars();
+        assert_eq!(
+            expand("{{service}} --> {{ db }}[({{db}})]", &vars).unwrap(),
+            "auth --> postgres[(postgres)]"
+        );
+        assert_eq!(
+            expand("A[v{{CARGO_PKG_VERSION}}]", &vars).unwrap(),
+            format!("A[v{}]", env!("CARGO_PKG_VERSION"))
+        );
+        assert_eq!(
+            expand("{{env.CARGO_PKG_NAME}}", &vars).unwrap(),
+            env!("CARGO_PKG_NAME")
+        );
+        assert_eq!(expand("a --> b", &vars).unwrap(), "a --> b");
+        assert_eq!(expand("a --> {{b", &vars).unwrap(), "a --> {{b");
+    }
+
+    #[test]
+    fn hexagons() {
+        let vars = vars();
+        assert_eq!(expand("A{{Decision}}", &vars).unwrap(), "A{{Decision}}");
+        assert_eq!(expand("A{{Is it ok?}}", &vars).unwrap(), "A{{Is it ok?}}");
+        assert_eq!(expand("A{{service}}", &vars).unwrap(), "A{{auth}}");
+        assert_eq!(
+            expand("A{{ service }} --> {{db}}", &vars).unwrap(),
+            "A{{auth}} --> postgres"
+        );
+        assert_eq!(
+            expand(r#"A["v{{service}}"] --> B(x{{db}})"#, &vars).unwrap(),
+            r#"A["vauth"] --> B(xpostgres)"#
+        );
+    }
+
+    #[test]
+    fn environment() {
+        let line =
+            "{{env.HOME}} --> A{{ CARGO_PKG_NAME }} --> {{service}} --> {{CARGO_}}{{env.PATH";
+        assert_eq!(
+            env_vars(line).collect::<Vec<_>>(),
+            ["HOME", "CARGO_PKG_NAME", "CARGO_"]
+        );
+        assert_eq!(env_vars("A{{Decision}}").count(), 0);
+    }
+
+    #[test]
+    fn undefined() {
+        let vars = vars();
+        assert_eq!(
+            expand("{{servce}} --> db", &vars).unwrap_err(),
+            "undefined variable `{{servce}}`, did you mean `service`?"
+        );
+        assert!(expand("{{region}}", &vars).is_err());
+        assert!(expand("{{env.AQUAMARINE_SURELY_UNSET}}", &vars).is_err());
+        assert!(expand("{{CARGO_SURELY_UNSET}}", &vars).is_err());
+    }
+}
@@ -0,0 +1,723 @@
+/// Syntax error in a diagram
+#[derive(Debug, PartialEq)]
+pub struct Error {
+    /// Index of the offending line
+    pub line: usize,
+    pub message: String,
+}
+
+impl Error {
+    fn new(line: usize, message: impl Into<String>) -> Error {
+        Error {
+            line,
+            message: message.into(),
+        }
+    }
+}
+
+const FLOWCHART_DIRECTIONS: &[&str] = &["TB", "TD", "BT", "RL", "LR"];
+const SEQUENCE_BLOCKS: &[&str] = &[
+    "loop", "alt", "opt", "par", "critical", "break", "rect", "box",
+];
+const SEQUENCE_KEYWORDS: &[&str] = &[
+    "participant",
+    "actor",
+    "create",
+    "destroy",
+    "autonumber",
+    "activate",
+    "deactivate",
+    "note",
+    "else",
+    "and",
+    "option",
+    "end",
+    "title",
+    "link",
+    "links",
+    "properties",
+    "details",
+];
+const CLASS_RELATIONS: &[&str] = &[
+    "<|--", "--|>", "<|..", "..|>", "*--", "--*", "o--", "--o", "<--", "-->", "<..", "..>", "--",
+    "..",
+];
+const STATE_STEREOTYPES: &[&str] = &["<<fork>>", "<<join>>", "<<choice>>"];
+const ER_LEFT_CARDINALITIES: &[&str] = &["|o", "||", "}o", "}|"];
+const ER_RIGHT_CARDINALITIES: &[&str] = &["o|", "||", "o{", "|{"];
+
+/// Check the syntax of the commonly used diagram types:
+/// flowchart, sequence, class, state and entity relationship diagrams
+///
+/// The check is conservative: only constructs mermaid is known to reject are reported,
+/// other diagram types are accepted as is
+pub fn check<S: AsRef<str>>(lines: &[S]) -> Vec<Error> {
+    let mut errors = Vec::new();
+    let diagram = match Diagram::parse(lines, &mut errors) {
+        Some(diagram) => diagram,
+        None => return errors,
+    };
+    let statements = diagram.statements.as_slice();
+
+    match diagram.kind {
+        DiagramType::Flowchart => {
+            let (header_line, header) = diagram.header;
+            let direction = header
+                .split(|c: char| c.is_whitespace() || c == ';')
+                .skip(1)
+                .find(|word| !word.is_empty());
+            if let Some(direction) = direction {
+                if !FLOWCHART_DIRECTIONS.contains(&direction) {
+                    errors.push(Error::new(
+                        header_line,
+                        format!(
+                            "unknown flowchart direction `{}`, expected one of: {}",
+                            direction,
+                            FLOWCHART_DIRECTIONS.join(", ")
+                        ),
+                    ));
+                }
+            }
+            flowchart(statements, &mut errors)
+        }
+        DiagramType::Sequence => sequence(statements, &mut errors),
+        DiagramType::Class => class(statements, &mut errors),
+        DiagramType::State => state(statements, &mut errors),
+        DiagramType::Er => er(statements, &mut errors),
+        DiagramType::Other => (),
+    }
+
+    errors.sort_by_key(|error| error.line);
+    errors
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DiagramType {
+    Flowchart,
+    Sequence,
+    Class,
+    State,
+    Er,
+    Other,
+}
+
+/// Diagram split into statements, with the line indices
+pub struct Diagram<'a> {
+    pub kind: DiagramType,
+    /// The first statement, naming the diagram type
+    pub header: (usize, &'a str),
+    pub statements: Vec<(usize, &'a str)>,
+}
+
+impl<'a> Diagram<'a> {
+    /// `None` for an empty diagram
+    pub fn parse<S: AsRef<str>>(lines: &'a [S], errors: &mut Vec<Error>) -> Option<Diagram<'a>> {
+        let mut statements = statements(lines, errors);
+        if statements.is_empty() {
+            return None;
+        }
+        let header = statements.remove(0);
+        let kind = match first_word(header.1) {
+            "graph" | "flowchart" | "flowchart-elk" => DiagramType::Flowchart,
+            "sequenceDiagram" => DiagramType::Sequence,
+            "classDiagram" | "classDiagram-v2" => DiagramType::Class,
+            "stateDiagram" | "stateDiagram-v2" => DiagramType::State,
+            "erDiagram" => DiagramType::Er,
+            _ => DiagramType::Other,
+        };
+        Some(Diagram {
+            kind,
+            header,
+            statements,
+        })
+    }
+}
+
+/// Non-empty lines, without the frontmatter, comments, directives and accessibility descriptions
+fn statements<'a, S: AsRef<str>>(lines: &'a [S], errors: &mut Vec<Error>) -> Vec<(usize, &'a str)> {
+    let mut lines = lines
+        .iter()
+        .map(|line| line.as_ref().trim())
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .peekable();
+
+    // the frontmatter comes first, comments and directives may precede the header
+    if let Some((index, _)) = lines.next_if(|(_, line)| *line == "---") {
+        if !lines.by_ref().any(|(_, line)| line == "---") {
+            errors.push(Error::new(index, "frontmatter is not closed with `---`"));
+        }
+    }
+
+    let mut statements = Vec::new();
+    while let Some((index, line)) = lines.next() {
+        if line.starts_with("%%") || line.starts_with("accTitle") {
+            continue;
+        }
+        if line.starts_with("accDescr") {
+            if line.ends_with('{') && !lines.by_ref().any(|(_, line)| line.ends_with('}')) {
+                errors.push(Error::new(index, "`accDescr` block is not closed with `}`"));
+            }
+            continue;
+        }
+        statements.push((index, line));
+    }
+    statements
+}
+
+/// Blocks opened by a statement and closed by another one, e.g. `subgraph` and `end`
+struct Blocks<'a> {
+    open: Vec<(usize, &'a str)>,
+}
+
+impl<'a> Blocks<'a> {
+    fn new() -> Self {
+        Blocks { open: Vec::new() }
+    }
+
+    fn open(&mut self, line: usize, name: &'a str) {
+        self.open.push((line, name))
+    }
+
+    fn innermost(&self) -> Option<&'a str> {
+        self.open.last().map(|(_, name)| *name)
+    }
+
+    fn close(&mut self, line: usize, closing: &str, errors: &mut Vec<Error>) {
+        if self.open.pop().is_none() {
+            errors.push(Error::new(
+                line,
+                format!("`{}` without an open block", closing),
+            ));
+        }
+    }
+
+    fn finish(self, closing: &str, errors: &mut Vec<Error>) {
+        for (line, name) in self.open {
+            errors.push(Error::new(
+                line,
+                format!("`{}` is not closed with `{}`", name, closing),
+            ));
+        }
+    }
+}
+
+/// The statement with the labels, strings and bracketed node shapes removed
+///
+/// Flowcharts also have link labels between pipes and `>` opening an asymmetric shape
+fn skeleton(line: &str, flowchart: bool) -> Result<String, String> {
+    let mut out = String::with_capacity(line.len());
+    let mut closers = Vec::new();
+    let mut in_string = false;
+    let mut in_label = false;
+
+    for c in line.chars() {
+        if in_string {
+            in_string = c != '"';
+            continue;
+        }
+        let is_nested = !closers.is_empty() || in_label;
+        match c {
+            '"' => in_string = true,
+            '|' if flowchart && closers.is_empty() => in_label = !in_label,
+            _ if in_label => (),
+            '[' => closers.push(']'),
+            '(' => closers.push(')'),
+            '{' => closers.push('}'),
+            '>' if flowchart
+                && !is_nested
+                && out.ends_with(|c: char| c.is_alphanumeric() || c == '_') =>
+            {
+                closers.push(']')
+            }
+            ']' | ')' | '}' => match closers.pop() {
+                Some(expected) if expected == c => (),
+                Some(expected) => return Err(format!("expected `{}`, found `{}`", expected, c)),
+                None => return Err(format!("unmatched `{}`", c)),
+            },
+            _ if is_nested => (),
+            c => out.push(c),
+        }
+    }
+
+    if in_string {
+        Err("unterminated string".to_string())
+    } else if in_label {
+        Err("unterminated link label, `|` is not closed".to_string())
+    } else if let Some(closer) = closers.pop() {
+        Err(format!("missing closing `{}`", closer))
+    } else {
+        Ok(out)
+    }
+}
+
+pub fn first_word(line: &str) -> &str {
+    line.split(|c: char| c.is_whitespace() || c == ':' || c == ';')
+        .next()
+        .unwrap_or_default()
+}
+
+/// Report a link with a missing side, `A -->` or `--> B`
+fn check_link_sides(line: usize, lhs: &str, rhs: &str, errors: &mut Vec<Error>) {
+    if lhs.trim().is_empty() {
+        errors.push(Error::new(line, "link without a source"));
+    } else if rhs.trim().is_empty() {
+        errors.push(Error::new(line, "link without a target"));
+    }
+}
+
+/// `subgraph` and `end` pairs are left to the lints
+fn flowchart(statements: &[(usize, &str)], errors: &mut Vec<Error>) {
+    for &(index, line) in statements {
+        match first_word(line) {
+            "subgraph" | "end" | "direction" | "classDef" | "class" | "style" | "linkStyle"
+            | "click" => (),
+            _ => match skeleton(line, true) {
+                Ok(skeleton) => {
+                    for statement in skeleton.split(';').map(str::trim) {
+                        if statement.starts_with(['-', '=', '~', '<'].as_ref()) {
+                            errors.push(Error::new(index, "link without a source node"));
+                        } else if statement.ends_with(['-', '>', '=', '~'].as_ref())
+                            || statement.ends_with("--o")
+                            || statement.ends_with("--x")
+                        {
+                            errors.push(Error::new(index, "link without a target node"));
+                        }
+                    }
+                }
+                Err(e) => errors.push(Error::new(index, e)),
+            },
+        }
+    }
+}
+
+/// Statement keyword of a sequence diagram, as opposed to a participant of a message
+pub fn is_sequence_keyword(word: &str) -> bool {
+    SEQUENCE_BLOCKS.contains(&word)
+        || SEQUENCE_KEYWORDS.contains(&word.to_ascii_lowercase().as_str())
+        || word.starts_with("acc")
+}
+
+/// Message of a sequence diagram, `Alice->>+Bob: Hello`
+pub struct Message<'a> {
+    pub from: &'a str,
+    pub to: &'a str,
+    /// `+` activating the target, or `-` deactivating the source
+    pub activation: Option<char>,
+}
+
+impl<'a> Message<'a> {
+    /// `None` when the statement has no arrow
+    pub fn parse(statement: &'a str) -> Option<Message<'a>> {
+        // longer arrows first, so that `->>` isn't taken for `->`
+        const ARROWS: &[&str] = &[
+            "<<-->>", "<<->>", "-->>", "->>", "-->", "->", "--x", "-x", "--)", "-)",
+        ];
+
+        let signal = statement.split(':').next().unwrap_or_default();
+        let (at, arrow) = ARROWS
+            .iter()
+            .filter_map(|arrow| signal.find(arrow).map(|at| (at, *arrow)))
+            .min_by_key(|(at, arrow)| (*at, usize::MAX - arrow.len()))?;
+        let target = signal[at + arrow.len()..].trim_start();
+        let activation = target.chars().next().filter(|c| *c == '+' || *c == '-');
+        Some(Message {
+            from: signal[..at].trim(),
+            to: target.trim_start_matches(['+', '-'].as_ref()).trim(),
+            activation,
+        })
+    }
+}
+
+fn sequence(statements: &[(usize, &str)], errors: &mut Vec<Error>) {
+    let mut blocks = Blocks::new();
+    for &(index, line) in statements {
+        let keyword = first_word(line);
+        let expected_block = match keyword {
+            "else" => Some("alt"),
+            "and" => Some("par"),
+            "option" => Some("critical"),
+            _ => None,
+        };
+
+        if SEQUENCE_BLOCKS.contains(&keyword) {
+            blocks.open(index, keyword);
+        } else if keyword == "end" {
+            blocks.close(index, "end", errors);
+        } else if let Some(block) = expected_block {
+            if blocks.innermost() != Some(block) {
+                errors.push(Error::new(
+                    index,
+                    format!("`{}` outside of an `{}` block", keyword, block),
+                ));
+            }
+        } else if keyword.eq_ignore_ascii_case("note") {
+            let rest = line[keyword.len()..].trim_start().to_ascii_lowercase();
+            let positioned = ["left of ", "right of ", "over "]
+                .iter()
+                .any(|position| rest.starts_with(position));
+            if !positioned {
+                errors.push(Error::new(
+                    index,
+                    "a note is placed with `left of`, `right of` or `over`",
+                ));
+            } else if !rest.contains(':') {
+                errors.push(Error::new(index, "a note needs a text after `:`"));
+            }
+        } else if !is_sequence_keyword(keyword) {
+            match Message::parse(line) {
+                Some(message) => check_link_sides(index, message.from, message.to, errors),
+                None => errors.push(Error::new(
+                    index,
+                    "expected a message, e.g. `Alice->>Bob: Hello`, or a statement",
+                )),
+            }
+        }
+    }
+    blocks.finish("end", errors);
+}
+
+fn class(statements: &[(usize, &str)], errors: &mut Vec<Error>) {
+    let mut blocks = Blocks::new();
+    for &(index, line) in statements {
+        if line == "}" {
+            blocks.close(index, "}", errors);
+            continue;
+        }
+        if line.matches('~').count() % 2 != 0 {
+            errors.push(Error::new(index, "generic type is not closed with `~`"));
+            continue;
+        }
+
+        let in_class_body = blocks.innermost() == Some("class");
+        let keyword = first_word(line);
+        let (line, opens) = match line.strip_suffix('{') {
+            Some(line) if !in_class_body => (line, true),
+            _ => (line, false),
+        };
+        if opens {
+            blocks.open(
+                index,
+                if keyword == "namespace" {
+                    "namespace"
+                } else {
+                    "class"
+                },
+            );
+        }
+
+        let skeleton = match skeleton(line, false) {
+            Ok(skeleton) => skeleton,
+            Err(e) => {
+                errors.push(Error::new(index, e));
+                continue;
+            }
+        };
+        if in_class_body {
+            continue;
+        }
+
+        match keyword {
+            "class" | "namespace" | "note" | "direction" | "classDef" | "cssClass" | "click"
+            | "callback" | "link" | "style" => (),
+            _ => {
+                let relation = skeleton.split(':').next().unwrap_or_default();
+                let found = CLASS_RELATIONS
+                    .iter()
+                    .find_map(|op| relation.find(op).map(|at| (at, op.len())));
+                if let Some((at, len)) = found {
+                    check_link_sides(index, &relation[..at], &relation[at + len..], errors);
+                } else if let Some(class) = skeleton.strip_suffix(':') {
+                    if class.trim().is_empty() {
+                        errors.push(Error::new(
+                            index,
+                            "a member needs a class, `Class : member`",
+                        ));
+                    }
+                }
+            }
+        }
+    }
+    blocks.finish("}", errors);
+}
+
+fn state(statements: &[(usize, &str)], errors: &mut Vec<Error>) {
+    let mut blocks = Blocks::new();
+    let mut note = None;
+    for &(index, line) in statements {
+        if note.is_some() {
+            if line.eq_ignore_ascii_case("end note") {
+                note = None;
+            }
+            continue;
+        }
+        if line == "}" {
+            blocks.close(index, "}", errors);
+            continue;
+        }
+
+        match first_word(line) {
+            "note" => {
+                if !line.contains(':') {
+                    note = Some(index);
+                }
+            }
+            "state" => {
+                if let Some(stereotype) = line.find("<<").map(|at| line[at..].trim()) {
+                    if !STATE_STEREOTYPES.contains(&stereotype) {
+                        errors.push(Error::new(
+                            index,
+                            format!(
+                                "unknown state type `{}`, expected one of: {}",
+                                stereotype,
+                                STATE_STEREOTYPES.join(", ")
+                            ),
+                        ));
+                    }
+                }
+                if line.ends_with('{') {
+                    blocks.open(index, "state");
+                }
+                if let Err(e) = skeleton(line.trim_end_matches('{'), false) {
+                    errors.push(Error::new(index, e));
+                }
+            }
+            "direction" | "classDef" | "class" | "style" | "--" => (),
+            _ => {
+                let transition = line.split(':').next().unwrap_or_default();
+                if let Some(at) = transition.find("-->") {
+                    check_link_sides(index, &transition[..at], &transition[at + 3..], errors);
+                }
+            }
+        }
+    }
+    if let Some(index) = note {
+        errors.push(Error::new(index, "note is not closed with `end note`"));
+    }
+    blocks.finish("}", errors);
+}
+
+fn er(statements: &[(usize, &str)], errors: &mut Vec<Error>) {
+    let mut blocks = Blocks::new();
+    for &(index, line) in statements {
+        if line == "}" {
+            blocks.close(index, "}", errors);
+            continue;
+        }
+        if blocks.innermost().is_some() {
+            // `type name [PK|FK|UK] ["comment"]`
+            let definition = line.split('"').next().unwrap_or_default();
+            if definition.split_whitespace().count() < 2 {
+                errors.push(Error::new(
+                    index,
+                    "an attribute needs a type and a name, e.g. `string name`",
+                ));
+            }
+            continue;
+        }
+        if let Some(entity) = line.strip_suffix('{') {
+            if entity.trim().is_empty() {
+                errors.push(Error::new(index, "attributes block without an entity"));
+            }
+            blocks.open(index, "{");
+            continue;
+        }
+
+        let relationship = line.split(':').next().unwrap_or_default();
+        let words: Vec<&str> = relationship.split_whitespace().collect();
+        let spec = words
+            .iter()
+            .position(|word| word.contains("--") || word.contains(".."));
+        let spec = match spec {
+            Some(spec) => spec,
+            // a lone entity, or a relationship in the word form
+            None => continue,
+        };
+
+        let symbols = words[spec];
+        let line_at = symbols
+            .find("--")
+            .or_else(|| symbols.find(".."))
+            .unwrap_or(0);
+        let (left, right) = (&symbols[..line_at], &symbols[line_at + 2..]);
+        if spec == 0 || spec + 1 == words.len() {
+            errors.push(Error::new(
+                index,
+                "relationship needs an entity on both sides",
+            ));
+        } else if !ER_LEFT_CARDINALITIES.contains(&left) || !ER_RIGHT_CARDINALITIES.contains(&right)
+        {
+            errors.push(Error::new(
+                index,
+                format!(
+                    "unknown relationship `{}`, cardinalities are `|o`, `||`, `}}o` or `}}|` \
+                     on the left and their mirrors on the right",
+                    symbols
+                ),
+            ));
+        } else if !line.contains(':') {
+            errors.push(Error::new(
+                index,
+                "relationship needs a label, e.g. `: places`",
+            ));
+        }
+    }
+    blocks.finish("}", errors);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check, Error};
+
+    fn errors(diagram: &str) -> Vec<(usize, String)> {
+        let lines: Vec<&str> = diagram.lines().collect();
+        check(&lines)
+            .into_iter()
+            .map(|Error { line, message }| (line, message))
+            .collect()
+    }
+
+    fn lines_with_errors(diagram: &str) -> Vec<usize> {
+        errors(diagram).into_iter().map(|(line, _)| line).collect()
+    }
+
+    #[test]
+    fn flowchart() {
+        let valid = r#"
+            ---
+            title: Example
+            ---
+            %%{init: {"theme": "forest"}}%%
+            graph LR
+                A[Start] --> B{Is it?}
+                B -->|Yes| C("ok (quoted)")
+                B -- No --> D>asymmetric] & E((circle))
+                subgraph one [The first]
+                    direction TB
+                    F[("database")] -.-> G[/slanted/]
+                end
+                click A callback "Tooltip"
+                classDef green fill:#9f6
+                H --> I; I --> J
+        "#;
+        assert_eq!(errors(valid), []);
+
+        let invalid =
+            "flowchart TT\n    A[Start --> B\n    B -->\n    --> C\n    C -->|label D\n    D(x]";
+        assert_eq!(lines_with_errors(invalid), [0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn sequence() {
+        let valid = r#"
+            sequenceDiagram
+                autonumber
+                participant A as Alice
+                actor B
+                A->>+B: Hello
+                B-->>-A: Hi
+                Note over A,B: A typical interaction
+                alt is sick
+                    B-xA: Not so good :(
+                else is well
+                    B-)A: Fine
+                end
+                loop Every minute
+                    A->>B: ping
+                end
+        "#;
+        assert_eq!(errors(valid), []);
+
+        let invalid = "sequenceDiagram\nA->>: no target\nelse\nNote A: misplaced\nAlice says hi\nloop forever";
+        assert_eq!(lines_with_errors(invalid), [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn class() {
+        let valid = r#"
+            classDiagram
+                Animal <|-- Duck
+                Animal "1" --> "*" Leg : has
+                Animal : +int age
+                Animal: +isMammal() bool
+                class Duck {
+                    +String beakColor
+                    +swim(speed, depth)
+                    +List~int~ ids
+                }
+                <<interface>> Animal
+        "#;
+        assert_eq!(errors(valid), []);
+
+        let invalid = "classDiagram\n<|-- Duck\nclass A {\n  +run(speed\n}\n}\nclass List~int";
+        assert_eq!(lines_with_errors(invalid), [1, 3, 5, 6]);
+    }
+
+    #[test]
+    fn state() {
+        let valid = r#"
+            stateDiagram-v2
+                [*] --> Still
+                Still --> Moving : push
+                state fork_state <<fork>>
+                state "Long name" as Long {
+                    [*] --> Inner
+                    --
+                    [*] --> Other
+                }
+                note right of Still
+                    multiline
+                end note
+                note left of Moving : single line
+                Moving --> [*]
+        "#;
+        assert_eq!(errors(valid), []);
+
+        let invalid = "stateDiagram\nStill -->\nstate s <<merge>>\nstate x {\nnote left of s";
+        assert_eq!(lines_with_errors(invalid), [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn er() {
+        let valid = r#"
+            erDiagram
+                CUSTOMER ||--o{ ORDER : places
+                ORDER ||--|{ LINE-ITEM : contains
+                CUSTOMER }|..|{ DELIVERY-ADDRESS : uses
+                CUSTOMER {
+                    string name PK "the name"
+                    int age
+                }
+                PRODUCT
+        "#;
+        assert_eq!(errors(valid), []);
+
+        let invalid = "erDiagram\nCUSTOMER ||--o{ ORDER\nA ||--<> B : x\nA ||--o{ : x\nA {\nname\n";
+        assert_eq!(lines_with_errors(invalid), [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn other_diagrams_are_accepted() {
+        assert_eq!(errors("pie\n  \"a\" : 1\n  (((broken"), []);
+        assert_eq!(errors(""), []);
+        assert_eq!(lines_with_errors("---\ntitle: x\ngraph LR"), [0]);
+    }
+
+    #[test]
+    fn comments_before_the_header() {
+        let valid = r#"
+            %%{init: {"theme": "forest"}}%%
+            %% the flow
+            accTitle: Flow
+            accDescr {
+                multiline
+            }
+            graph LR
+                A --> B
+        "#;
+        assert_eq!(errors(valid), []);
+
+        let invalid = "%% the flow\nflowchart TT\n    A --> B";
+        assert_eq!(lines_with_errors(invalid), [1]);
+    }
+}